@@ -0,0 +1,209 @@
+//! Offline conversion between Simplified and Traditional Chinese, built from a
+//! character table plus a small phrase table for characters whose conversion
+//! depends on context (`头发` → `頭髮` but `发现` → `發現`).
+#![no_std]
+use aidoku::{
+	Chapter, FilterValue, HomeComponent, HomeComponentValue, HomeLayout, Link, LinkValue, Manga,
+	MangaPageResult, MangaWithChapter,
+	alloc::{String, Vec},
+	imports::defaults::defaults_get,
+};
+
+mod table;
+
+#[cfg(test)]
+mod test;
+
+/// Settings key shared by the sources offering script conversion.
+///
+/// Its value is `original`, `simplified` or `traditional`.
+pub const SETTING_KEY: &str = "chineseScript";
+
+type CharTable = &'static [(char, char)];
+type PhraseTable = &'static [(&'static str, &'static str)];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Script {
+	Simplified,
+	Traditional,
+}
+
+impl Script {
+	/// The script chosen in the source settings, or `None` to keep the
+	/// original text.
+	pub fn preferred() -> Option<Self> {
+		match defaults_get::<String>(SETTING_KEY)?.as_str() {
+			"simplified" => Some(Self::Simplified),
+			"traditional" => Some(Self::Traditional),
+			_ => None,
+		}
+	}
+
+	fn tables(self) -> (CharTable, PhraseTable) {
+		match self {
+			Self::Simplified => (table::T2S_CHARS, table::T2S_PHRASES),
+			Self::Traditional => (table::S2T_CHARS, table::S2T_PHRASES),
+		}
+	}
+}
+
+/// Converts `text` into the `to` script.
+pub fn convert(text: &str, to: Script) -> String {
+	let (chars, phrases) = to.tables();
+	let mut result = String::with_capacity(text.len());
+	let mut rest = text;
+
+	'outer: while let Some(ch) = rest.chars().next() {
+		// byte offsets after each of the next few characters
+		let mut ends = [0; table::MAX_PHRASE_LEN];
+		let mut count = 0;
+		let offsets = rest
+			.char_indices()
+			.skip(1)
+			.map(|(index, _)| index)
+			.chain(core::iter::once(rest.len()));
+		for (slot, end) in ends.iter_mut().zip(offsets) {
+			*slot = end;
+			count += 1;
+		}
+
+		// prefer the longest phrase starting here
+		for &end in ends[1..count].iter().rev() {
+			let candidate = &rest[..end];
+			if let Ok(index) = phrases.binary_search_by(|(key, _)| (*key).cmp(candidate)) {
+				result.push_str(phrases[index].1);
+				rest = &rest[end..];
+				continue 'outer;
+			}
+		}
+
+		result.push(
+			chars
+				.binary_search_by_key(&ch, |&(key, _)| key)
+				.map(|index| chars[index].1)
+				.unwrap_or(ch),
+		);
+		rest = &rest[ch.len_utf8()..];
+	}
+
+	result
+}
+
+/// Converts text and option values of search filters into the script a site
+/// expects, so that searching a converted tag still matches.
+pub fn normalize_filters(filters: &mut [FilterValue], site: Script) {
+	for filter in filters {
+		filter.convert(site);
+	}
+}
+
+/// Converts `value` into the preferred script, if one is set.
+pub fn localize<T: Convert>(mut value: T) -> T {
+	if let Some(script) = Script::preferred() {
+		value.convert(script);
+	}
+	value
+}
+
+/// Types carrying user-facing text that can be converted in place.
+pub trait Convert {
+	fn convert(&mut self, to: Script);
+}
+
+impl Convert for String {
+	fn convert(&mut self, to: Script) {
+		*self = convert(self, to);
+	}
+}
+
+impl<T: Convert> Convert for Option<T> {
+	fn convert(&mut self, to: Script) {
+		if let Some(value) = self {
+			value.convert(to);
+		}
+	}
+}
+
+impl<T: Convert> Convert for Vec<T> {
+	fn convert(&mut self, to: Script) {
+		for value in self {
+			value.convert(to);
+		}
+	}
+}
+
+impl Convert for Chapter {
+	fn convert(&mut self, to: Script) {
+		self.title.convert(to);
+	}
+}
+
+impl Convert for Manga {
+	fn convert(&mut self, to: Script) {
+		self.title.convert(to);
+		self.description.convert(to);
+		self.tags.convert(to);
+		self.chapters.convert(to);
+	}
+}
+
+impl Convert for MangaPageResult {
+	fn convert(&mut self, to: Script) {
+		self.entries.convert(to);
+	}
+}
+
+impl Convert for MangaWithChapter {
+	fn convert(&mut self, to: Script) {
+		self.manga.convert(to);
+		self.chapter.convert(to);
+	}
+}
+
+impl Convert for Link {
+	fn convert(&mut self, to: Script) {
+		self.title.convert(to);
+		self.subtitle.convert(to);
+		if let Some(LinkValue::Manga(manga)) = &mut self.value {
+			manga.convert(to);
+		}
+	}
+}
+
+impl Convert for HomeComponent {
+	fn convert(&mut self, to: Script) {
+		self.title.convert(to);
+		self.subtitle.convert(to);
+		match &mut self.value {
+			HomeComponentValue::ImageScroller { links, .. } => links.convert(to),
+			HomeComponentValue::BigScroller { entries, .. } => entries.convert(to),
+			HomeComponentValue::Scroller { entries, .. }
+			| HomeComponentValue::MangaList { entries, .. } => entries.convert(to),
+			HomeComponentValue::MangaChapterList { entries, .. } => entries.convert(to),
+			_ => {}
+		}
+	}
+}
+
+impl Convert for HomeLayout {
+	fn convert(&mut self, to: Script) {
+		self.components.convert(to);
+	}
+}
+
+impl Convert for FilterValue {
+	fn convert(&mut self, to: Script) {
+		match self {
+			FilterValue::Text { value, .. } | FilterValue::Select { value, .. } => {
+				value.convert(to);
+			}
+			FilterValue::MultiSelect {
+				included, excluded, ..
+			} => {
+				included.convert(to);
+				excluded.convert(to);
+			}
+			_ => {}
+		}
+	}
+}
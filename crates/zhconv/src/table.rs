@@ -0,0 +1,398 @@
+//! Conversion tables, kept sorted so lookups can binary search.
+
+/// Longest phrase in either phrase table, in characters.
+pub const MAX_PHRASE_LEN: usize = 3;
+
+/// Simplified to Traditional characters, sorted by the simplified form.
+#[rustfmt::skip]
+pub static S2T_CHARS: &[(char, char)] = &[
+	('万', '萬'), ('与', '與'), ('丑', '醜'), ('专', '專'), ('业', '業'), ('丛', '叢'), ('东', '東'), ('丝', '絲'),
+	('两', '兩'), ('严', '嚴'), ('丧', '喪'), ('个', '個'), ('丰', '豐'), ('临', '臨'), ('为', '為'), ('丽', '麗'),
+	('举', '舉'), ('么', '麼'), ('义', '義'), ('乌', '烏'), ('乐', '樂'), ('乔', '喬'), ('习', '習'), ('乡', '鄉'),
+	('书', '書'), ('买', '買'), ('乱', '亂'), ('争', '爭'), ('于', '於'), ('亏', '虧'), ('云', '雲'), ('亚', '亞'),
+	('产', '產'), ('亩', '畝'), ('亲', '親'), ('亿', '億'), ('仅', '僅'), ('仆', '僕'), ('从', '從'), ('仑', '侖'),
+	('仓', '倉'), ('仪', '儀'), ('们', '們'), ('价', '價'), ('众', '眾'), ('优', '優'), ('会', '會'), ('伞', '傘'),
+	('伟', '偉'), ('传', '傳'), ('伤', '傷'), ('伦', '倫'), ('伪', '偽'), ('体', '體'), ('余', '餘'), ('佣', '傭'),
+	('侠', '俠'), ('侣', '侶'), ('侥', '僥'), ('侦', '偵'), ('侧', '側'), ('侨', '僑'), ('侩', '儈'), ('俩', '倆'),
+	('俭', '儉'), ('债', '債'), ('倾', '傾'), ('偿', '償'), ('傥', '儻'), ('储', '儲'), ('儿', '兒'), ('兑', '兌'),
+	('党', '黨'), ('兰', '蘭'), ('关', '關'), ('兴', '興'), ('兹', '茲'), ('养', '養'), ('兽', '獸'), ('内', '內'),
+	('冈', '岡'), ('册', '冊'), ('写', '寫'), ('军', '軍'), ('农', '農'), ('冯', '馮'), ('冲', '衝'), ('决', '決'),
+	('况', '況'), ('冻', '凍'), ('净', '淨'), ('凄', '淒'), ('准', '準'), ('凉', '涼'), ('减', '減'), ('凑', '湊'),
+	('凛', '凜'), ('几', '幾'), ('凤', '鳳'), ('凭', '憑'), ('凯', '凱'), ('击', '擊'), ('凿', '鑿'), ('刍', '芻'),
+	('划', '劃'), ('刘', '劉'), ('则', '則'), ('刚', '剛'), ('创', '創'), ('删', '刪'), ('别', '別'), ('刽', '劊'),
+	('剂', '劑'), ('剑', '劍'), ('剥', '剝'), ('剧', '劇'), ('劝', '勸'), ('办', '辦'), ('务', '務'), ('动', '動'),
+	('励', '勵'), ('劲', '勁'), ('劳', '勞'), ('势', '勢'), ('勋', '勳'), ('匀', '勻'), ('区', '區'), ('医', '醫'),
+	('华', '華'), ('协', '協'), ('单', '單'), ('卖', '賣'), ('卢', '盧'), ('卤', '滷'), ('卧', '臥'), ('卫', '衛'),
+	('却', '卻'), ('厂', '廠'), ('厅', '廳'), ('历', '歷'), ('厉', '厲'), ('压', '壓'), ('厌', '厭'), ('厕', '廁'),
+	('厢', '廂'), ('厦', '廈'), ('厨', '廚'), ('县', '縣'), ('参', '參'), ('双', '雙'), ('发', '發'), ('变', '變'),
+	('叙', '敘'), ('叠', '疊'), ('叶', '葉'), ('号', '號'), ('叹', '嘆'), ('叽', '嘰'), ('吁', '籲'), ('后', '後'),
+	('吓', '嚇'), ('吕', '呂'), ('吗', '嗎'), ('吨', '噸'), ('听', '聽'), ('启', '啟'), ('吴', '吳'), ('呐', '吶'),
+	('呕', '嘔'), ('呗', '唄'), ('员', '員'), ('呛', '嗆'), ('呜', '嗚'), ('咏', '詠'), ('咙', '嚨'), ('咛', '嚀'),
+	('响', '響'), ('哑', '啞'), ('哒', '噠'), ('哔', '嗶'), ('哗', '嘩'), ('哟', '喲'), ('唠', '嘮'), ('唤', '喚'),
+	('啧', '嘖'), ('啮', '齧'), ('啰', '囉'), ('啸', '嘯'), ('喷', '噴'), ('喽', '嘍'), ('嘘', '噓'), ('嘤', '嚶'),
+	('嘱', '囑'), ('团', '團'), ('园', '園'), ('围', '圍'), ('国', '國'), ('图', '圖'), ('圆', '圓'), ('圣', '聖'),
+	('场', '場'), ('坏', '壞'), ('块', '塊'), ('坚', '堅'), ('坛', '壇'), ('坝', '壩'), ('坞', '塢'), ('坟', '墳'),
+	('坠', '墜'), ('垄', '壟'), ('垒', '壘'), ('垦', '墾'), ('垫', '墊'), ('堑', '塹'), ('堕', '墮'), ('墙', '牆'),
+	('壮', '壯'), ('声', '聲'), ('壳', '殼'), ('壶', '壺'), ('处', '處'), ('备', '備'), ('复', '復'), ('够', '夠'),
+	('头', '頭'), ('夸', '誇'), ('夹', '夾'), ('夺', '奪'), ('奋', '奮'), ('奖', '獎'), ('奥', '奧'), ('妆', '妝'),
+	('妇', '婦'), ('妈', '媽'), ('妩', '嫵'), ('姗', '姍'), ('娄', '婁'), ('娆', '嬈'), ('娇', '嬌'), ('娱', '娛'),
+	('娴', '嫻'), ('婴', '嬰'), ('婵', '嬋'), ('婶', '嬸'), ('孙', '孫'), ('学', '學'), ('孪', '孿'), ('宁', '寧'),
+	('宝', '寶'), ('实', '實'), ('宠', '寵'), ('审', '審'), ('宪', '憲'), ('宫', '宮'), ('宽', '寬'), ('宾', '賓'),
+	('寝', '寢'), ('对', '對'), ('寻', '尋'), ('导', '導'), ('寿', '壽'), ('将', '將'), ('尔', '爾'), ('尘', '塵'),
+	('尝', '嘗'), ('尧', '堯'), ('尴', '尷'), ('尸', '屍'), ('尽', '盡'), ('层', '層'), ('屉', '屜'), ('届', '屆'),
+	('属', '屬'), ('屡', '屢'), ('屿', '嶼'), ('岁', '歲'), ('岂', '豈'), ('岗', '崗'), ('岛', '島'), ('岭', '嶺'),
+	('岿', '巋'), ('峡', '峽'), ('峥', '崢'), ('峦', '巒'), ('崭', '嶄'), ('巩', '鞏'), ('币', '幣'), ('帅', '帥'),
+	('师', '師'), ('帐', '帳'), ('帘', '簾'), ('帜', '幟'), ('带', '帶'), ('帧', '幀'), ('帮', '幫'), ('幂', '冪'),
+	('干', '幹'), ('并', '並'), ('广', '廣'), ('庄', '莊'), ('庆', '慶'), ('庐', '廬'), ('库', '庫'), ('应', '應'),
+	('庙', '廟'), ('庞', '龐'), ('废', '廢'), ('开', '開'), ('异', '異'), ('弃', '棄'), ('张', '張'), ('弥', '彌'),
+	('弯', '彎'), ('弹', '彈'), ('强', '強'), ('归', '歸'), ('当', '當'), ('录', '錄'), ('彝', '彞'), ('彦', '彥'),
+	('彻', '徹'), ('径', '徑'), ('忆', '憶'), ('忏', '懺'), ('忧', '憂'), ('怀', '懷'), ('态', '態'), ('怂', '慫'),
+	('怅', '悵'), ('怜', '憐'), ('总', '總'), ('恋', '戀'), ('恒', '恆'), ('恳', '懇'), ('恶', '惡'), ('恼', '惱'),
+	('悦', '悅'), ('悬', '懸'), ('悯', '憫'), ('惊', '驚'), ('惧', '懼'), ('惨', '慘'), ('惩', '懲'), ('惫', '憊'),
+	('惭', '慚'), ('惮', '憚'), ('惯', '慣'), ('愤', '憤'), ('愿', '願'), ('慑', '懾'), ('懒', '懶'), ('戏', '戲'),
+	('战', '戰'), ('户', '戶'), ('扑', '撲'), ('执', '執'), ('扩', '擴'), ('扫', '掃'), ('扬', '揚'), ('扰', '擾'),
+	('抚', '撫'), ('抛', '拋'), ('抠', '摳'), ('抡', '掄'), ('抢', '搶'), ('护', '護'), ('报', '報'), ('担', '擔'),
+	('拟', '擬'), ('拢', '攏'), ('拣', '揀'), ('拥', '擁'), ('拦', '攔'), ('拧', '擰'), ('拨', '撥'), ('择', '擇'),
+	('挂', '掛'), ('挚', '摯'), ('挛', '攣'), ('挝', '撾'), ('挞', '撻'), ('挟', '挾'), ('挠', '撓'), ('挡', '擋'),
+	('挣', '掙'), ('挤', '擠'), ('挥', '揮'), ('捞', '撈'), ('损', '損'), ('捡', '撿'), ('换', '換'), ('捣', '搗'),
+	('据', '據'), ('掳', '擄'), ('掷', '擲'), ('掸', '撣'), ('掺', '摻'), ('揽', '攬'), ('搀', '攙'), ('搁', '擱'),
+	('搂', '摟'), ('搅', '攪'), ('携', '攜'), ('摄', '攝'), ('摆', '擺'), ('摇', '搖'), ('摊', '攤'), ('撑', '撐'),
+	('撵', '攆'), ('擞', '擻'), ('攒', '攢'), ('敌', '敵'), ('敛', '斂'), ('数', '數'), ('斋', '齋'), ('斗', '鬥'),
+	('斩', '斬'), ('断', '斷'), ('无', '無'), ('旧', '舊'), ('时', '時'), ('旷', '曠'), ('昙', '曇'), ('昼', '晝'),
+	('显', '顯'), ('晋', '晉'), ('晒', '曬'), ('晓', '曉'), ('晔', '曄'), ('晕', '暈'), ('晖', '暉'), ('暂', '暫'),
+	('术', '術'), ('机', '機'), ('杀', '殺'), ('杂', '雜'), ('权', '權'), ('条', '條'), ('来', '來'), ('杨', '楊'),
+	('杰', '傑'), ('极', '極'), ('构', '構'), ('枢', '樞'), ('枣', '棗'), ('枪', '槍'), ('枫', '楓'), ('柜', '櫃'),
+	('柠', '檸'), ('栅', '柵'), ('标', '標'), ('栈', '棧'), ('栋', '棟'), ('栏', '欄'), ('树', '樹'), ('栖', '棲'),
+	('样', '樣'), ('档', '檔'), ('桥', '橋'), ('桨', '槳'), ('桩', '樁'), ('梦', '夢'), ('检', '檢'), ('椭', '橢'),
+	('楼', '樓'), ('槛', '檻'), ('槟', '檳'), ('横', '橫'), ('樱', '櫻'), ('橱', '櫥'), ('欢', '歡'), ('欧', '歐'),
+	('歼', '殲'), ('残', '殘'), ('殴', '毆'), ('毁', '毀'), ('毕', '畢'), ('毙', '斃'), ('毡', '氈'), ('气', '氣'),
+	('氢', '氫'), ('汇', '匯'), ('汉', '漢'), ('污', '汙'), ('汤', '湯'), ('汹', '洶'), ('沟', '溝'), ('没', '沒'),
+	('沤', '漚'), ('沥', '瀝'), ('沦', '淪'), ('沧', '滄'), ('沪', '滬'), ('泞', '濘'), ('泪', '淚'), ('泻', '瀉'),
+	('泼', '潑'), ('泽', '澤'), ('洁', '潔'), ('洒', '灑'), ('洼', '窪'), ('浅', '淺'), ('浆', '漿'), ('浇', '澆'),
+	('浊', '濁'), ('测', '測'), ('济', '濟'), ('浏', '瀏'), ('浑', '渾'), ('浓', '濃'), ('涂', '塗'), ('涌', '湧'),
+	('涛', '濤'), ('涝', '澇'), ('涟', '漣'), ('涡', '渦'), ('涣', '渙'), ('涤', '滌'), ('润', '潤'), ('涧', '澗'),
+	('涨', '漲'), ('涩', '澀'), ('淀', '澱'), ('渊', '淵'), ('渍', '漬'), ('渐', '漸'), ('渔', '漁'), ('渗', '滲'),
+	('温', '溫'), ('游', '遊'), ('湾', '灣'), ('湿', '濕'), ('溃', '潰'), ('溅', '濺'), ('滚', '滾'), ('滞', '滯'),
+	('满', '滿'), ('滤', '濾'), ('滥', '濫'), ('滦', '灤'), ('滨', '濱'), ('滩', '灘'), ('潍', '濰'), ('潜', '潛'),
+	('澜', '瀾'), ('灭', '滅'), ('灯', '燈'), ('灵', '靈'), ('灶', '竈'), ('灾', '災'), ('灿', '燦'), ('炉', '爐'),
+	('炜', '煒'), ('点', '點'), ('炼', '煉'), ('炽', '熾'), ('烁', '爍'), ('烂', '爛'), ('烃', '烴'), ('烛', '燭'),
+	('烟', '煙'), ('烦', '煩'), ('烧', '燒'), ('烨', '燁'), ('烩', '燴'), ('烫', '燙'), ('烬', '燼'), ('热', '熱'),
+	('焕', '煥'), ('爱', '愛'), ('爷', '爺'), ('牵', '牽'), ('牺', '犧'), ('犊', '犢'), ('状', '狀'), ('犹', '猶'),
+	('狈', '狽'), ('狞', '獰'), ('独', '獨'), ('狭', '狹'), ('狮', '獅'), ('狰', '猙'), ('狱', '獄'), ('猎', '獵'),
+	('猪', '豬'), ('猫', '貓'), ('献', '獻'), ('獭', '獺'), ('玛', '瑪'), ('环', '環'), ('现', '現'), ('珐', '琺'),
+	('琐', '瑣'), ('琼', '瓊'), ('瑶', '瑤'), ('瓮', '甕'), ('电', '電'), ('画', '畫'), ('畅', '暢'), ('畴', '疇'),
+	('疗', '療'), ('疟', '瘧'), ('疡', '瘍'), ('疮', '瘡'), ('疯', '瘋'), ('痈', '癰'), ('痉', '痙'), ('痒', '癢'),
+	('痪', '瘓'), ('痴', '癡'), ('瘫', '癱'), ('癣', '癬'), ('皱', '皺'), ('盏', '盞'), ('盐', '鹽'), ('监', '監'),
+	('盖', '蓋'), ('盗', '盜'), ('盘', '盤'), ('眯', '瞇'), ('着', '著'), ('睁', '睜'), ('瞒', '瞞'), ('瞩', '矚'),
+	('矫', '矯'), ('矾', '礬'), ('矿', '礦'), ('码', '碼'), ('砖', '磚'), ('砚', '硯'), ('砾', '礫'), ('础', '礎'),
+	('硕', '碩'), ('确', '確'), ('碍', '礙'), ('碱', '鹼'), ('礼', '禮'), ('祷', '禱'), ('祸', '禍'), ('禄', '祿'),
+	('离', '離'), ('秆', '稈'), ('种', '種'), ('积', '積'), ('称', '稱'), ('秽', '穢'), ('税', '稅'), ('稳', '穩'),
+	('穷', '窮'), ('窃', '竊'), ('窍', '竅'), ('窜', '竄'), ('窝', '窩'), ('窥', '窺'), ('竖', '豎'), ('竞', '競'),
+	('笋', '筍'), ('笔', '筆'), ('笺', '箋'), ('笼', '籠'), ('筑', '築'), ('筛', '篩'), ('筝', '箏'), ('筹', '籌'),
+	('签', '簽'), ('简', '簡'), ('箩', '籮'), ('篓', '簍'), ('篮', '籃'), ('篱', '籬'), ('类', '類'), ('粤', '粵'),
+	('粪', '糞'), ('粮', '糧'), ('紧', '緊'), ('纠', '糾'), ('红', '紅'), ('纤', '纖'), ('约', '約'), ('级', '級'),
+	('纪', '紀'), ('纫', '紉'), ('纬', '緯'), ('纯', '純'), ('纱', '紗'), ('纲', '綱'), ('纳', '納'), ('纵', '縱'),
+	('纶', '綸'), ('纷', '紛'), ('纸', '紙'), ('纹', '紋'), ('纺', '紡'), ('纽', '紐'), ('线', '線'), ('练', '練'),
+	('组', '組'), ('绅', '紳'), ('细', '細'), ('织', '織'), ('终', '終'), ('绊', '絆'), ('绍', '紹'), ('绎', '繹'),
+	('经', '經'), ('绑', '綁'), ('绒', '絨'), ('结', '結'), ('绕', '繞'), ('绘', '繪'), ('给', '給'), ('绚', '絢'),
+	('络', '絡'), ('绝', '絕'), ('绞', '絞'), ('统', '統'), ('绢', '絹'), ('绣', '繡'), ('绥', '綏'), ('继', '繼'),
+	('绩', '績'), ('绪', '緒'), ('绫', '綾'), ('续', '續'), ('绮', '綺'), ('绯', '緋'), ('绰', '綽'), ('绳', '繩'),
+	('维', '維'), ('绵', '綿'), ('绷', '繃'), ('绸', '綢'), ('综', '綜'), ('绽', '綻'), ('绿', '綠'), ('缀', '綴'),
+	('缄', '緘'), ('缅', '緬'), ('缆', '纜'), ('缈', '緲'), ('缉', '緝'), ('缎', '緞'), ('缓', '緩'), ('缔', '締'),
+	('缕', '縷'), ('编', '編'), ('缘', '緣'), ('缚', '縛'), ('缝', '縫'), ('缠', '纏'), ('缤', '繽'), ('缥', '縹'),
+	('缨', '纓'), ('缩', '縮'), ('缮', '繕'), ('缴', '繳'), ('网', '網'), ('罗', '羅'), ('罚', '罰'), ('罢', '罷'),
+	('羡', '羨'), ('翘', '翹'), ('耸', '聳'), ('耻', '恥'), ('聂', '聶'), ('聋', '聾'), ('职', '職'), ('联', '聯'),
+	('聪', '聰'), ('肃', '肅'), ('肠', '腸'), ('肤', '膚'), ('肮', '骯'), ('肾', '腎'), ('肿', '腫'), ('胀', '脹'),
+	('胁', '脅'), ('胆', '膽'), ('胜', '勝'), ('胶', '膠'), ('脉', '脈'), ('脏', '髒'), ('脐', '臍'), ('脑', '腦'),
+	('脓', '膿'), ('脚', '腳'), ('脱', '脫'), ('脸', '臉'), ('腊', '臘'), ('腻', '膩'), ('腾', '騰'), ('舆', '輿'),
+	('舰', '艦'), ('舱', '艙'), ('艰', '艱'), ('艳', '艷'), ('艺', '藝'), ('节', '節'), ('芜', '蕪'), ('芦', '蘆'),
+	('苇', '葦'), ('苍', '蒼'), ('苏', '蘇'), ('苹', '蘋'), ('范', '範'), ('茎', '莖'), ('茧', '繭'), ('荆', '荊'),
+	('荐', '薦'), ('荚', '莢'), ('荞', '蕎'), ('荡', '蕩'), ('荣', '榮'), ('荤', '葷'), ('荧', '熒'), ('荫', '蔭'),
+	('药', '藥'), ('莱', '萊'), ('莲', '蓮'), ('获', '獲'), ('莹', '瑩'), ('莺', '鶯'), ('萝', '蘿'), ('萤', '螢'),
+	('营', '營'), ('萧', '蕭'), ('萨', '薩'), ('葱', '蔥'), ('蒋', '蔣'), ('蓝', '藍'), ('蓟', '薊'), ('蔷', '薔'),
+	('蔼', '藹'), ('蕴', '蘊'), ('虏', '虜'), ('虑', '慮'), ('虚', '虛'), ('虫', '蟲'), ('虽', '雖'), ('虾', '蝦'),
+	('蚀', '蝕'), ('蚁', '蟻'), ('蚂', '螞'), ('蚕', '蠶'), ('蛊', '蠱'), ('蛮', '蠻'), ('蛰', '蟄'), ('蜕', '蛻'),
+	('蜗', '蝸'), ('蜡', '蠟'), ('蝇', '蠅'), ('蝉', '蟬'), ('蝎', '蠍'), ('衅', '釁'), ('衔', '銜'), ('补', '補'),
+	('衬', '襯'), ('袄', '襖'), ('袜', '襪'), ('袭', '襲'), ('装', '裝'), ('裤', '褲'), ('见', '見'), ('观', '觀'),
+	('规', '規'), ('觅', '覓'), ('视', '視'), ('览', '覽'), ('觉', '覺'), ('触', '觸'), ('誉', '譽'), ('誊', '謄'),
+	('计', '計'), ('订', '訂'), ('讣', '訃'), ('认', '認'), ('讥', '譏'), ('讨', '討'), ('让', '讓'), ('讫', '訖'),
+	('训', '訓'), ('议', '議'), ('讯', '訊'), ('记', '記'), ('讲', '講'), ('讳', '諱'), ('讶', '訝'), ('讷', '訥'),
+	('许', '許'), ('讹', '訛'), ('论', '論'), ('讼', '訟'), ('讽', '諷'), ('设', '設'), ('访', '訪'), ('诀', '訣'),
+	('证', '證'), ('评', '評'), ('诅', '詛'), ('识', '識'), ('诈', '詐'), ('诉', '訴'), ('诊', '診'), ('诌', '謅'),
+	('词', '詞'), ('译', '譯'), ('试', '試'), ('诗', '詩'), ('诚', '誠'), ('诛', '誅'), ('话', '話'), ('诞', '誕'),
+	('诡', '詭'), ('询', '詢'), ('诣', '詣'), ('该', '該'), ('详', '詳'), ('诧', '詫'), ('诫', '誡'), ('诬', '誣'),
+	('语', '語'), ('误', '誤'), ('诱', '誘'), ('诲', '誨'), ('说', '說'), ('诵', '誦'), ('诶', '誒'), ('请', '請'),
+	('诸', '諸'), ('诺', '諾'), ('读', '讀'), ('诽', '誹'), ('课', '課'), ('谁', '誰'), ('调', '調'), ('谅', '諒'),
+	('谆', '諄'), ('谈', '談'), ('谊', '誼'), ('谋', '謀'), ('谍', '諜'), ('谎', '謊'), ('谐', '諧'), ('谓', '謂'),
+	('谗', '讒'), ('谙', '諳'), ('谚', '諺'), ('谜', '謎'), ('谢', '謝'), ('谣', '謠'), ('谤', '謗'), ('谦', '謙'),
+	('谨', '謹'), ('谩', '謾'), ('谪', '謫'), ('谬', '謬'), ('谭', '譚'), ('谰', '讕'), ('谱', '譜'), ('谴', '譴'),
+	('贝', '貝'), ('贞', '貞'), ('负', '負'), ('贡', '貢'), ('财', '財'), ('责', '責'), ('贤', '賢'), ('败', '敗'),
+	('账', '賬'), ('货', '貨'), ('质', '質'), ('贩', '販'), ('贪', '貪'), ('贫', '貧'), ('贬', '貶'), ('购', '購'),
+	('贮', '貯'), ('贯', '貫'), ('贰', '貳'), ('贱', '賤'), ('贴', '貼'), ('贵', '貴'), ('贷', '貸'), ('贸', '貿'),
+	('费', '費'), ('贺', '賀'), ('贼', '賊'), ('贾', '賈'), ('贿', '賄'), ('赁', '賃'), ('赂', '賂'), ('赃', '贓'),
+	('资', '資'), ('赊', '賒'), ('赋', '賦'), ('赌', '賭'), ('赎', '贖'), ('赏', '賞'), ('赐', '賜'), ('赔', '賠'),
+	('赖', '賴'), ('赘', '贅'), ('赚', '賺'), ('赛', '賽'), ('赞', '贊'), ('赠', '贈'), ('赡', '贍'), ('赢', '贏'),
+	('赣', '贛'), ('赵', '趙'), ('赶', '趕'), ('趋', '趨'), ('跃', '躍'), ('践', '踐'), ('踊', '踴'), ('踪', '蹤'),
+	('躯', '軀'), ('车', '車'), ('轧', '軋'), ('轨', '軌'), ('轩', '軒'), ('转', '轉'), ('轮', '輪'), ('软', '軟'),
+	('轰', '轟'), ('轴', '軸'), ('轻', '輕'), ('载', '載'), ('轿', '轎'), ('较', '較'), ('辅', '輔'), ('辆', '輛'),
+	('辈', '輩'), ('辉', '輝'), ('辊', '輥'), ('辐', '輻'), ('辑', '輯'), ('输', '輸'), ('辕', '轅'), ('辖', '轄'),
+	('辗', '輾'), ('辙', '轍'), ('辞', '辭'), ('辩', '辯'), ('辫', '辮'), ('边', '邊'), ('辽', '遼'), ('达', '達'),
+	('迁', '遷'), ('过', '過'), ('迈', '邁'), ('运', '運'), ('还', '還'), ('这', '這'), ('进', '進'), ('远', '遠'),
+	('违', '違'), ('连', '連'), ('迟', '遲'), ('迹', '跡'), ('适', '適'), ('选', '選'), ('逊', '遜'), ('递', '遞'),
+	('逻', '邏'), ('遗', '遺'), ('邓', '鄧'), ('邮', '郵'), ('邹', '鄒'), ('邻', '鄰'), ('郁', '鬱'), ('郑', '鄭'),
+	('郧', '鄖'), ('郸', '鄲'), ('酝', '醞'), ('酱', '醬'), ('酿', '釀'), ('释', '釋'), ('里', '裡'), ('鉴', '鑑'),
+	('针', '針'), ('钉', '釘'), ('钒', '釩'), ('钓', '釣'), ('钙', '鈣'), ('钝', '鈍'), ('钞', '鈔'), ('钟', '鐘'),
+	('钠', '鈉'), ('钡', '鋇'), ('钢', '鋼'), ('钥', '鑰'), ('钦', '欽'), ('钧', '鈞'), ('钨', '鎢'), ('钩', '鉤'),
+	('钮', '鈕'), ('钰', '鈺'), ('钱', '錢'), ('钳', '鉗'), ('钵', '缽'), ('钻', '鑽'), ('钾', '鉀'), ('铀', '鈾'),
+	('铁', '鐵'), ('铂', '鉑'), ('铃', '鈴'), ('铅', '鉛'), ('铆', '鉚'), ('铜', '銅'), ('铝', '鋁'), ('铠', '鎧'),
+	('铡', '鍘'), ('铣', '銑'), ('铭', '銘'), ('铰', '鉸'), ('铱', '銥'), ('铲', '鏟'), ('银', '銀'), ('铸', '鑄'),
+	('铺', '鋪'), ('链', '鏈'), ('销', '銷'), ('锁', '鎖'), ('锄', '鋤'), ('锅', '鍋'), ('锈', '鏽'), ('锋', '鋒'),
+	('锌', '鋅'), ('锐', '銳'), ('锑', '銻'), ('锗', '鍺'), ('错', '錯'), ('锚', '錨'), ('锡', '錫'), ('锣', '鑼'),
+	('锤', '錘'), ('锥', '錐'), ('锦', '錦'), ('锭', '錠'), ('键', '鍵'), ('锯', '鋸'), ('锰', '錳'), ('锹', '鍬'),
+	('锻', '鍛'), ('镀', '鍍'), ('镁', '鎂'), ('镇', '鎮'), ('镊', '鑷'), ('镍', '鎳'), ('镐', '鎬'), ('镑', '鎊'),
+	('镖', '鏢'), ('镜', '鏡'), ('镣', '鐐'), ('镭', '鐳'), ('镰', '鐮'), ('镶', '鑲'), ('长', '長'), ('门', '門'),
+	('闪', '閃'), ('闭', '閉'), ('问', '問'), ('闯', '闖'), ('闰', '閏'), ('闲', '閒'), ('间', '間'), ('闷', '悶'),
+	('闸', '閘'), ('闹', '鬧'), ('闺', '閨'), ('闻', '聞'), ('闽', '閩'), ('阀', '閥'), ('阁', '閣'), ('阂', '閡'),
+	('阅', '閱'), ('阉', '閹'), ('阎', '閻'), ('阐', '闡'), ('阑', '闌'), ('阔', '闊'), ('队', '隊'), ('阳', '陽'),
+	('阴', '陰'), ('阵', '陣'), ('阶', '階'), ('际', '際'), ('陆', '陸'), ('陇', '隴'), ('陈', '陳'), ('陕', '陝'),
+	('陨', '隕'), ('险', '險'), ('随', '隨'), ('隐', '隱'), ('隶', '隸'), ('难', '難'), ('雾', '霧'), ('霭', '靄'),
+	('静', '靜'), ('韦', '韋'), ('韧', '韌'), ('韩', '韓'), ('韵', '韻'), ('页', '頁'), ('顶', '頂'), ('顷', '頃'),
+	('项', '項'), ('顺', '順'), ('须', '須'), ('顽', '頑'), ('顾', '顧'), ('顿', '頓'), ('颁', '頒'), ('颂', '頌'),
+	('预', '預'), ('颅', '顱'), ('领', '領'), ('颇', '頗'), ('颈', '頸'), ('颊', '頰'), ('颐', '頤'), ('频', '頻'),
+	('颓', '頹'), ('颖', '穎'), ('颗', '顆'), ('题', '題'), ('颜', '顏'), ('额', '額'), ('颠', '顛'), ('颤', '顫'),
+	('颧', '顴'), ('风', '風'), ('飒', '颯'), ('飘', '飄'), ('飞', '飛'), ('饥', '饑'), ('饭', '飯'), ('饮', '飲'),
+	('饯', '餞'), ('饰', '飾'), ('饱', '飽'), ('饲', '飼'), ('饵', '餌'), ('饶', '饒'), ('饺', '餃'), ('饼', '餅'),
+	('饿', '餓'), ('馁', '餒'), ('馅', '餡'), ('馆', '館'), ('馈', '饋'), ('馋', '饞'), ('馏', '餾'), ('馒', '饅'),
+	('马', '馬'), ('驭', '馭'), ('驮', '馱'), ('驯', '馴'), ('驰', '馳'), ('驱', '驅'), ('驳', '駁'), ('驴', '驢'),
+	('驶', '駛'), ('驹', '駒'), ('驻', '駐'), ('驼', '駝'), ('驾', '駕'), ('骁', '驍'), ('骂', '罵'), ('骄', '驕'),
+	('骆', '駱'), ('骇', '駭'), ('骋', '騁'), ('验', '驗'), ('骏', '駿'), ('骑', '騎'), ('骗', '騙'), ('骚', '騷'),
+	('骡', '騾'), ('骤', '驟'), ('鬓', '鬢'), ('魇', '魘'), ('鱼', '魚'), ('鲁', '魯'), ('鲍', '鮑'), ('鲛', '鮫'),
+	('鲜', '鮮'), ('鲤', '鯉'), ('鲸', '鯨'), ('鳃', '鰓'), ('鳄', '鱷'), ('鳖', '鱉'), ('鳞', '鱗'), ('鸟', '鳥'),
+	('鸡', '雞'), ('鸣', '鳴'), ('鸥', '鷗'), ('鸦', '鴉'), ('鸭', '鴨'), ('鸯', '鴦'), ('鸳', '鴛'), ('鸵', '鴕'),
+	('鸽', '鴿'), ('鸾', '鸞'), ('鸿', '鴻'), ('鹃', '鵑'), ('鹅', '鵝'), ('鹊', '鵲'), ('鹏', '鵬'), ('鹤', '鶴'),
+	('鹰', '鷹'), ('麦', '麥'), ('黄', '黃'), ('齐', '齊'), ('齿', '齒'), ('龄', '齡'), ('龋', '齲'), ('龙', '龍'),
+	('龚', '龔'), ('龟', '龜'),
+];
+
+/// Traditional to Simplified characters, sorted by the traditional form.
+#[rustfmt::skip]
+pub static T2S_CHARS: &[(char, char)] = &[
+	('並', '并'), ('乾', '干'), ('亂', '乱'), ('亞', '亚'), ('佔', '占'), ('來', '来'), ('侖', '仑'), ('侶', '侣'),
+	('係', '系'), ('俠', '侠'), ('倆', '俩'), ('倉', '仓'), ('個', '个'), ('們', '们'), ('倫', '伦'), ('偉', '伟'),
+	('側', '侧'), ('偵', '侦'), ('偽', '伪'), ('傑', '杰'), ('傘', '伞'), ('備', '备'), ('傢', '家'), ('傭', '佣'),
+	('傳', '传'), ('債', '债'), ('傷', '伤'), ('傾', '倾'), ('僅', '仅'), ('僑', '侨'), ('僕', '仆'), ('僞', '伪'),
+	('僥', '侥'), ('價', '价'), ('儀', '仪'), ('億', '亿'), ('儈', '侩'), ('儉', '俭'), ('儘', '尽'), ('償', '偿'),
+	('優', '优'), ('儲', '储'), ('儻', '傥'), ('兇', '凶'), ('兌', '兑'), ('兒', '儿'), ('內', '内'), ('兩', '两'),
+	('冊', '册'), ('冪', '幂'), ('凍', '冻'), ('凜', '凛'), ('凱', '凯'), ('別', '别'), ('刪', '删'), ('則', '则'),
+	('剛', '刚'), ('剝', '剥'), ('創', '创'), ('劃', '划'), ('劇', '剧'), ('劉', '刘'), ('劊', '刽'), ('劍', '剑'),
+	('劑', '剂'), ('勁', '劲'), ('動', '动'), ('務', '务'), ('勝', '胜'), ('勞', '劳'), ('勢', '势'), ('勳', '勋'),
+	('勵', '励'), ('勸', '劝'), ('勻', '匀'), ('匯', '汇'), ('區', '区'), ('協', '协'), ('卻', '却'), ('厭', '厌'),
+	('厲', '厉'), ('參', '参'), ('叢', '丛'), ('吳', '吴'), ('吶', '呐'), ('呂', '吕'), ('員', '员'), ('唄', '呗'),
+	('問', '问'), ('啓', '启'), ('啞', '哑'), ('啟', '启'), ('喚', '唤'), ('喪', '丧'), ('喬', '乔'), ('單', '单'),
+	('喲', '哟'), ('嗆', '呛'), ('嗎', '吗'), ('嗚', '呜'), ('嗶', '哔'), ('嘆', '叹'), ('嘍', '喽'), ('嘔', '呕'),
+	('嘖', '啧'), ('嘗', '尝'), ('嘩', '哗'), ('嘮', '唠'), ('嘯', '啸'), ('嘰', '叽'), ('噓', '嘘'), ('噠', '哒'),
+	('噴', '喷'), ('噸', '吨'), ('嚀', '咛'), ('嚇', '吓'), ('嚨', '咙'), ('嚴', '严'), ('嚶', '嘤'), ('囉', '啰'),
+	('囑', '嘱'), ('國', '国'), ('圍', '围'), ('園', '园'), ('圓', '圆'), ('圖', '图'), ('團', '团'), ('執', '执'),
+	('堅', '坚'), ('堯', '尧'), ('報', '报'), ('場', '场'), ('塊', '块'), ('塗', '涂'), ('塢', '坞'), ('塵', '尘'),
+	('塹', '堑'), ('墊', '垫'), ('墜', '坠'), ('墮', '堕'), ('墳', '坟'), ('墾', '垦'), ('壇', '坛'), ('壓', '压'),
+	('壘', '垒'), ('壞', '坏'), ('壟', '垄'), ('壩', '坝'), ('壯', '壮'), ('壺', '壶'), ('壽', '寿'), ('夠', '够'),
+	('夢', '梦'), ('夥', '伙'), ('夾', '夹'), ('奧', '奥'), ('奪', '夺'), ('奮', '奋'), ('妝', '妆'), ('姍', '姗'),
+	('娛', '娱'), ('婁', '娄'), ('婦', '妇'), ('媽', '妈'), ('嫵', '妩'), ('嫻', '娴'), ('嬈', '娆'), ('嬋', '婵'),
+	('嬌', '娇'), ('嬰', '婴'), ('嬸', '婶'), ('孫', '孙'), ('學', '学'), ('孿', '孪'), ('宮', '宫'), ('寢', '寝'),
+	('實', '实'), ('寧', '宁'), ('審', '审'), ('寫', '写'), ('寬', '宽'), ('寵', '宠'), ('寶', '宝'), ('將', '将'),
+	('專', '专'), ('尋', '寻'), ('對', '对'), ('導', '导'), ('尷', '尴'), ('屆', '届'), ('屍', '尸'), ('屜', '屉'),
+	('屢', '屡'), ('層', '层'), ('屬', '属'), ('岡', '冈'), ('島', '岛'), ('峽', '峡'), ('崗', '岗'), ('崢', '峥'),
+	('嶄', '崭'), ('嶺', '岭'), ('嶼', '屿'), ('巋', '岿'), ('巒', '峦'), ('帥', '帅'), ('師', '师'), ('帳', '帐'),
+	('帶', '带'), ('幀', '帧'), ('幟', '帜'), ('幣', '币'), ('幫', '帮'), ('幹', '干'), ('幾', '几'), ('庫', '库'),
+	('廁', '厕'), ('廂', '厢'), ('廈', '厦'), ('廚', '厨'), ('廟', '庙'), ('廠', '厂'), ('廢', '废'), ('廣', '广'),
+	('廬', '庐'), ('廳', '厅'), ('張', '张'), ('強', '强'), ('彈', '弹'), ('彌', '弥'), ('彎', '弯'), ('彙', '汇'),
+	('彞', '彝'), ('彥', '彦'), ('後', '后'), ('徑', '径'), ('從', '从'), ('復', '复'), ('徵', '征'), ('徹', '彻'),
+	('恆', '恒'), ('恥', '耻'), ('悅', '悦'), ('悵', '怅'), ('悶', '闷'), ('惡', '恶'), ('惱', '恼'), ('愛', '爱'),
+	('態', '态'), ('慘', '惨'), ('慚', '惭'), ('慣', '惯'), ('慫', '怂'), ('慮', '虑'), ('慶', '庆'), ('憂', '忧'),
+	('憊', '惫'), ('憐', '怜'), ('憑', '凭'), ('憚', '惮'), ('憤', '愤'), ('憫', '悯'), ('憲', '宪'), ('憶', '忆'),
+	('懇', '恳'), ('應', '应'), ('懲', '惩'), ('懶', '懒'), ('懷', '怀'), ('懸', '悬'), ('懺', '忏'), ('懼', '惧'),
+	('懾', '慑'), ('戀', '恋'), ('戰', '战'), ('戲', '戏'), ('戶', '户'), ('拋', '抛'), ('挾', '挟'), ('捨', '舍'),
+	('捲', '卷'), ('掃', '扫'), ('掄', '抡'), ('掙', '挣'), ('掛', '挂'), ('揀', '拣'), ('揚', '扬'), ('換', '换'),
+	('揮', '挥'), ('損', '损'), ('搖', '摇'), ('搗', '捣'), ('搶', '抢'), ('摟', '搂'), ('摯', '挚'), ('摳', '抠'),
+	('摻', '掺'), ('撈', '捞'), ('撐', '撑'), ('撓', '挠'), ('撣', '掸'), ('撥', '拨'), ('撫', '抚'), ('撲', '扑'),
+	('撻', '挞'), ('撾', '挝'), ('撿', '捡'), ('擁', '拥'), ('擄', '掳'), ('擇', '择'), ('擊', '击'), ('擋', '挡'),
+	('擔', '担'), ('據', '据'), ('擠', '挤'), ('擬', '拟'), ('擰', '拧'), ('擱', '搁'), ('擲', '掷'), ('擴', '扩'),
+	('擺', '摆'), ('擻', '擞'), ('擾', '扰'), ('攆', '撵'), ('攏', '拢'), ('攔', '拦'), ('攙', '搀'), ('攜', '携'),
+	('攝', '摄'), ('攢', '攒'), ('攣', '挛'), ('攤', '摊'), ('攪', '搅'), ('攬', '揽'), ('敗', '败'), ('敘', '叙'),
+	('敵', '敌'), ('數', '数'), ('斂', '敛'), ('斃', '毙'), ('斬', '斩'), ('斷', '断'), ('於', '于'), ('時', '时'),
+	('晉', '晋'), ('晝', '昼'), ('暈', '晕'), ('暉', '晖'), ('暢', '畅'), ('暫', '暂'), ('曄', '晔'), ('曆', '历'),
+	('曇', '昙'), ('曉', '晓'), ('曠', '旷'), ('曬', '晒'), ('書', '书'), ('會', '会'), ('東', '东'), ('柵', '栅'),
+	('條', '条'), ('棄', '弃'), ('棗', '枣'), ('棟', '栋'), ('棧', '栈'), ('棲', '栖'), ('楊', '杨'), ('楓', '枫'),
+	('業', '业'), ('極', '极'), ('榮', '荣'), ('構', '构'), ('槍', '枪'), ('槳', '桨'), ('樁', '桩'), ('樂', '乐'),
+	('樓', '楼'), ('標', '标'), ('樞', '枢'), ('樣', '样'), ('樸', '朴'), ('樹', '树'), ('橋', '桥'), ('機', '机'),
+	('橢', '椭'), ('橫', '横'), ('檔', '档'), ('檢', '检'), ('檯', '台'), ('檳', '槟'), ('檸', '柠'), ('檻', '槛'),
+	('櫃', '柜'), ('櫥', '橱'), ('櫻', '樱'), ('欄', '栏'), ('權', '权'), ('欽', '钦'), ('歐', '欧'), ('歡', '欢'),
+	('歲', '岁'), ('歷', '历'), ('歸', '归'), ('殘', '残'), ('殲', '歼'), ('殺', '杀'), ('殼', '壳'), ('毀', '毁'),
+	('毆', '殴'), ('氈', '毡'), ('氣', '气'), ('氫', '氢'), ('汙', '污'), ('決', '决'), ('沒', '没'), ('沖', '冲'),
+	('況', '况'), ('洶', '汹'), ('涼', '凉'), ('淒', '凄'), ('淚', '泪'), ('淨', '净'), ('淪', '沦'), ('淵', '渊'),
+	('淺', '浅'), ('渙', '涣'), ('減', '减'), ('渦', '涡'), ('測', '测'), ('渾', '浑'), ('湊', '凑'), ('湧', '涌'),
+	('湯', '汤'), ('準', '准'), ('溝', '沟'), ('溫', '温'), ('滄', '沧'), ('滅', '灭'), ('滌', '涤'), ('滬', '沪'),
+	('滯', '滞'), ('滲', '渗'), ('滷', '卤'), ('滾', '滚'), ('滿', '满'), ('漁', '渔'), ('漚', '沤'), ('漢', '汉'),
+	('漣', '涟'), ('漬', '渍'), ('漲', '涨'), ('漸', '渐'), ('漿', '浆'), ('潑', '泼'), ('潔', '洁'), ('潛', '潜'),
+	('潤', '润'), ('潰', '溃'), ('澀', '涩'), ('澆', '浇'), ('澇', '涝'), ('澗', '涧'), ('澤', '泽'), ('澱', '淀'),
+	('濁', '浊'), ('濃', '浓'), ('濕', '湿'), ('濘', '泞'), ('濟', '济'), ('濤', '涛'), ('濫', '滥'), ('濰', '潍'),
+	('濱', '滨'), ('濺', '溅'), ('濾', '滤'), ('瀉', '泻'), ('瀏', '浏'), ('瀝', '沥'), ('瀾', '澜'), ('灑', '洒'),
+	('灘', '滩'), ('灣', '湾'), ('灤', '滦'), ('災', '灾'), ('為', '为'), ('烏', '乌'), ('烴', '烃'), ('無', '无'),
+	('煉', '炼'), ('煒', '炜'), ('煙', '烟'), ('煥', '焕'), ('煩', '烦'), ('熒', '荧'), ('熱', '热'), ('熾', '炽'),
+	('燁', '烨'), ('燈', '灯'), ('燒', '烧'), ('燙', '烫'), ('營', '营'), ('燦', '灿'), ('燭', '烛'), ('燴', '烩'),
+	('燼', '烬'), ('爍', '烁'), ('爐', '炉'), ('爛', '烂'), ('爭', '争'), ('爲', '为'), ('爺', '爷'), ('爾', '尔'),
+	('牆', '墙'), ('牽', '牵'), ('犢', '犊'), ('犧', '牺'), ('狀', '状'), ('狹', '狭'), ('狽', '狈'), ('猙', '狰'),
+	('猶', '犹'), ('獄', '狱'), ('獅', '狮'), ('獎', '奖'), ('獨', '独'), ('獰', '狞'), ('獲', '获'), ('獵', '猎'),
+	('獸', '兽'), ('獺', '獭'), ('獻', '献'), ('現', '现'), ('琺', '珐'), ('瑣', '琐'), ('瑤', '瑶'), ('瑩', '莹'),
+	('瑪', '玛'), ('環', '环'), ('瓊', '琼'), ('甕', '瓮'), ('產', '产'), ('畝', '亩'), ('畢', '毕'), ('畫', '画'),
+	('異', '异'), ('當', '当'), ('疇', '畴'), ('疊', '叠'), ('痙', '痉'), ('瘋', '疯'), ('瘍', '疡'), ('瘓', '痪'),
+	('瘡', '疮'), ('瘧', '疟'), ('療', '疗'), ('癡', '痴'), ('癢', '痒'), ('癬', '癣'), ('癰', '痈'), ('癱', '瘫'),
+	('發', '发'), ('皺', '皱'), ('盜', '盗'), ('盞', '盏'), ('盡', '尽'), ('監', '监'), ('盤', '盘'), ('盧', '卢'),
+	('眾', '众'), ('睜', '睁'), ('瞇', '眯'), ('瞞', '瞒'), ('矚', '瞩'), ('矯', '矫'), ('硯', '砚'), ('碩', '硕'),
+	('確', '确'), ('碼', '码'), ('磚', '砖'), ('礎', '础'), ('礙', '碍'), ('礦', '矿'), ('礫', '砾'), ('礬', '矾'),
+	('祿', '禄'), ('禍', '祸'), ('禮', '礼'), ('禱', '祷'), ('稅', '税'), ('稈', '秆'), ('種', '种'), ('稱', '称'),
+	('穀', '谷'), ('積', '积'), ('穎', '颖'), ('穢', '秽'), ('穩', '稳'), ('穫', '获'), ('窩', '窝'), ('窪', '洼'),
+	('窮', '穷'), ('窺', '窥'), ('竄', '窜'), ('竅', '窍'), ('竈', '灶'), ('竊', '窃'), ('競', '竞'), ('筆', '笔'),
+	('筍', '笋'), ('箋', '笺'), ('箏', '筝'), ('節', '节'), ('範', '范'), ('築', '筑'), ('篩', '筛'), ('簍', '篓'),
+	('簡', '简'), ('簽', '签'), ('簾', '帘'), ('籃', '篮'), ('籌', '筹'), ('籠', '笼'), ('籤', '签'), ('籬', '篱'),
+	('籮', '箩'), ('籲', '吁'), ('粵', '粤'), ('糞', '粪'), ('糧', '粮'), ('糾', '纠'), ('紀', '纪'), ('約', '约'),
+	('紅', '红'), ('紉', '纫'), ('紋', '纹'), ('納', '纳'), ('紐', '纽'), ('純', '纯'), ('紗', '纱'), ('紙', '纸'),
+	('級', '级'), ('紛', '纷'), ('紡', '纺'), ('細', '细'), ('紳', '绅'), ('紹', '绍'), ('終', '终'), ('組', '组'),
+	('絆', '绊'), ('結', '结'), ('絕', '绝'), ('絞', '绞'), ('絡', '络'), ('絢', '绚'), ('給', '给'), ('絨', '绒'),
+	('統', '统'), ('絲', '丝'), ('絹', '绢'), ('綁', '绑'), ('綏', '绥'), ('經', '经'), ('綜', '综'), ('綠', '绿'),
+	('綢', '绸'), ('維', '维'), ('綱', '纲'), ('網', '网'), ('綴', '缀'), ('綸', '纶'), ('綺', '绮'), ('綻', '绽'),
+	('綽', '绰'), ('綾', '绫'), ('綿', '绵'), ('緊', '紧'), ('緋', '绯'), ('緒', '绪'), ('緘', '缄'), ('線', '线'),
+	('緝', '缉'), ('緞', '缎'), ('締', '缔'), ('緣', '缘'), ('編', '编'), ('緩', '缓'), ('緬', '缅'), ('緯', '纬'),
+	('緲', '缈'), ('練', '练'), ('縛', '缚'), ('縣', '县'), ('縫', '缝'), ('縮', '缩'), ('縱', '纵'), ('縷', '缕'),
+	('縹', '缥'), ('總', '总'), ('績', '绩'), ('繃', '绷'), ('織', '织'), ('繕', '缮'), ('繞', '绕'), ('繡', '绣'),
+	('繩', '绳'), ('繪', '绘'), ('繫', '系'), ('繭', '茧'), ('繳', '缴'), ('繹', '绎'), ('繼', '继'), ('繽', '缤'),
+	('續', '续'), ('纏', '缠'), ('纓', '缨'), ('纖', '纤'), ('纜', '缆'), ('缽', '钵'), ('罰', '罚'), ('罵', '骂'),
+	('罷', '罢'), ('羅', '罗'), ('羨', '羡'), ('義', '义'), ('習', '习'), ('翹', '翘'), ('聖', '圣'), ('聞', '闻'),
+	('聯', '联'), ('聰', '聪'), ('聲', '声'), ('聳', '耸'), ('聶', '聂'), ('職', '职'), ('聽', '听'), ('聾', '聋'),
+	('肅', '肃'), ('脅', '胁'), ('脈', '脉'), ('脫', '脱'), ('脹', '胀'), ('腎', '肾'), ('腦', '脑'), ('腫', '肿'),
+	('腳', '脚'), ('腸', '肠'), ('膚', '肤'), ('膠', '胶'), ('膩', '腻'), ('膽', '胆'), ('膿', '脓'), ('臉', '脸'),
+	('臍', '脐'), ('臘', '腊'), ('臟', '脏'), ('臥', '卧'), ('臨', '临'), ('臺', '台'), ('與', '与'), ('興', '兴'),
+	('舉', '举'), ('舊', '旧'), ('艙', '舱'), ('艦', '舰'), ('艱', '艰'), ('艷', '艳'), ('芻', '刍'), ('茲', '兹'),
+	('荊', '荆'), ('莊', '庄'), ('莖', '茎'), ('莢', '荚'), ('華', '华'), ('萊', '莱'), ('萬', '万'), ('葉', '叶'),
+	('葦', '苇'), ('葷', '荤'), ('蒼', '苍'), ('蓋', '盖'), ('蓮', '莲'), ('蔣', '蒋'), ('蔥', '葱'), ('蔭', '荫'),
+	('蕎', '荞'), ('蕩', '荡'), ('蕪', '芜'), ('蕭', '萧'), ('薊', '蓟'), ('薔', '蔷'), ('薦', '荐'), ('薩', '萨'),
+	('藍', '蓝'), ('藝', '艺'), ('藥', '药'), ('藹', '蔼'), ('蘆', '芦'), ('蘇', '苏'), ('蘊', '蕴'), ('蘋', '苹'),
+	('蘭', '兰'), ('蘿', '萝'), ('處', '处'), ('虛', '虚'), ('虜', '虏'), ('號', '号'), ('虧', '亏'), ('蛻', '蜕'),
+	('蝕', '蚀'), ('蝦', '虾'), ('蝸', '蜗'), ('螞', '蚂'), ('螢', '萤'), ('蟄', '蛰'), ('蟬', '蝉'), ('蟲', '虫'),
+	('蟻', '蚁'), ('蠅', '蝇'), ('蠍', '蝎'), ('蠟', '蜡'), ('蠱', '蛊'), ('蠶', '蚕'), ('蠻', '蛮'), ('衆', '众'),
+	('術', '术'), ('衛', '卫'), ('衝', '冲'), ('裏', '里'), ('補', '补'), ('裝', '装'), ('裡', '里'), ('製', '制'),
+	('褲', '裤'), ('襖', '袄'), ('襪', '袜'), ('襯', '衬'), ('襲', '袭'), ('見', '见'), ('規', '规'), ('覓', '觅'),
+	('視', '视'), ('親', '亲'), ('覺', '觉'), ('覽', '览'), ('觀', '观'), ('觸', '触'), ('訂', '订'), ('訃', '讣'),
+	('計', '计'), ('訊', '讯'), ('討', '讨'), ('訓', '训'), ('訖', '讫'), ('記', '记'), ('訛', '讹'), ('訝', '讶'),
+	('訟', '讼'), ('訣', '诀'), ('訥', '讷'), ('訪', '访'), ('設', '设'), ('許', '许'), ('訴', '诉'), ('診', '诊'),
+	('詐', '诈'), ('評', '评'), ('詛', '诅'), ('詞', '词'), ('詠', '咏'), ('詢', '询'), ('詣', '诣'), ('試', '试'),
+	('詩', '诗'), ('詫', '诧'), ('詭', '诡'), ('話', '话'), ('該', '该'), ('詳', '详'), ('誅', '诛'), ('誇', '夸'),
+	('誌', '志'), ('認', '认'), ('誒', '诶'), ('誕', '诞'), ('誘', '诱'), ('語', '语'), ('誠', '诚'), ('誡', '诫'),
+	('誣', '诬'), ('誤', '误'), ('誦', '诵'), ('誨', '诲'), ('說', '说'), ('説', '说'), ('誰', '谁'), ('課', '课'),
+	('誹', '诽'), ('誼', '谊'), ('調', '调'), ('諄', '谆'), ('談', '谈'), ('請', '请'), ('諒', '谅'), ('論', '论'),
+	('諜', '谍'), ('諧', '谐'), ('諱', '讳'), ('諳', '谙'), ('諷', '讽'), ('諸', '诸'), ('諺', '谚'), ('諾', '诺'),
+	('謀', '谋'), ('謂', '谓'), ('謄', '誊'), ('謅', '诌'), ('謊', '谎'), ('謎', '谜'), ('謗', '谤'), ('謙', '谦'),
+	('講', '讲'), ('謝', '谢'), ('謠', '谣'), ('謫', '谪'), ('謬', '谬'), ('謹', '谨'), ('謾', '谩'), ('證', '证'),
+	('譏', '讥'), ('識', '识'), ('譚', '谭'), ('譜', '谱'), ('譯', '译'), ('議', '议'), ('譴', '谴'), ('護', '护'),
+	('譽', '誉'), ('讀', '读'), ('變', '变'), ('讒', '谗'), ('讓', '让'), ('讕', '谰'), ('豈', '岂'), ('豎', '竖'),
+	('豐', '丰'), ('豬', '猪'), ('貓', '猫'), ('貝', '贝'), ('貞', '贞'), ('負', '负'), ('財', '财'), ('貢', '贡'),
+	('貧', '贫'), ('貨', '货'), ('販', '贩'), ('貪', '贪'), ('貫', '贯'), ('責', '责'), ('貯', '贮'), ('貳', '贰'),
+	('貴', '贵'), ('貶', '贬'), ('買', '买'), ('貸', '贷'), ('費', '费'), ('貼', '贴'), ('貿', '贸'), ('賀', '贺'),
+	('賂', '赂'), ('賃', '赁'), ('賄', '贿'), ('資', '资'), ('賈', '贾'), ('賊', '贼'), ('賒', '赊'), ('賓', '宾'),
+	('賜', '赐'), ('賞', '赏'), ('賠', '赔'), ('賢', '贤'), ('賣', '卖'), ('賤', '贱'), ('賦', '赋'), ('質', '质'),
+	('賬', '账'), ('賭', '赌'), ('賴', '赖'), ('賺', '赚'), ('購', '购'), ('賽', '赛'), ('贅', '赘'), ('贈', '赠'),
+	('贊', '赞'), ('贍', '赡'), ('贏', '赢'), ('贓', '赃'), ('贖', '赎'), ('贛', '赣'), ('趕', '赶'), ('趙', '赵'),
+	('趨', '趋'), ('跡', '迹'), ('踐', '践'), ('踴', '踊'), ('蹤', '踪'), ('躍', '跃'), ('軀', '躯'), ('車', '车'),
+	('軋', '轧'), ('軌', '轨'), ('軍', '军'), ('軒', '轩'), ('軟', '软'), ('軸', '轴'), ('較', '较'), ('載', '载'),
+	('輔', '辅'), ('輕', '轻'), ('輛', '辆'), ('輝', '辉'), ('輥', '辊'), ('輩', '辈'), ('輪', '轮'), ('輯', '辑'),
+	('輸', '输'), ('輻', '辐'), ('輾', '辗'), ('輿', '舆'), ('轄', '辖'), ('轅', '辕'), ('轉', '转'), ('轍', '辙'),
+	('轎', '轿'), ('轟', '轰'), ('辦', '办'), ('辭', '辞'), ('辮', '辫'), ('辯', '辩'), ('農', '农'), ('這', '这'),
+	('連', '连'), ('週', '周'), ('進', '进'), ('遊', '游'), ('運', '运'), ('過', '过'), ('達', '达'), ('違', '违'),
+	('遜', '逊'), ('遞', '递'), ('遠', '远'), ('適', '适'), ('遲', '迟'), ('遷', '迁'), ('選', '选'), ('遺', '遗'),
+	('遼', '辽'), ('邁', '迈'), ('還', '还'), ('邊', '边'), ('邏', '逻'), ('郵', '邮'), ('鄉', '乡'), ('鄒', '邹'),
+	('鄖', '郧'), ('鄧', '邓'), ('鄭', '郑'), ('鄰', '邻'), ('鄲', '郸'), ('醜', '丑'), ('醞', '酝'), ('醫', '医'),
+	('醬', '酱'), ('釀', '酿'), ('釁', '衅'), ('釋', '释'), ('釘', '钉'), ('針', '针'), ('釣', '钓'), ('釩', '钒'),
+	('鈉', '钠'), ('鈍', '钝'), ('鈔', '钞'), ('鈕', '钮'), ('鈞', '钧'), ('鈣', '钙'), ('鈴', '铃'), ('鈺', '钰'),
+	('鈾', '铀'), ('鉀', '钾'), ('鉑', '铂'), ('鉗', '钳'), ('鉚', '铆'), ('鉛', '铅'), ('鉤', '钩'), ('鉸', '铰'),
+	('銀', '银'), ('銅', '铜'), ('銑', '铣'), ('銘', '铭'), ('銜', '衔'), ('銥', '铱'), ('銳', '锐'), ('銷', '销'),
+	('銻', '锑'), ('鋁', '铝'), ('鋅', '锌'), ('鋇', '钡'), ('鋒', '锋'), ('鋤', '锄'), ('鋪', '铺'), ('鋸', '锯'),
+	('鋼', '钢'), ('錄', '录'), ('錐', '锥'), ('錘', '锤'), ('錠', '锭'), ('錢', '钱'), ('錦', '锦'), ('錨', '锚'),
+	('錫', '锡'), ('錯', '错'), ('錳', '锰'), ('錶', '表'), ('鍋', '锅'), ('鍍', '镀'), ('鍘', '铡'), ('鍛', '锻'),
+	('鍬', '锹'), ('鍵', '键'), ('鍺', '锗'), ('鍾', '钟'), ('鎂', '镁'), ('鎊', '镑'), ('鎖', '锁'), ('鎢', '钨'),
+	('鎧', '铠'), ('鎬', '镐'), ('鎮', '镇'), ('鎳', '镍'), ('鏈', '链'), ('鏟', '铲'), ('鏡', '镜'), ('鏢', '镖'),
+	('鏽', '锈'), ('鐐', '镣'), ('鐘', '钟'), ('鐮', '镰'), ('鐳', '镭'), ('鐵', '铁'), ('鑄', '铸'), ('鑑', '鉴'),
+	('鑰', '钥'), ('鑲', '镶'), ('鑷', '镊'), ('鑼', '锣'), ('鑽', '钻'), ('鑿', '凿'), ('長', '长'), ('門', '门'),
+	('閃', '闪'), ('閉', '闭'), ('開', '开'), ('閏', '闰'), ('閒', '闲'), ('間', '间'), ('閘', '闸'), ('閡', '阂'),
+	('閣', '阁'), ('閥', '阀'), ('閨', '闺'), ('閩', '闽'), ('閱', '阅'), ('閹', '阉'), ('閻', '阎'), ('闊', '阔'),
+	('闌', '阑'), ('闖', '闯'), ('關', '关'), ('闡', '阐'), ('陝', '陕'), ('陣', '阵'), ('陰', '阴'), ('陳', '陈'),
+	('陸', '陆'), ('陽', '阳'), ('隊', '队'), ('階', '阶'), ('隕', '陨'), ('際', '际'), ('隨', '随'), ('險', '险'),
+	('隱', '隐'), ('隴', '陇'), ('隸', '隶'), ('隻', '只'), ('雖', '虽'), ('雙', '双'), ('雜', '杂'), ('雞', '鸡'),
+	('離', '离'), ('難', '难'), ('雲', '云'), ('電', '电'), ('霧', '雾'), ('靄', '霭'), ('靈', '灵'), ('靜', '静'),
+	('鞏', '巩'), ('韋', '韦'), ('韌', '韧'), ('韓', '韩'), ('韻', '韵'), ('響', '响'), ('頁', '页'), ('頂', '顶'),
+	('頃', '顷'), ('項', '项'), ('順', '顺'), ('須', '须'), ('頌', '颂'), ('預', '预'), ('頑', '顽'), ('頒', '颁'),
+	('頓', '顿'), ('頗', '颇'), ('領', '领'), ('頤', '颐'), ('頭', '头'), ('頰', '颊'), ('頸', '颈'), ('頹', '颓'),
+	('頻', '频'), ('顆', '颗'), ('題', '题'), ('額', '额'), ('顏', '颜'), ('願', '愿'), ('顛', '颠'), ('類', '类'),
+	('顧', '顾'), ('顫', '颤'), ('顯', '显'), ('顱', '颅'), ('顴', '颧'), ('風', '风'), ('颯', '飒'), ('颱', '台'),
+	('飄', '飘'), ('飛', '飞'), ('飯', '饭'), ('飲', '饮'), ('飼', '饲'), ('飽', '饱'), ('飾', '饰'), ('餃', '饺'),
+	('餅', '饼'), ('養', '养'), ('餌', '饵'), ('餒', '馁'), ('餓', '饿'), ('餘', '余'), ('餞', '饯'), ('餡', '馅'),
+	('館', '馆'), ('餾', '馏'), ('饅', '馒'), ('饋', '馈'), ('饑', '饥'), ('饒', '饶'), ('饞', '馋'), ('馬', '马'),
+	('馭', '驭'), ('馮', '冯'), ('馱', '驮'), ('馳', '驰'), ('馴', '驯'), ('駁', '驳'), ('駐', '驻'), ('駒', '驹'),
+	('駕', '驾'), ('駛', '驶'), ('駝', '驼'), ('駭', '骇'), ('駱', '骆'), ('駿', '骏'), ('騁', '骋'), ('騎', '骑'),
+	('騙', '骗'), ('騰', '腾'), ('騷', '骚'), ('騾', '骡'), ('驅', '驱'), ('驍', '骁'), ('驕', '骄'), ('驗', '验'),
+	('驚', '惊'), ('驟', '骤'), ('驢', '驴'), ('骯', '肮'), ('髒', '脏'), ('體', '体'), ('髮', '发'), ('鬆', '松'),
+	('鬚', '须'), ('鬢', '鬓'), ('鬥', '斗'), ('鬧', '闹'), ('鬱', '郁'), ('魘', '魇'), ('魚', '鱼'), ('魯', '鲁'),
+	('鮑', '鲍'), ('鮫', '鲛'), ('鮮', '鲜'), ('鯉', '鲤'), ('鯨', '鲸'), ('鰓', '鳃'), ('鱉', '鳖'), ('鱗', '鳞'),
+	('鱷', '鳄'), ('鳥', '鸟'), ('鳳', '凤'), ('鳴', '鸣'), ('鴉', '鸦'), ('鴕', '鸵'), ('鴛', '鸳'), ('鴦', '鸯'),
+	('鴨', '鸭'), ('鴻', '鸿'), ('鴿', '鸽'), ('鵑', '鹃'), ('鵝', '鹅'), ('鵬', '鹏'), ('鵲', '鹊'), ('鶯', '莺'),
+	('鶴', '鹤'), ('鷗', '鸥'), ('鷹', '鹰'), ('鸞', '鸾'), ('鹼', '碱'), ('鹽', '盐'), ('麗', '丽'), ('麥', '麦'),
+	('麵', '面'), ('麼', '么'), ('黃', '黄'), ('點', '点'), ('黨', '党'), ('齊', '齐'), ('齋', '斋'), ('齒', '齿'),
+	('齡', '龄'), ('齧', '啮'), ('齲', '龋'), ('龍', '龙'), ('龐', '庞'), ('龔', '龚'), ('龜', '龟'),
+];
+
+/// Phrases whose characters convert differently than on their own.
+#[rustfmt::skip]
+pub static S2T_PHRASES: &[(&str, &str)] = &[
+	("一只", "一隻"), ("一周", "一週"), ("万里", "萬里"), ("上游", "上游"), ("下游", "下游"), ("不准", "不准"),
+	("丑时", "丑時"), ("两只", "兩隻"), ("书签", "書籤"), ("伙伴", "夥伴"), ("公里", "公里"), ("关系", "關係"),
+	("内脏", "內臟"), ("农历", "農曆"), ("冲泡", "沖泡"), ("冲洗", "沖洗"), ("冲澡", "沖澡"), ("准许", "准許"),
+	("凶恶", "兇惡"), ("凶手", "兇手"), ("凶猛", "兇猛"), ("划船", "划船"), ("制作", "製作"), ("制品", "製品"),
+	("制造", "製造"), ("北斗", "北斗"), ("千里", "千里"), ("占据", "佔據"), ("占有", "佔有"), ("占领", "佔領"),
+	("卷发", "捲髮"), ("历法", "曆法"), ("发型", "髮型"), ("台风", "颱風"), ("周年", "週年"), ("周末", "週末"),
+	("复制", "複製"), ("复印", "複印"), ("复数", "複數"), ("复杂", "複雜"), ("天后", "天后"), ("太后", "太后"),
+	("头发", "頭髮"), ("家伙", "傢伙"), ("宽松", "寬鬆"), ("小丑", "小丑"), ("尽快", "儘快"), ("尽管", "儘管"),
+	("尽量", "儘量"), ("干净", "乾淨"), ("干扰", "干擾"), ("干杯", "乾杯"), ("干涉", "干涉"), ("干燥", "乾燥"),
+	("干脆", "乾脆"), ("干预", "干預"), ("征兆", "徵兆"), ("心脏", "心臟"), ("手表", "手錶"), ("批准", "批准"),
+	("抽签", "抽籤"), ("拉面", "拉麵"), ("收获", "收穫"), ("放松", "放鬆"), ("斗篷", "斗篷"), ("日历", "日曆"),
+	("日志", "日誌"), ("晒干", "曬乾"), ("朴实", "樸實"), ("朴素", "樸素"), ("杂志", "雜誌"), ("松开", "鬆開"),
+	("松懈", "鬆懈"), ("标签", "標籤"), ("每周", "每週"), ("毛发", "毛髮"), ("泡面", "泡麵"), ("游泳", "游泳"),
+	("漏斗", "漏斗"), ("特征", "特徵"), ("王后", "王后"), ("理发", "理髮"), ("白发", "白髮"), ("皇后", "皇后"),
+	("相干", "相干"), ("短发", "短髮"), ("稻谷", "稻穀"), ("红发", "紅髮"), ("绘制", "繪製"), ("维系", "維繫"),
+	("联系", "聯繫"), ("肝脏", "肝臟"), ("胡须", "鬍鬚"), ("舍不得", "捨不得"), ("舍弃", "捨棄"), ("若干", "若干"),
+	("英里", "英里"), ("茶几", "茶几"), ("词汇", "詞彙"), ("谷物", "穀物"), ("象征", "象徵"), ("轻松", "輕鬆"),
+	("里程", "里程"), ("重复", "重複"), ("金发", "金髮"), ("钟情", "鍾情"), ("钟爱", "鍾愛"), ("银发", "銀髮"),
+	("长发", "長髮"), ("面包", "麵包"), ("面条", "麵條"), ("面粉", "麵粉"), ("饼干", "餅乾"), ("黑发", "黑髮"),
+];
+
+/// Reverse of `S2T_PHRASES`, sorted by the traditional form.
+#[rustfmt::skip]
+pub static T2S_PHRASES: &[(&str, &str)] = &[
+	("一週", "一周"), ("一隻", "一只"), ("上游", "上游"), ("下游", "下游"), ("不准", "不准"), ("丑時", "丑时"),
+	("乾杯", "干杯"), ("乾淨", "干净"), ("乾燥", "干燥"), ("乾脆", "干脆"), ("佔據", "占据"), ("佔有", "占有"),
+	("佔領", "占领"), ("傢伙", "家伙"), ("儘快", "尽快"), ("儘管", "尽管"), ("儘量", "尽量"), ("兇惡", "凶恶"),
+	("兇手", "凶手"), ("兇猛", "凶猛"), ("內臟", "内脏"), ("兩隻", "两只"), ("公里", "公里"), ("准許", "准许"),
+	("划船", "划船"), ("北斗", "北斗"), ("千里", "千里"), ("夥伴", "伙伴"), ("天后", "天后"), ("太后", "太后"),
+	("寬鬆", "宽松"), ("小丑", "小丑"), ("干擾", "干扰"), ("干涉", "干涉"), ("干預", "干预"), ("徵兆", "征兆"),
+	("心臟", "心脏"), ("手錶", "手表"), ("批准", "批准"), ("抽籤", "抽签"), ("拉麵", "拉面"), ("捨不得", "舍不得"),
+	("捨棄", "舍弃"), ("捲髮", "卷发"), ("收穫", "收获"), ("放鬆", "放松"), ("斗篷", "斗篷"), ("日曆", "日历"),
+	("日誌", "日志"), ("曆法", "历法"), ("曬乾", "晒干"), ("書籤", "书签"), ("標籤", "标签"), ("樸實", "朴实"),
+	("樸素", "朴素"), ("每週", "每周"), ("毛髮", "毛发"), ("沖泡", "冲泡"), ("沖洗", "冲洗"), ("沖澡", "冲澡"),
+	("泡麵", "泡面"), ("游泳", "游泳"), ("漏斗", "漏斗"), ("特徵", "特征"), ("王后", "王后"), ("理髮", "理发"),
+	("白髮", "白发"), ("皇后", "皇后"), ("相干", "相干"), ("短髮", "短发"), ("稻穀", "稻谷"), ("穀物", "谷物"),
+	("紅髮", "红发"), ("維繫", "维系"), ("繪製", "绘制"), ("聯繫", "联系"), ("肝臟", "肝脏"), ("若干", "若干"),
+	("英里", "英里"), ("茶几", "茶几"), ("萬里", "万里"), ("製作", "制作"), ("製品", "制品"), ("製造", "制造"),
+	("複印", "复印"), ("複數", "复数"), ("複製", "复制"), ("複雜", "复杂"), ("詞彙", "词汇"), ("象徵", "象征"),
+	("輕鬆", "轻松"), ("農曆", "农历"), ("週年", "周年"), ("週末", "周末"), ("里程", "里程"), ("重複", "重复"),
+	("金髮", "金发"), ("銀髮", "银发"), ("鍾情", "钟情"), ("鍾愛", "钟爱"), ("長髮", "长发"), ("關係", "关系"),
+	("雜誌", "杂志"), ("頭髮", "头发"), ("颱風", "台风"), ("餅乾", "饼干"), ("髮型", "发型"), ("鬆懈", "松懈"),
+	("鬆開", "松开"), ("鬍鬚", "胡须"), ("麵包", "面包"), ("麵條", "面条"), ("麵粉", "面粉"), ("黑髮", "黑发"),
+];
@@ -0,0 +1,52 @@
+use super::*;
+use aidoku_test::aidoku_test;
+
+#[aidoku_test]
+fn to_traditional() {
+	assert_eq!(
+		convert("这个漫画的后续还没有更新", Script::Traditional),
+		"這個漫畫的後續還沒有更新"
+	);
+}
+
+#[aidoku_test]
+fn to_simplified() {
+	assert_eq!(
+		convert("這個漫畫的後續還沒有更新", Script::Simplified),
+		"这个漫画的后续还没有更新"
+	);
+}
+
+#[aidoku_test]
+fn phrases_take_precedence() {
+	assert_eq!(
+		convert("她发现头发变长了", Script::Traditional),
+		"她發現頭髮變長了"
+	);
+	assert_eq!(convert("皇后的后宫", Script::Traditional), "皇后的後宮");
+	assert_eq!(convert("乾淨的頭髮", Script::Simplified), "干净的头发");
+}
+
+#[aidoku_test]
+fn keeps_other_text() {
+	assert_eq!(
+		convert("Vol.1 第1话 ～ღ", Script::Traditional),
+		"Vol.1 第1話 ～ღ"
+	);
+	assert_eq!(convert("", Script::Simplified), "");
+}
+
+#[aidoku_test]
+fn tables_are_sorted() {
+	for chars in [table::S2T_CHARS, table::T2S_CHARS] {
+		assert!(chars.windows(2).all(|pair| pair[0].0 < pair[1].0));
+	}
+	for phrases in [table::S2T_PHRASES, table::T2S_PHRASES] {
+		assert!(phrases.windows(2).all(|pair| pair[0].0 < pair[1].0));
+		assert!(
+			phrases
+				.iter()
+				.all(|(key, _)| key.chars().count() <= table::MAX_PHRASE_LEN)
+		);
+	}
+}
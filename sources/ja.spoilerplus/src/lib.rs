@@ -187,6 +187,7 @@ impl Impl for SpoilerPlus {
 
 	fn process_page_image(
 		&self,
+		_cache: &mut Cache,
 		_params: &Params,
 		response: ImageResponse,
 		context: Option<PageContext>,
@@ -1,12 +1,19 @@
 use crate::USER_AGENT;
+use crate::models::ImageLocation;
+use crate::parser::{
+	extract_between, parse_favorite_categories, parse_gallery_id_token, parse_image_quota,
+};
 use crate::settings::{
-	build_cookie_header, get_base_url, get_domain, get_ipb_member_id, get_ipb_pass_hash,
-	refresh_igneous_from_set_cookie,
+	build_cookie_header, clear_auth, cookie_from_set_cookie, get_base_url, get_domain, get_igneous,
+	get_ipb_member_id, get_ipb_pass_hash, refresh_igneous_from_set_cookie, set_favorite_categories,
+	set_member_cookies,
 };
 use aidoku::{
 	Result,
 	alloc::{Vec, string::String, string::ToString},
+	helpers::uri::encode_uri_component,
 	imports::{
+		error::AidokuError,
 		html::Document,
 		net::{Request, Response},
 	},
@@ -85,6 +92,61 @@ pub fn eh_get_html(url: &str, cookies: &str, user_agent: &str) -> Result<Documen
 	Ok(doc)
 }
 
+const LOGIN_URL: &str = "https://forums.e-hentai.org/index.php?act=Login&CODE=01";
+
+/// Logs in through the forums and stores the member cookies. When ExHentai is
+/// the selected domain, also fetches `igneous` and fails if the account has
+/// no ExHentai access.
+pub fn login(username: &str, password: &str) -> Result<()> {
+	let body = format!(
+		"referer={}&b=&bt=&UserName={}&PassWord={}&CookieDate=1",
+		encode_uri_component("https://forums.e-hentai.org/index.php"),
+		encode_uri_component(username),
+		encode_uri_component(password)
+	);
+	let resp = Request::post(LOGIN_URL)?
+		.header("Content-Type", "application/x-www-form-urlencoded")
+		.header("User-Agent", USER_AGENT)
+		.header("Referer", "https://forums.e-hentai.org/index.php?act=Login")
+		.body(body.as_bytes())
+		.send()?;
+
+	let set_cookie = resp.get_header("Set-Cookie").unwrap_or_default();
+	let member_id = cookie_from_set_cookie(&set_cookie, "ipb_member_id");
+	let pass_hash = cookie_from_set_cookie(&set_cookie, "ipb_pass_hash");
+	let (Some(member_id), Some(pass_hash)) = (member_id, pass_hash) else {
+		let page = resp.get_string().unwrap_or_default();
+		if page.contains("captcha") {
+			bail!(
+				"E-Hentai requires a captcha to log in. Log in on the website and enter the cookies manually."
+			);
+		}
+		bail!("Incorrect username or password.");
+	};
+	set_member_cookies(&member_id, &pass_hash);
+
+	let cookies = format!("nw=1; ipb_member_id={member_id}; ipb_pass_hash={pass_hash}");
+	if let Ok(probe) = Request::get("https://exhentai.org")?
+		.header("Cookie", &cookies)
+		.header("User-Agent", USER_AGENT)
+		.send()
+		&& let Some(set_cookie) = probe.get_header("Set-Cookie")
+	{
+		refresh_igneous_from_set_cookie(&set_cookie);
+	}
+
+	// a "mystery" igneous is handed to accounts that aren't allowed in
+	let igneous = get_igneous();
+	if get_domain() == "exhentai.org" && (igneous.is_empty() || igneous == "mystery") {
+		clear_auth();
+		bail!(
+			"This account doesn't have ExHentai access. Switch the domain to E-Hentai to use it."
+		);
+	}
+
+	Ok(())
+}
+
 pub fn rewrite_domain(url: &str) -> String {
 	let domain = get_domain();
 	if url.contains("exhentai.org") {
@@ -96,6 +158,57 @@ pub fn rewrite_domain(url: &str) -> String {
 	}
 }
 
+/// Points a gallery url at e-hentai.org so keys built from it don't change with
+/// the domain setting; `rewrite_domain` turns it back before it is requested.
+pub fn canonical_domain(url: &str) -> String {
+	url.replacen("exhentai.org", "e-hentai.org", 1)
+}
+
+/// Adds the gallery to favorite category `favcat`, moves it there if it is
+/// already a favorite, or removes it when `favcat` is `"favdel"`.
+pub fn set_favorite(gallery_url: &str, favcat: &str, note: &str, cookies: &str) -> Result<()> {
+	let (gid, token) = parse_gallery_id_token(gallery_url);
+	if gid.is_empty() || token.is_empty() {
+		bail!("Invalid gallery URL");
+	}
+	let url = format!(
+		"{}/gallerypopups.php?gid={gid}&t={token}&act=addfav",
+		get_base_url()
+	);
+	let body = format!(
+		"favcat={favcat}&favnote={}&apply=Apply+Changes&update=1",
+		encode_uri_component(note)
+	);
+	let resp = Request::post(&url)?
+		.header("Content-Type", "application/x-www-form-urlencoded")
+		.header("Cookie", cookies)
+		.header("User-Agent", USER_AGENT)
+		.header("Referer", gallery_url)
+		.body(body.as_bytes())
+		.send()?;
+	if resp.status_code() >= 400 {
+		bail!("Failed to update favorites");
+	}
+	// a successful change answers with a script closing the popup, while errors
+	// still come back as 200 with the form or a message page
+	if !resp.get_string()?.contains("window.close()") {
+		bail!("Failed to update favorites");
+	}
+	Ok(())
+}
+
+/// Loads the favorite category names from `favorites.php` and caches them for
+/// the favorite listings.
+pub fn refresh_favorite_categories(cookies: &str) -> Result<Vec<(String, String)>> {
+	let url = format!("{}/favorites.php", get_base_url());
+	let categories = parse_favorite_categories(&eh_get_html(&url, cookies, USER_AGENT)?);
+	// an empty list means the page didn't load properly, so try again next time
+	if !categories.is_empty() {
+		set_favorite_categories(&categories);
+	}
+	Ok(categories)
+}
+
 pub fn get_api_url() -> &'static str {
 	"https://api.e-hentai.org/api.php"
 }
@@ -107,7 +220,7 @@ pub fn api_showpage(
 	showkey: &str,
 	nl: Option<&str>,
 	cookies: &str,
-) -> Option<(String, Option<String>)> {
+) -> Option<ImageLocation> {
 	let nl_val = nl.unwrap_or("");
 	let body = format!(
 		r#"{{"method":"showpage","gid":{gid},"imgkey":"{imgkey}","page":{page},"showkey":"{showkey}","nl":"{nl_val}"}}"#
@@ -129,8 +242,18 @@ pub fn api_showpage(
 		.get("i6")
 		.and_then(|v| v.as_str())
 		.and_then(extract_nl_from_i6);
+	let original = json
+		.get("i7")
+		.and_then(|v| v.as_str())
+		.and_then(|i7| extract_between(i7, "href=\"", "\""))
+		.filter(|href| href.contains("fullimg"))
+		.map(|href| href.replace("&amp;", "&"));
 
-	Some((img_url.to_string(), nl_out))
+	Some(ImageLocation {
+		url: img_url.to_string(),
+		nl: nl_out,
+		original,
+	})
 }
 
 pub fn extract_src_from_img_html(html: &str) -> Option<&str> {
@@ -157,7 +280,7 @@ pub fn api_imagedispatch(
 	mpvkey: &str,
 	nl: Option<&str>,
 	cookies: &str,
-) -> Option<(String, Option<String>)> {
+) -> Option<ImageLocation> {
 	let nl_val = nl.unwrap_or("");
 	let body = format!(
 		r#"{{"method":"imagedispatch","gid":{gid},"imgkey":"{imgkey}","page":{page},"mpvkey":"{mpvkey}","nl":"{nl_val}"}}"#
@@ -179,6 +302,38 @@ pub fn api_imagedispatch(
 		.and_then(|v| v.as_str())
 		.filter(|s| !s.is_empty())
 		.map(|s| s.to_string());
+	// `lf` is relative to the site, e.g. `fullimg/{gid}/{page}/{key}/{name}`
+	let original = json
+		.get("lf")
+		.and_then(|v| v.as_str())
+		.filter(|s| !s.is_empty())
+		.map(|lf| format!("{}/{}", get_base_url(), lf.trim_start_matches('/')));
+
+	Some(ImageLocation {
+		url: img_url,
+		nl: nl_out,
+		original,
+	})
+}
 
-	Some((img_url, nl_out))
+/// Whether `url` is the placeholder served once the image limit is used up.
+pub fn is_quota_placeholder(url: &str) -> bool {
+	url.ends_with("/509.gif") || url.ends_with("/509s.gif")
+}
+
+/// Reads the account's image limit usage from `home.php` as `(used, limit)`.
+pub fn fetch_image_quota(cookies: &str) -> Option<(u32, u32)> {
+	let html = eh_get_html("https://e-hentai.org/home.php", cookies, USER_AGENT).ok()?;
+	parse_image_quota(&html)
+}
+
+pub fn quota_exceeded_error(cookies: &str) -> AidokuError {
+	match fetch_image_quota(cookies) {
+		Some((used, limit)) => error!(
+			"Image limit exceeded ({used} / {limit}). Wait for it to recover or reset it on the E-Hentai home page."
+		),
+		None => error!(
+			"Image limit exceeded. Wait for it to recover or reset it on the E-Hentai home page."
+		),
+	}
 }
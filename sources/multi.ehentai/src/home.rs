@@ -130,7 +130,7 @@ fn build_and_send_gallery_scroller(
 
 impl Home for EHentai {
 	fn get_home(&self) -> Result<HomeLayout> {
-		let logged_in = !get_ipb_member_id().is_empty() && !get_ipb_pass_hash().is_empty();
+		let logged_in = is_logged_in();
 
 		let mut skeleton: Vec<HomeComponent> = vec![
 			HomeComponent {
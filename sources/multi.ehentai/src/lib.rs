@@ -1,9 +1,9 @@
 #![no_std]
 
 use aidoku::{
-	Chapter, DeepLinkHandler, DeepLinkResult, DynamicListings, FilterValue, ImageRequestProvider,
-	Listing, ListingProvider, Manga, MangaPageResult, Page, PageContent, PageContext, Result,
-	Source,
+	BasicLoginHandler, Chapter, DeepLinkHandler, DeepLinkResult, DynamicListings, DynamicSettings,
+	FilterValue, GroupSetting, ImageRequestProvider, Listing, ListingProvider, Manga,
+	MangaPageResult, NotificationHandler, Page, PageContent, PageContext, Result, Setting, Source,
 	alloc::{String, Vec, string::ToString, vec},
 	helpers::uri::{QueryParameters, encode_uri_component},
 	imports::{error::AidokuError, net::Request, std::parse_date},
@@ -17,6 +17,7 @@ mod parser;
 mod settings;
 
 use helpers::*;
+use models::EHGallery;
 use parser::*;
 use settings::*;
 const USER_AGENT: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 \
@@ -258,7 +259,7 @@ impl Source for EHentai {
 
 		let gallery = parse_gallery_detail(&html, &url);
 
-		let chapter = if needs_chapters {
+		let chapters = if needs_chapters {
 			let scanlators = gallery.as_ref().and_then(|g| {
 				if g.language.is_empty() {
 					return None;
@@ -278,21 +279,58 @@ impl Source for EHentai {
 				parse_date(&g.posted, "yyyy-MM-dd HH:mm")
 			});
 
-			Some(Chapter {
+			// each version of the gallery is a chapter, numbered along the
+			// chain so that the first upload comes first and newer uploads follow
+			let ancestors = parent_chain(gallery.as_ref().and_then(|g| g.parent.clone()), &cookies);
+			let number = ancestors.len() as f32 + 1.0;
+
+			let mut chapters: Vec<Chapter> = gallery
+				.as_ref()
+				.map(|g| {
+					g.newer_versions
+						.iter()
+						.enumerate()
+						.rev()
+						.map(|(index, version)| Chapter {
+							key: canonical_domain(&version.url),
+							title: non_empty_string(&version.title),
+							chapter_number: Some(number + 1.0 + index as f32),
+							date_uploaded: parse_date(&version.added, "yyyy-MM-dd HH:mm"),
+							url: Some(rewrite_domain(&version.url)),
+							scanlators: scanlators.clone(),
+							..Default::default()
+						})
+						.collect()
+				})
+				.unwrap_or_default();
+
+			chapters.push(Chapter {
 				key: manga.key.clone(),
-				title: gallery.as_ref().and_then(|g| {
-					if g.category.is_empty() {
-						None
-					} else {
-						Some(g.category.clone())
-					}
-				}),
-				chapter_number: Some(1.0),
+				title: gallery.as_ref().and_then(|g| non_empty_string(&g.category)),
+				chapter_number: Some(number),
 				date_uploaded,
 				url: Some(url),
 				scanlators,
 				..Default::default()
-			})
+			});
+
+			for (index, (parent, ancestor)) in ancestors.iter().enumerate() {
+				chapters.push(Chapter {
+					key: canonical_domain(parent),
+					title: ancestor
+						.as_ref()
+						.and_then(|g| non_empty_string(&g.title))
+						.or_else(|| Some("Parent version".into())),
+					chapter_number: Some(number - 1.0 - index as f32),
+					date_uploaded: ancestor
+						.as_ref()
+						.and_then(|g| parse_date(&g.posted, "yyyy-MM-dd HH:mm")),
+					url: Some(rewrite_domain(parent)),
+					..Default::default()
+				});
+			}
+
+			Some(chapters)
 		} else {
 			None
 		};
@@ -303,7 +341,7 @@ impl Source for EHentai {
 		}
 
 		if needs_chapters {
-			manga.chapters = chapter.map(|c| vec![c]);
+			manga.chapters = chapters;
 		}
 
 		Ok(manga)
@@ -405,6 +443,36 @@ impl Source for EHentai {
 	}
 }
 
+fn non_empty_string(value: &str) -> Option<String> {
+	if value.is_empty() {
+		None
+	} else {
+		Some(value.into())
+	}
+}
+
+/// Older versions of a gallery, nearest first, found by following each
+/// version's parent link.
+fn parent_chain(parent: Option<String>, cookies: &str) -> Vec<(String, Option<EHGallery>)> {
+	const MAX_DEPTH: usize = 10;
+
+	let mut chain: Vec<(String, Option<EHGallery>)> = Vec::new();
+	let mut next = parent;
+	while let Some(url) = next.take() {
+		if chain.len() >= MAX_DEPTH || chain.iter().any(|(known, _)| *known == url) {
+			break;
+		}
+		// an ancestor that fails to load still ends the chain with a chapter
+		let page_url = rewrite_domain(&url);
+		let gallery = eh_get_html(&page_url, cookies, USER_AGENT)
+			.ok()
+			.and_then(|html| parse_gallery_detail(&html, &page_url));
+		next = gallery.as_ref().and_then(|g| g.parent.clone());
+		chain.push((url, gallery));
+	}
+	chain
+}
+
 impl ListingProvider for EHentai {
 	fn get_manga_list(&self, listing: Listing, page: i32) -> Result<MangaPageResult> {
 		let base_url = get_base_url();
@@ -478,14 +546,41 @@ impl ListingProvider for EHentai {
 					format!("{base_url}/watched?{}", q)
 				}
 			}
-			_ => return Err(AidokuError::Unimplemented),
+			"favorites" => {
+				if next_param_clean.is_empty() {
+					format!("{base_url}/favorites.php")
+				} else {
+					format!("{base_url}/favorites.php?{next_param_clean}")
+				}
+			}
+			id => match id.strip_prefix("favorites:") {
+				Some(favcat) => {
+					format!("{base_url}/favorites.php?favcat={favcat}{next_param}")
+				}
+				None => return Err(AidokuError::Unimplemented),
+			},
 		};
 
 		let html = eh_get_html(&url, &cookies, USER_AGENT)?;
 
+		if cursor_id.starts_with("favorites") {
+			// the page lists the categories too, keep their names current
+			let categories = parse_favorite_categories(&html);
+			if !categories.is_empty() {
+				set_favorite_categories(&categories);
+			}
+		}
+
 		let (items, has_next, last_gid) = parse_gallery_list(&html, &base_url);
-		if let Some(gid) = last_gid {
-			set_page_cursor(cursor_id, &gid);
+		// favorites are ordered by time favorited, so the cursor has to come
+		// from the next link rather than the last gallery
+		let cursor = if cursor_id.starts_with("favorites") {
+			parse_next_page_cursor(&html)
+		} else {
+			last_gid
+		};
+		if let Some(cursor) = cursor {
+			set_page_cursor(cursor_id, &cursor);
 		}
 		let blocklist = get_blocklist();
 		Ok(items_to_manga_page(items, has_next, &blocklist))
@@ -496,12 +591,27 @@ impl DynamicListings for EHentai {
 	fn get_dynamic_listings(&self) -> Result<Vec<Listing>> {
 		let mut listings = Vec::new();
 
-		if !get_ipb_member_id().is_empty() && !get_ipb_pass_hash().is_empty() {
+		if is_logged_in() {
 			listings.push(Listing {
 				id: "watched".into(),
 				name: "Watched".into(),
 				..Default::default()
 			});
+			listings.push(Listing {
+				id: "favorites".into(),
+				name: "Favorites".into(),
+				..Default::default()
+			});
+
+			// category names are cached at login, only load them here if that failed
+			let categories = get_favorite_categories()
+				.or_else(|| refresh_favorite_categories(&build_cookie_header()).ok())
+				.unwrap_or_default();
+			listings.extend(categories.into_iter().map(|(favcat, name)| Listing {
+				id: format!("favorites:{favcat}"),
+				name,
+				..Default::default()
+			}));
 		}
 
 		listings.extend([
@@ -541,10 +651,58 @@ impl DynamicListings for EHentai {
 	}
 }
 
+impl DynamicSettings for EHentai {
+	fn get_dynamic_settings(&self) -> Result<Vec<Setting>> {
+		let mut settings: Vec<Setting> = Vec::new();
+
+		if is_logged_in() {
+			let footer = match fetch_image_quota(&build_cookie_header()) {
+				Some((used, limit)) => format!(
+					"Used {used} of {limit}. Original images count more heavily against the limit."
+				),
+				None => "Couldn't load the image limit from the E-Hentai home page.".into(),
+			};
+			settings.push(
+				GroupSetting {
+					key: "imageLimits".into(),
+					title: "Image Limits".into(),
+					items: Vec::new(),
+					footer: Some(footer.into()),
+					..Default::default()
+				}
+				.into(),
+			);
+		}
+
+		if let Some(status) = get_favorite_status() {
+			settings.push(
+				GroupSetting {
+					key: "favoriteStatus".into(),
+					title: "Favorites Status".into(),
+					items: Vec::new(),
+					footer: Some(status.into()),
+					..Default::default()
+				}
+				.into(),
+			);
+		}
+
+		Ok(settings)
+	}
+}
+
 impl ImageRequestProvider for EHentai {
 	fn get_image_request(&self, url: String, context: Option<PageContext>) -> Result<Request> {
 		let cookies = build_cookie_header();
 		let base_url = get_base_url();
+		let use_original = use_original_images();
+
+		let image_request = |img_url: &str| -> Result<Request> {
+			Ok(Request::get(img_url)?
+				.header("Cookie", &cookies)
+				.header("User-Agent", USER_AGENT)
+				.header("Referer", &base_url))
+		};
 
 		if let Some(mut ctx) = context {
 			let mode = ctx.remove("mode").unwrap_or_default();
@@ -555,68 +713,50 @@ impl ImageRequestProvider for EHentai {
 			let page: u32 = page_str.parse().unwrap_or(1);
 
 			if !imgkey.is_empty() && !gid.is_empty() {
-				if mode == "mpv" {
-					let mpvkey = ctx.remove("mpvkey").unwrap_or_default();
-					if !mpvkey.is_empty()
-						&& let Some((img_url, nl_val)) =
-							api_imagedispatch(&gid, &imgkey, page, &mpvkey, None, &cookies)
-					{
-						if img_url.contains("509.gif") || img_url.contains("509") {
-							if let Some(ref nl) = nl_val
-								&& let Some((retry_url, _)) = api_imagedispatch(
-									&gid,
-									&imgkey,
-									page,
-									&mpvkey,
-									Some(nl),
-									&cookies,
-								) {
-								return Ok(Request::get(retry_url)?
-									.header("Cookie", &cookies)
-									.header("User-Agent", USER_AGENT)
-									.header("Referer", &base_url));
-							}
-						} else {
-							return Ok(Request::get(img_url)?
-								.header("Cookie", &cookies)
-								.header("User-Agent", USER_AGENT)
-								.header("Referer", &base_url));
+				let mpvkey = ctx.remove("mpvkey").unwrap_or_default();
+				let showkey = ctx.remove("showkey").unwrap_or_default();
+				let fetch = |nl: Option<&str>| {
+					if mode == "mpv" {
+						if mpvkey.is_empty() {
+							return None;
 						}
-					}
-				} else {
-					let showkey = ctx.remove("showkey").unwrap_or_default();
-					if !showkey.is_empty()
-						&& let Some((img_url, nl_val)) =
-							api_showpage(&gid, &imgkey, page, &showkey, None, &cookies)
-					{
-						if img_url.contains("509.gif") || img_url.contains("509") {
-							if let Some(ref nl) = nl_val
-								&& let Some((retry_url, _)) =
-									api_showpage(&gid, &imgkey, page, &showkey, Some(nl), &cookies)
-							{
-								return Ok(Request::get(retry_url)?
-									.header("Cookie", &cookies)
-									.header("User-Agent", USER_AGENT)
-									.header("Referer", &base_url));
-							}
-						} else {
-							return Ok(Request::get(img_url)?
-								.header("Cookie", &cookies)
-								.header("User-Agent", USER_AGENT)
-								.header("Referer", &base_url));
+						api_imagedispatch(&gid, &imgkey, page, &mpvkey, nl, &cookies)
+					} else {
+						if showkey.is_empty() {
+							return None;
 						}
+						api_showpage(&gid, &imgkey, page, &showkey, nl, &cookies)
 					}
+				};
+
+				let mut quota_exceeded = false;
+
+				let mut location = fetch(None);
+				if let Some(loc) = &location
+					&& is_quota_placeholder(&loc.url)
+				{
+					// retry once on another image server
+					let retry = loc.nl.as_deref().and_then(|nl| fetch(Some(nl)));
+					location = retry;
+				}
+				if let Some(loc) = location {
+					if !is_quota_placeholder(&loc.url) {
+						let img_url = loc.original.filter(|_| use_original).unwrap_or(loc.url);
+						return image_request(&img_url);
+					}
+					quota_exceeded = true;
 				}
 
 				// API failed: HTML viewer page fallback
 				if let Ok(html) = eh_get_html(&viewer_url, &cookies, USER_AGENT) {
 					let img_url = parse_image_page(&html).unwrap_or_default();
-					if !img_url.is_empty() && !img_url.contains("509") {
-						return Ok(Request::get(img_url)?
-							.header("Cookie", &cookies)
-							.header("User-Agent", USER_AGENT)
-							.header("Referer", &base_url));
+					if !img_url.is_empty() && !is_quota_placeholder(&img_url) {
+						let img_url = parse_original_image(&html)
+							.filter(|_| use_original)
+							.unwrap_or(img_url);
+						return image_request(&img_url);
 					}
+					quota_exceeded |= is_quota_placeholder(&img_url);
 					if let Some(nl) = parse_nl_value(&html) {
 						let retry_viewer = if viewer_url.contains('?') {
 							format!("{}&nl={}", viewer_url, nl)
@@ -625,22 +765,25 @@ impl ImageRequestProvider for EHentai {
 						};
 						if let Ok(retry_html) = eh_get_html(&retry_viewer, &cookies, USER_AGENT) {
 							let retry_img = parse_image_page(&retry_html).unwrap_or_default();
-							if !retry_img.is_empty() {
-								return Ok(Request::get(retry_img)?
-									.header("Cookie", &cookies)
-									.header("User-Agent", USER_AGENT)
-									.header("Referer", &base_url));
+							if !retry_img.is_empty() && !is_quota_placeholder(&retry_img) {
+								let retry_img = parse_original_image(&retry_html)
+									.filter(|_| use_original)
+									.unwrap_or(retry_img);
+								return image_request(&retry_img);
 							}
+							quota_exceeded |= is_quota_placeholder(&retry_img);
 						}
 					}
 				}
+
+				// stop instead of loading placeholders once the limit is used up
+				if quota_exceeded {
+					return Err(quota_exceeded_error(&cookies));
+				}
 			}
 		}
 
-		Ok(Request::get(url)?
-			.header("Cookie", &cookies)
-			.header("User-Agent", USER_AGENT)
-			.header("Referer", &base_url))
+		image_request(&url)
 	}
 }
 
@@ -656,11 +799,84 @@ impl DeepLinkHandler for EHentai {
 	}
 }
 
+impl BasicLoginHandler for EHentai {
+	fn handle_basic_login(&self, _key: String, username: String, password: String) -> Result<bool> {
+		if username.is_empty() || password.is_empty() {
+			return Ok(false);
+		}
+		login(&username, &password)?;
+		set_just_logged_in();
+		let _ = refresh_favorite_categories(&build_cookie_header());
+		Ok(true)
+	}
+}
+
+impl NotificationHandler for EHentai {
+	fn handle_notification(&self, notification: String) {
+		if notification == "login" {
+			if !take_just_logged_in() {
+				clear_auth();
+			}
+			return;
+		}
+		if notification == "cookies" {
+			// the categories belong to the previous account
+			clear_favorite_categories();
+			return;
+		}
+		if notification != FAVORITE_GALLERY_KEY {
+			return;
+		}
+		let Some(gallery) = take_favorite_gallery() else {
+			return;
+		};
+		let status = match update_favorite(&gallery) {
+			Ok(status) => status,
+			Err(AidokuError::Message(reason)) => format!("Couldn't update favorites: {reason}"),
+			Err(_) => "Couldn't update favorites: the request failed.".into(),
+		};
+		set_favorite_status(&status);
+	}
+}
+
+/// Applies the favorites action chosen in settings to `gallery`, describing
+/// the result.
+fn update_favorite(gallery: &str) -> Result<String> {
+	if !is_logged_in() {
+		bail!("log in first.");
+	}
+	let url = if gallery.contains("e-hentai.org/g/") || gallery.contains("exhentai.org/g/") {
+		normalize_gallery_url(&rewrite_domain(gallery))
+	} else if let Some((gid, token)) = parse_gid_token(gallery) {
+		format!("{}/g/{gid}/{token}/", get_base_url())
+	} else {
+		bail!("\"{gallery}\" isn't a gallery URL or \"<gid> <token>\".");
+	};
+
+	let favcat = get_favorite_category();
+	set_favorite(&url, &favcat, &get_favorite_note(), &build_cookie_header())?;
+
+	if favcat == "favdel" {
+		return Ok(format!("Removed {url} from favorites."));
+	}
+	let name = get_favorite_categories()
+		.and_then(|categories| {
+			categories
+				.into_iter()
+				.find_map(|(id, name)| (id == favcat).then_some(name))
+		})
+		.unwrap_or_else(|| format!("Favorites {favcat}"));
+	Ok(format!("Added {url} to {name}."))
+}
+
 register_source!(
 	EHentai,
 	Home,
 	ListingProvider,
 	DeepLinkHandler,
 	ImageRequestProvider,
-	DynamicListings
+	DynamicListings,
+	BasicLoginHandler,
+	NotificationHandler,
+	DynamicSettings
 );
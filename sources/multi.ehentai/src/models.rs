@@ -30,6 +30,27 @@ pub struct EHGallery {
 	pub rating_count: i32,
 	pub visible: String,
 	pub tags: Vec<EHTag>,
+	/// URL of the gallery this one is an updated version of.
+	pub parent: Option<String>,
+	/// Newer versions of this gallery, oldest first.
+	pub newer_versions: Vec<EHGalleryVersion>,
+}
+
+/// Where an image page resolves to, as returned by the page APIs.
+pub struct ImageLocation {
+	/// The resampled image shown in the viewer.
+	pub url: String,
+	/// Token for requesting the image from another server.
+	pub nl: Option<String>,
+	/// Link to the original image, present when it differs from the resample.
+	pub original: Option<String>,
+}
+
+/// A newer upload of a gallery, listed on the gallery page.
+pub struct EHGalleryVersion {
+	pub url: String,
+	pub title: String,
+	pub added: String,
 }
 
 /// Compact gallery info parsed from gallery list pages
@@ -321,6 +342,9 @@ impl From<EHGallery> for Manga {
 		if !gallery.uploader.is_empty() {
 			desc_parts.push(format!("Uploader: {}", gallery.uploader));
 		}
+		if !gallery.newer_versions.is_empty() {
+			desc_parts.push(format!("Newer versions: {}", gallery.newer_versions.len()));
+		}
 		let description = if desc_parts.is_empty() {
 			None
 		} else {
@@ -353,7 +377,7 @@ impl From<EHGallery> for Manga {
 				ContentRating::NSFW
 			},
 			viewer,
-			update_strategy: UpdateStrategy::Never,
+			update_strategy: UpdateStrategy::Always,
 			..Default::default()
 		}
 	}
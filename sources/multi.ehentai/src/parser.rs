@@ -1,4 +1,4 @@
-use crate::models::{EHGallery, EHGalleryItem, EHTag};
+use crate::models::{EHGallery, EHGalleryItem, EHGalleryVersion, EHTag};
 use aidoku::{
 	Manga, MangaPageResult,
 	alloc::{
@@ -155,7 +155,6 @@ fn has_toplist_next_page(html: &Document) -> bool {
 	false
 }
 
-#[allow(dead_code)]
 pub fn parse_next_page_cursor(html: &Document) -> Option<String> {
 	let href = html.select_first("a#dnext")?.attr("href")?;
 	href.split("next=")
@@ -163,6 +162,33 @@ pub fn parse_next_page_cursor(html: &Document) -> Option<String> {
 		.map(|s| s.split('&').next().unwrap_or(s).to_string())
 }
 
+/// Parses the favorite category selector on `favorites.php` into
+/// `(favcat, name)` pairs, skipping the "Show All Favorites" entry.
+pub fn parse_favorite_categories(html: &Document) -> Vec<(String, String)> {
+	let mut categories = Vec::new();
+	let Some(entries) = html.select(".ido .fp") else {
+		return categories;
+	};
+	for entry in entries {
+		let Some(favcat) = entry
+			.attr("onclick")
+			.and_then(|onclick| extract_between(&onclick, "favcat=", "'").map(String::from))
+			.filter(|favcat| !favcat.is_empty() && favcat.chars().all(|c| c.is_ascii_digit()))
+		else {
+			continue;
+		};
+		let name = entry
+			.select("div")
+			.and_then(|divs| divs.last())
+			.and_then(|div| div.text())
+			.map(|text| text.trim().to_string())
+			.filter(|text| !text.is_empty())
+			.unwrap_or_else(|| format!("Favorites {favcat}"));
+		categories.push((favcat, name));
+	}
+	categories
+}
+
 fn parse_item_tags(el: &Element) -> (Vec<String>, Option<String>) {
 	let mut tags: Vec<String> = Vec::new();
 	let mut language: Option<String> = None;
@@ -402,6 +428,12 @@ pub fn parse_gallery_detail(html: &Document, gallery_url: &str) -> Option<EHGall
 					gallery.language = v.trim_end_matches("TR").trim().to_string();
 				}
 				"file size" => gallery.file_size = value.trim().to_string(),
+				"parent" => {
+					gallery.parent = row
+						.select_first(".gdt2 a")
+						.and_then(|a| a.attr("href"))
+						.map(|href| normalize_gallery_url(&href));
+				}
 				"length" => {
 					gallery.length = value
 						.trim()
@@ -471,9 +503,43 @@ pub fn parse_gallery_detail(html: &Document, gallery_url: &str) -> Option<EHGall
 		}
 	}
 
+	gallery.newer_versions = parse_newer_versions(html);
+
 	Some(gallery)
 }
 
+/// Parses the "There are newer versions of this gallery available" block,
+/// where each link is followed by `, added yyyy-MM-dd HH:mm`.
+fn parse_newer_versions(html: &Document) -> Vec<EHGalleryVersion> {
+	let Some(block) = html.select_first("#gnd") else {
+		return Vec::new();
+	};
+	// the markup following each link, so a link without a date can't take
+	// the date of the next one
+	let markup = block.html().unwrap_or_default();
+	let mut trailers = markup.split("</a>").skip(1);
+
+	block
+		.select("a")
+		.map(|links| {
+			links
+				.filter_map(|a| {
+					let trailer = trailers.next().unwrap_or_default();
+					let url = normalize_gallery_url(&a.attr("href")?);
+					let title = a.text().unwrap_or_default().trim().to_string();
+					let added = trailer
+						.split("<a")
+						.next()
+						.and_then(|text| text.split_once("added "))
+						.map(|(_, rest)| rest.trim_start().chars().take(16).collect())
+						.unwrap_or_default();
+					Some(EHGalleryVersion { url, title, added })
+				})
+				.collect()
+		})
+		.unwrap_or_default()
+}
+
 pub fn parse_gallery_pages(html: &Document) -> Vec<String> {
 	html.select("#gdt a")
 		.map(|links| links.filter_map(|a| a.attr("href")).collect())
@@ -495,6 +561,19 @@ pub fn parse_image_page(html: &Document) -> Option<String> {
 	html.select_first("#img").and_then(|img| img.attr("src"))
 }
 
+pub fn parse_original_image(html: &Document) -> Option<String> {
+	html.select_first("a[href*=fullimg]")
+		.and_then(|a| a.attr("href"))
+}
+
+/// Parses the image limit usage from `home.php` as `(used, limit)`.
+pub fn parse_image_quota(html: &Document) -> Option<(u32, u32)> {
+	let mut values = html
+		.select(".homebox strong")?
+		.filter_map(|e| e.text()?.trim().replace(',', "").parse::<u32>().ok());
+	Some((values.next()?, values.next()?))
+}
+
 pub fn parse_nl_value(html: &Document) -> Option<String> {
 	let onclick = html
 		.select_first("#loadfail")
@@ -625,7 +704,7 @@ fn extract_url_from_style(style: &str) -> Option<String> {
 	Some(style[start + 1..end].trim().to_string())
 }
 
-fn extract_between<'a>(s: &'a str, start: &str, end: &str) -> Option<&'a str> {
+pub fn extract_between<'a>(s: &'a str, start: &str, end: &str) -> Option<&'a str> {
 	let idx = s.find(start)?;
 	let after = &s[idx + start.len()..];
 	let end_idx = after.find(end)?;
@@ -664,3 +743,6 @@ pub fn items_to_manga_page(
 		has_next_page,
 	}
 }
+
+#[cfg(test)]
+mod test;
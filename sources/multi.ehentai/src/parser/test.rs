@@ -0,0 +1,60 @@
+use super::*;
+use aidoku::imports::html::Html;
+use aidoku_test::aidoku_test;
+
+const GALLERY_URL: &str = "https://e-hentai.org/g/1822345/5f0a1b2c3d/";
+
+// gallery page markup with a parent and two newer uploads
+const GALLERY_PAGE: &str = r#"<html><body>
+<div id="gd2"><h1 id="gn">[Circle (Artist)] Title [English]</h1><h1 id="gj">[サークル (作者)] タイトル [英訳]</h1></div>
+<div id="gdd"><table>
+<tr><td class="gdt1">Posted:</td><td class="gdt2">2021-01-30 12:34</td></tr>
+<tr><td class="gdt1">Parent:</td><td class="gdt2"><a href="https://e-hentai.org/g/1798765/0e1d2c3b4a/">1798765</a></td></tr>
+<tr><td class="gdt1">Length:</td><td class="gdt2">24 pages</td></tr>
+</table></div>
+<div id="gnd">There are newer versions of this gallery available:<br><br><a href="https://e-hentai.org/g/1856789/9a8b7c6d5e/">[Circle (Artist)] Title [English] [Decensored]</a>, added 2021-03-05 08:15<br><a href="https://e-hentai.org/g/2012345/1f2e3d4c5b/">[Circle (Artist)] Title [English] [Decensored] [Digital]</a>, added 2021-09-12 21:03</div>
+</body></html>"#;
+
+#[aidoku_test]
+fn gallery_versions() {
+	let html = Html::parse(GALLERY_PAGE.as_bytes()).unwrap();
+	let gallery = parse_gallery_detail(&html, GALLERY_URL).unwrap();
+
+	assert_eq!(gallery.length, 24);
+	assert_eq!(
+		gallery.parent.as_deref(),
+		Some("https://e-hentai.org/g/1798765/0e1d2c3b4a/")
+	);
+
+	let versions = gallery.newer_versions;
+	assert_eq!(versions.len(), 2);
+	assert_eq!(
+		versions[0].url,
+		"https://e-hentai.org/g/1856789/9a8b7c6d5e/"
+	);
+	assert_eq!(
+		versions[0].title,
+		"[Circle (Artist)] Title [English] [Decensored]"
+	);
+	assert_eq!(versions[0].added, "2021-03-05 08:15");
+	assert_eq!(
+		versions[1].url,
+		"https://e-hentai.org/g/2012345/1f2e3d4c5b/"
+	);
+	assert_eq!(versions[1].added, "2021-09-12 21:03");
+}
+
+#[aidoku_test]
+fn version_without_date_keeps_its_place() {
+	let html = Html::parse(
+		r#"<div id="gnd">There are newer versions of this gallery available:<br><br><a href="https://e-hentai.org/g/1856789/9a8b7c6d5e/">First</a><br><a href="https://e-hentai.org/g/2012345/1f2e3d4c5b/">Second</a>, added 2021-09-12 21:03</div>"#
+			.as_bytes(),
+	)
+	.unwrap();
+
+	let versions = parse_newer_versions(&html);
+	assert_eq!(versions.len(), 2);
+	assert_eq!(versions[0].added, "");
+	assert_eq!(versions[1].title, "Second");
+	assert_eq!(versions[1].added, "2021-09-12 21:03");
+}
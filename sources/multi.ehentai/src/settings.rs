@@ -11,6 +11,13 @@ const IGNEOUS_KEY: &str = "igneous";
 const TITLE_PREFERENCE_KEY: &str = "titlePreference";
 const LANGUAGES_KEY: &str = "language";
 const BLOCKLIST_KEY: &str = "blocklist";
+pub const FAVORITE_GALLERY_KEY: &str = "favoriteGallery";
+const FAVORITE_CATEGORY_KEY: &str = "favoriteCategory";
+const FAVORITE_NOTE_KEY: &str = "favoriteNote";
+const FAVORITE_CATEGORIES_KEY: &str = "favoriteCategories";
+const FAVORITE_STATUS_KEY: &str = "favoriteStatus";
+const JUST_LOGGED_IN_KEY: &str = "justLoggedIn";
+const IMAGE_QUALITY_KEY: &str = "imageQuality";
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum TitlePreference {
@@ -49,6 +56,12 @@ pub fn get_title_preference() -> TitlePreference {
 		.unwrap_or_default()
 }
 
+/// Whether to load original images instead of the resampled ones, which
+/// counts more heavily against the image limit.
+pub fn use_original_images() -> bool {
+	defaults_get::<String>(IMAGE_QUALITY_KEY).is_some_and(|v| v == "original")
+}
+
 /// Returns the tag blocklist as lowercase strings.
 /// Items can be plain names (e.g. `"guro"`) or namespace-prefixed (e.g. `"female:guro"`).
 pub fn get_blocklist() -> Vec<String> {
@@ -82,6 +95,94 @@ pub fn get_language_filter() -> Option<String> {
 	})
 }
 
+pub fn set_member_cookies(member_id: &str, pass_hash: &str) {
+	defaults_set(IPB_MEMBER_ID_KEY, DefaultValue::String(member_id.into()));
+	defaults_set(IPB_PASS_HASH_KEY, DefaultValue::String(pass_hash.into()));
+}
+
+pub fn clear_auth() {
+	defaults_set(IPB_MEMBER_ID_KEY, DefaultValue::Null);
+	defaults_set(IPB_PASS_HASH_KEY, DefaultValue::Null);
+	defaults_set(IGNEOUS_KEY, DefaultValue::Null);
+	clear_favorite_categories();
+	defaults_set(FAVORITE_STATUS_KEY, DefaultValue::Null);
+}
+
+pub fn set_just_logged_in() {
+	defaults_set(JUST_LOGGED_IN_KEY, DefaultValue::Bool(true));
+}
+
+/// Returns whether the login notification follows a login rather than a
+/// logout, resetting the flag.
+pub fn take_just_logged_in() -> bool {
+	let just_logged_in = defaults_get::<bool>(JUST_LOGGED_IN_KEY).unwrap_or(false);
+	defaults_set(JUST_LOGGED_IN_KEY, DefaultValue::Null);
+	just_logged_in
+}
+
+pub fn is_logged_in() -> bool {
+	!get_ipb_member_id().is_empty() && !get_ipb_pass_hash().is_empty()
+}
+
+/// Returns the favorite category selected for the favorites action: `"0"`
+/// to `"9"`, or `"favdel"` to remove the gallery.
+pub fn get_favorite_category() -> String {
+	defaults_get::<String>(FAVORITE_CATEGORY_KEY).unwrap_or_else(|| "0".into())
+}
+
+pub fn get_favorite_note() -> String {
+	defaults_get::<String>(FAVORITE_NOTE_KEY).unwrap_or_default()
+}
+
+/// Reads the gallery entered for the favorites action and clears the field
+/// so the action isn't repeated.
+pub fn take_favorite_gallery() -> Option<String> {
+	let gallery = defaults_get::<String>(FAVORITE_GALLERY_KEY)
+		.map(|s| s.trim().into())
+		.filter(|s: &String| !s.is_empty())?;
+	defaults_set(FAVORITE_GALLERY_KEY, DefaultValue::String(String::new()));
+	Some(gallery)
+}
+
+/// Returns the cached favorite categories as `(favcat, name)` pairs, or
+/// `None` if they haven't been loaded for the current account yet.
+pub fn get_favorite_categories() -> Option<Vec<(String, String)>> {
+	defaults_get::<Vec<String>>(FAVORITE_CATEGORIES_KEY)
+		.map(|entries| {
+			entries
+				.iter()
+				.filter_map(|entry| entry.split_once('\t'))
+				.map(|(favcat, name)| (favcat.into(), name.into()))
+				.collect::<Vec<_>>()
+		})
+		.filter(|categories| !categories.is_empty())
+}
+
+pub fn set_favorite_categories(categories: &[(String, String)]) {
+	defaults_set(
+		FAVORITE_CATEGORIES_KEY,
+		DefaultValue::StringArray(
+			categories
+				.iter()
+				.map(|(favcat, name)| format!("{favcat}\t{name}"))
+				.collect(),
+		),
+	);
+}
+
+pub fn clear_favorite_categories() {
+	defaults_set(FAVORITE_CATEGORIES_KEY, DefaultValue::Null);
+}
+
+/// Returns the result of the last favorites action, shown in the settings.
+pub fn get_favorite_status() -> Option<String> {
+	defaults_get::<String>(FAVORITE_STATUS_KEY).filter(|s| !s.is_empty())
+}
+
+pub fn set_favorite_status(status: &str) {
+	defaults_set(FAVORITE_STATUS_KEY, DefaultValue::String(status.into()));
+}
+
 pub fn build_cookie_header() -> String {
 	let member_id = get_ipb_member_id();
 	let pass_hash = get_ipb_pass_hash();
@@ -120,20 +221,20 @@ pub fn clear_page_cursor(listing_id: &str) {
 	defaults_set(&cursor_key(listing_id), DefaultValue::Null);
 }
 
+/// Finds the value of cookie `name` in a (possibly combined) `Set-Cookie` header.
+pub fn cookie_from_set_cookie(set_cookie: &str, name: &str) -> Option<String> {
+	set_cookie
+		.split([',', '\n'])
+		.map(str::trim)
+		.find_map(|part| part.strip_prefix(name)?.strip_prefix('='))
+		.and_then(|value| value.split(';').next())
+		.map(str::trim)
+		.filter(|v| !v.is_empty())
+		.map(String::from)
+}
+
 pub fn refresh_igneous_from_set_cookie(set_cookie: &str) {
-	for part in set_cookie.split([',', '\n']) {
-		let part = part.trim();
-		if part.starts_with("igneous=") {
-			if let Some(value) = part
-				.trim_start_matches("igneous=")
-				.split(';')
-				.next()
-				.map(str::trim)
-				.filter(|v| !v.is_empty())
-			{
-				defaults_set(IGNEOUS_KEY, DefaultValue::String(value.into()));
-			}
-			return;
-		}
+	if let Some(value) = cookie_from_set_cookie(set_cookie, "igneous") {
+		defaults_set(IGNEOUS_KEY, DefaultValue::String(value));
 	}
 }
@@ -145,6 +145,11 @@ impl Source for Hitomi {
 			});
 		}
 
+		// "related:<id>" shortcut from the gallery description
+		if let Some(ids) = related_gallery_ids(&raw_q.to_lowercase()) {
+			return gallery_page(&ids, page);
+		}
+
 		let mut positive_terms: Vec<String> = Vec::new();
 		let mut negative_terms: Vec<String> = Vec::new();
 
@@ -356,40 +361,7 @@ impl Source for Hitomi {
 			result_ids.retain(|id| negative_ids.binary_search(id).is_err());
 		}
 
-		let start = ((page - 1) * PAGE_SIZE) as usize;
-		let end = (start + PAGE_SIZE as usize).min(result_ids.len());
-		let has_next_page = end < result_ids.len();
-		let page_ids = if start < result_ids.len() {
-			&result_ids[start..end]
-		} else {
-			&[]
-		};
-
-		// Parallelize fetching galleries for the current page via batch requests.
-		let mut entries: Vec<Manga> = Vec::new();
-		if !page_ids.is_empty() {
-			let mut gallery_reqs: Vec<Request> = Vec::new();
-			for id in page_ids {
-				let url = format!("{LTN_URL}/galleries/{id}.js");
-				gallery_reqs.push(Request::get(&url)?.header("Referer", REFERER));
-			}
-
-			// send_all returns Vec<Result<Response, RequestError>>
-			let responses = Request::send_all(gallery_reqs);
-			for resp in responses.into_iter() {
-				if let Ok(r) = resp
-					&& let Ok(body) = r.get_string()
-					&& let Some(g) = parse_galleryinfo_js(body)
-				{
-					entries.push(g.into());
-				}
-			}
-		}
-
-		Ok(MangaPageResult {
-			entries,
-			has_next_page,
-		})
+		gallery_page(&result_ids, page)
 	}
 
 	fn get_manga_update(
@@ -411,15 +383,28 @@ impl Source for Hitomi {
 				.map(|l| vec![l.clone()]);
 			let date_uploaded =
 				parse_date(&gallery.date[..10.min(gallery.date.len())], "yyyy-MM-dd");
-			let chapter = Chapter {
+			let mut chapters = vec![Chapter {
 				key: manga.key.clone(),
 				chapter_number: Some(1.0),
 				date_uploaded,
 				url: Some(format!("{BASE_URL}/reader/{id}.html")),
 				scanlators,
 				..Default::default()
-			};
-			Some(vec![chapter])
+			}];
+			// translations of the same work, told apart by their scanlator
+			for variant in gallery.languages.iter().flatten() {
+				if variant.galleryid == manga.key || variant.galleryid.is_empty() {
+					continue;
+				}
+				chapters.push(Chapter {
+					key: variant.galleryid.clone(),
+					chapter_number: Some(1.0),
+					url: Some(format!("{BASE_URL}/reader/{}.html", variant.galleryid)),
+					scanlators: Some(vec![variant.name.clone()]),
+					..Default::default()
+				});
+			}
+			Some(chapters)
 		} else {
 			None
 		};
@@ -500,6 +485,43 @@ impl ListingProvider for Hitomi {
 	}
 }
 
+/// Fetches the galleries for `page` of `ids` in one batch.
+fn gallery_page(ids: &[i64], page: i32) -> Result<MangaPageResult> {
+	let start = ((page - 1) * PAGE_SIZE) as usize;
+	let end = (start + PAGE_SIZE as usize).min(ids.len());
+	let has_next_page = end < ids.len();
+	let page_ids = if start < ids.len() {
+		&ids[start..end]
+	} else {
+		&[]
+	};
+
+	let mut entries: Vec<Manga> = Vec::new();
+	if !page_ids.is_empty() {
+		let mut gallery_reqs: Vec<Request> = Vec::new();
+		for id in page_ids {
+			let url = format!("{LTN_URL}/galleries/{id}.js");
+			gallery_reqs.push(Request::get(&url)?.header("Referer", REFERER));
+		}
+
+		// send_all returns Vec<Result<Response, RequestError>>
+		let responses = Request::send_all(gallery_reqs);
+		for resp in responses.into_iter() {
+			if let Ok(r) = resp
+				&& let Ok(body) = r.get_string()
+				&& let Some(g) = parse_galleryinfo_js(body)
+			{
+				entries.push(g.into());
+			}
+		}
+	}
+
+	Ok(MangaPageResult {
+		entries,
+		has_next_page,
+	})
+}
+
 /// Extract a gallery ID from any hitomi.la URL format:
 /// - https://hitomi.la/reader/123456.html
 /// - https://hitomi.la/g/123456/
@@ -207,6 +207,23 @@ impl From<HitomiGallery> for Manga {
 			let s: Vec<&str> = characters.iter().map(|c| c.character.as_str()).collect();
 			description_parts.push(format!("Characters: {}", s.join(", ")));
 		}
+		if let Some(related) = &g.related
+			&& !related.is_empty()
+		{
+			description_parts.push(format!(
+				"Related: {} galleries (search \"related:{}\")",
+				related.len(),
+				g.id
+			));
+		}
+		if let Some(parodys) = &g.parodys
+			&& let Some(series) = parodys.iter().find(|p| p.parody != "original")
+		{
+			description_parts.push(format!(
+				"Same series: search \"series:{}\"",
+				series.parody.replace(' ', "_")
+			));
+		}
 
 		let title = match title_preference {
 			TitlePreference::Japanese => {
@@ -6,7 +6,7 @@ use aidoku::{
 };
 use sha2::{Digest, Sha256};
 
-use crate::{LTN_URL, PAGE_SIZE, REFERER};
+use crate::{LTN_URL, PAGE_SIZE, REFERER, gg::fetch_gallery};
 
 pub fn decode_nozomi(data: &[u8]) -> Vec<i64> {
 	data.chunks_exact(4)
@@ -231,6 +231,13 @@ fn fetch_galleryids_from_data(version: &str, offset: u64, length: u32) -> Option
 	Some(ids)
 }
 
+/// Resolves the `related:<id>` search shortcut to the galleries listed as
+/// related to gallery `<id>`.
+pub fn related_gallery_ids(query: &str) -> Option<Vec<i64>> {
+	let id = query.strip_prefix("related:")?.trim().parse::<i64>().ok()?;
+	Some(fetch_gallery(id)?.related.unwrap_or_default())
+}
+
 pub fn search_plain_text(term: &str) -> Option<Vec<i64>> {
 	let normalized = term.replace('_', " ").to_lowercase();
 	let key = hash_term(&normalized);
@@ -0,0 +1,76 @@
+use crate::{API_URL, USER_AGENT, models::*};
+use aidoku::{
+	HashMap, Result,
+	alloc::{String, Vec},
+	imports::{
+		defaults::{DefaultValue, defaults_get, defaults_set},
+		net::Request,
+	},
+	prelude::*,
+};
+
+const SESSION_KEY: &str = "session";
+const CSRF_TOKEN_KEY: &str = "csrfToken";
+const ACCOUNT_BLACKLIST_KEY: &str = "accountBlacklist";
+
+pub fn is_logged_in() -> bool {
+	defaults_get::<String>(SESSION_KEY).is_some_and(|s| !s.is_empty())
+}
+
+pub fn handle_login(cookies: HashMap<String, String>) -> Result<bool> {
+	let Some(session) = cookies.get("sessionid") else {
+		return Ok(false);
+	};
+	defaults_set(SESSION_KEY, DefaultValue::String(session.clone()));
+	if let Some(csrf_token) = cookies.get("csrftoken") {
+		defaults_set(CSRF_TOKEN_KEY, DefaultValue::String(csrf_token.clone()));
+	}
+	// a failed blacklist fetch shouldn't fail the login itself
+	let _ = refresh_blacklist();
+	Ok(true)
+}
+
+pub fn logout() {
+	defaults_set(SESSION_KEY, DefaultValue::Null);
+	defaults_set(CSRF_TOKEN_KEY, DefaultValue::Null);
+	defaults_set(ACCOUNT_BLACKLIST_KEY, DefaultValue::Null);
+}
+
+fn authed_request(url: &str) -> Result<Request> {
+	let session = defaults_get::<String>(SESSION_KEY)
+		.filter(|s| !s.is_empty())
+		.ok_or(error!("Log in to nhentai in the source settings first"))?;
+	let mut cookie = format!("sessionid={session}");
+	if let Some(csrf_token) = defaults_get::<String>(CSRF_TOKEN_KEY) {
+		cookie.push_str(&format!("; csrftoken={csrf_token}"));
+	}
+	Ok(Request::get(url)?
+		.header("User-Agent", USER_AGENT)
+		.header("Cookie", &cookie))
+}
+
+pub fn get_favorites(page: i32) -> Result<NHentaiSearchResponse> {
+	authed_request(&format!("{API_URL}/favorites?page={page}"))?.json_owned()
+}
+
+/// Fetches the account's tag blacklist and stores it for filtering.
+pub fn refresh_blacklist() -> Result<()> {
+	let blacklist: NHentaiBlacklist =
+		authed_request(&format!("{API_URL}/blacklist"))?.json_owned()?;
+	let tags = blacklist
+		.tags
+		.into_iter()
+		.map(|tag| tag.blocklist_entry())
+		.collect();
+	defaults_set(ACCOUNT_BLACKLIST_KEY, DefaultValue::StringArray(tags));
+	Ok(())
+}
+
+/// The account blacklist as of the last refresh, in the same format as the
+/// local blocklist.
+pub fn get_account_blacklist() -> Vec<String> {
+	if !is_logged_in() {
+		return Vec::new();
+	}
+	defaults_get::<Vec<String>>(ACCOUNT_BLACKLIST_KEY).unwrap_or_default()
+}
@@ -1,4 +1,4 @@
-use crate::{API_URL, NHentai, models::NHentaiSearchResponse, settings};
+use crate::{API_URL, NHentai, auth, models::NHentaiSearchResponse, settings};
 use aidoku::{
 	Home, HomeComponent, HomeLayout, HomePartialResult, Listing, ListingKind, Manga, Result,
 	alloc::{Vec, vec},
@@ -42,17 +42,17 @@ impl Home for NHentai {
 			],
 		}));
 
-		let blocklist = settings::get_blocklist();
+		if auth::is_logged_in() {
+			// keep the account blacklist current; the last copy is used if this fails
+			let _ = auth::refresh_blacklist();
+		}
+
 		let mut query_parts = Vec::new();
 
 		if let Some(language) = settings::get_language() {
 			query_parts.push(format!("language:{language}"));
 		}
-		for blocked in blocklist.iter() {
-			if !blocked.is_empty() {
-				query_parts.push(format!("-tag:\"{blocked}\""));
-			}
-		}
+		query_parts.extend(settings::get_blocklist_terms());
 
 		let query = encode_uri_component(if query_parts.is_empty() {
 			" ".into()
@@ -1,13 +1,15 @@
 #![no_std]
 use aidoku::{
-	Chapter, DeepLinkHandler, DeepLinkResult, FilterValue, Listing, ListingProvider, Manga,
-	MangaPageResult, Page, PageContent, Result, Source,
+	Chapter, DeepLinkHandler, DeepLinkResult, DynamicListings, FilterValue, HashMap, Listing,
+	ListingProvider, Manga, MangaPageResult, NotificationHandler, Page, PageContent, Result,
+	Source, WebLoginHandler,
 	alloc::{String, Vec, string::ToString, vec},
 	helpers::uri::encode_uri_component,
-	imports::{error::AidokuError, net::Request},
+	imports::{defaults::defaults_get, error::AidokuError, net::Request},
 	prelude::*,
 };
 
+mod auth;
 mod home;
 mod models;
 mod settings;
@@ -113,11 +115,7 @@ impl Source for NHentai {
 			query_parts.push(format!("language:{language}"));
 		}
 
-		for blocked in settings::get_blocklist() {
-			if !blocked.is_empty() {
-				query_parts.push(format!("-tag:\"{blocked}\""));
-			}
-		}
+		query_parts.extend(settings::get_blocklist_terms());
 
 		let combined_query = if query_parts.is_empty() {
 			" ".into()
@@ -266,11 +264,51 @@ impl ListingProvider for NHentai {
 					ascending: false,
 				}],
 			),
+			"favorites" => {
+				let response = auth::get_favorites(page)?;
+				let entries = response
+					.result
+					.into_iter()
+					.map(|item| item.into())
+					.collect::<Vec<Manga>>();
+				Ok(MangaPageResult {
+					entries,
+					has_next_page: page < response.num_pages,
+				})
+			}
 			_ => Err(AidokuError::Unimplemented),
 		}
 	}
 }
 
+impl DynamicListings for NHentai {
+	fn get_dynamic_listings(&self) -> Result<Vec<Listing>> {
+		let mut listings = Vec::new();
+		if auth::is_logged_in() {
+			listings.push(Listing {
+				id: "favorites".into(),
+				name: "Favorites".into(),
+				..Default::default()
+			});
+		}
+		Ok(listings)
+	}
+}
+
+impl WebLoginHandler for NHentai {
+	fn handle_web_login(&self, _key: String, cookies: HashMap<String, String>) -> Result<bool> {
+		auth::handle_login(cookies)
+	}
+}
+
+impl NotificationHandler for NHentai {
+	fn handle_notification(&self, notification: String) {
+		if notification == "login" && defaults_get::<String>("login").is_none() {
+			auth::logout();
+		}
+	}
+}
+
 impl DeepLinkHandler for NHentai {
 	fn handle_deep_link(&self, url: String) -> Result<Option<DeepLinkResult>> {
 		if !url.starts_with(BASE_URL) {
@@ -293,4 +331,12 @@ impl DeepLinkHandler for NHentai {
 	}
 }
 
-register_source!(NHentai, Home, ListingProvider, DeepLinkHandler);
+register_source!(
+	NHentai,
+	Home,
+	ListingProvider,
+	DynamicListings,
+	DeepLinkHandler,
+	WebLoginHandler,
+	NotificationHandler
+);
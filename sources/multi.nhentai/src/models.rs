@@ -19,6 +19,31 @@ pub struct NHentaiTag {
 	pub slug: Option<String>,
 }
 
+#[derive(Deserialize, Debug, Clone)]
+pub struct NHentaiBlacklistTag {
+	pub name: String,
+	pub r#type: String,
+}
+
+impl NHentaiBlacklistTag {
+	/// Formats the tag like a blocklist setting entry, prefixing the
+	/// namespace for anything other than plain tags.
+	pub fn blocklist_entry(self) -> String {
+		let name = self.name.to_lowercase();
+		if self.r#type == "tag" {
+			name
+		} else {
+			format!("{}:{name}", self.r#type)
+		}
+	}
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct NHentaiBlacklist {
+	#[serde(alias = "result")]
+	pub tags: Vec<NHentaiBlacklistTag>,
+}
+
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct NHentaiCover {
 	pub path: String,
@@ -1,6 +1,8 @@
+use crate::auth;
 use aidoku::{
 	alloc::{string::String, vec::Vec},
 	imports::defaults::defaults_get,
+	prelude::*,
 };
 const TITLE_PREFERENCE_KEY: &str = "titlePreference";
 const LANGUAGE_KEY: &str = "language";
@@ -39,12 +41,40 @@ pub fn get_language() -> Option<String> {
 	})
 }
 
+/// The local blocklist merged with the account's blacklist when logged in.
 pub fn get_blocklist() -> Vec<String> {
-	defaults_get::<Vec<String>>(BLOCKLIST_KEY)
+	let mut blocklist: Vec<String> = defaults_get::<Vec<String>>(BLOCKLIST_KEY)
 		.unwrap_or_default()
 		.into_iter()
+		.chain(auth::get_account_blacklist())
 		.map(|s| s.trim().to_lowercase())
 		.filter(|s| !s.is_empty())
+		.collect();
+	blocklist.sort_unstable();
+	blocklist.dedup();
+	blocklist
+}
+
+/// Search terms excluding every blocked entry. Entries can be plain tag
+/// names or namespaced, e.g. `artist:name`.
+pub fn get_blocklist_terms() -> Vec<String> {
+	const NAMESPACES: [&str; 7] = [
+		"tag",
+		"artist",
+		"group",
+		"parody",
+		"character",
+		"language",
+		"category",
+	];
+	get_blocklist()
+		.into_iter()
+		.map(|blocked| match blocked.split_once(':') {
+			Some((namespace, name)) if NAMESPACES.contains(&namespace) => {
+				format!("-{namespace}:\"{name}\"")
+			}
+			_ => format!("-tag:\"{blocked}\""),
+		})
 		.collect()
 }
 
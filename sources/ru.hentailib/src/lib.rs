@@ -12,6 +12,7 @@ impl Impl for HentaiLib {
 	fn params(&self) -> Params {
 		Params {
 			site_id: Cow::Owned(4),
+			domains: Cow::Borrowed(&["hentailib.me", "hentailib.org"]),
 		}
 	}
 }
@@ -19,7 +20,9 @@ impl Impl for HentaiLib {
 register_source!(
 	LibGroup<HentaiLib>,
 	ListingProvider,
+	DynamicListings,
 	Home,
+	DeepLinkHandler,
 	ImageRequestProvider,
 	AlternateCoverProvider,
 	MigrationHandler
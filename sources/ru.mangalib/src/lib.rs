@@ -12,6 +12,7 @@ impl Impl for MangaLib {
 	fn params(&self) -> Params {
 		Params {
 			site_id: Cow::Owned(1),
+			domains: Cow::Borrowed(&["mangalib.me", "mangalib.org"]),
 		}
 	}
 }
@@ -19,7 +20,9 @@ impl Impl for MangaLib {
 register_source!(
 	LibGroup<MangaLib>,
 	ListingProvider,
+	DynamicListings,
 	Home,
+	DeepLinkHandler,
 	ImageRequestProvider,
 	AlternateCoverProvider,
 	MigrationHandler
@@ -12,6 +12,7 @@ impl Impl for RanobeLib {
 	fn params(&self) -> Params {
 		Params {
 			site_id: Cow::Owned(3),
+			domains: Cow::Borrowed(&["ranobelib.me", "ranobelib.org"]),
 		}
 	}
 }
@@ -19,7 +20,9 @@ impl Impl for RanobeLib {
 register_source!(
 	LibGroup<RanobeLib>,
 	ListingProvider,
+	DynamicListings,
 	Home,
+	DeepLinkHandler,
 	ImageRequestProvider,
 	AlternateCoverProvider,
 	MigrationHandler
@@ -14,6 +14,8 @@ const USERNAME_KEY: &str = "remanga.username";
 const AUTH_HINT_KEY: &str = "remanga.auth_hint";
 const JUST_LOGGED_IN_KEY: &str = "remanga.just";
 const BALANCE_KEY: &str = "remanga.balance";
+const USER_ID_KEY: &str = "remanga.user_id";
+const BALANCE_AMOUNT_KEY: &str = "remanga.balance_amount";
 
 /// Cookie names from Remanga `Re` enum / BFF credentials.
 /// JS sets `token`; BFF may also set httpOnly `serverTokenV2`.
@@ -33,6 +35,7 @@ struct UserEnvelope {
 
 #[derive(Deserialize)]
 struct UserContent {
+	id: Option<i64>,
 	username: Option<String>,
 }
 
@@ -61,6 +64,14 @@ impl FlexNumber {
 			Self::Text(v) => v.clone(),
 		}
 	}
+
+	fn as_f64(&self) -> Option<f64> {
+		match self {
+			Self::Int(v) => Some(*v as f64),
+			Self::Float(v) => Some(*v),
+			Self::Text(v) => v.trim().replace(',', ".").parse().ok(),
+		}
+	}
 }
 
 /// True when a validated session token is stored.
@@ -95,6 +106,16 @@ pub fn stored_username() -> Option<String> {
 	defaults_get::<String>(USERNAME_KEY).filter(|s| !s.is_empty())
 }
 
+/// Spendable balance from the last refresh, used to flag chapters the account can't afford.
+pub fn stored_balance_amount() -> Option<f64> {
+	defaults_get::<String>(BALANCE_AMOUNT_KEY).and_then(|s| s.parse().ok())
+}
+
+/// Account id from the last `/users/current/` refresh, used for bookmark endpoints.
+pub fn stored_user_id() -> Option<String> {
+	defaults_get::<String>(USER_ID_KEY).filter(|s| !s.is_empty())
+}
+
 /// Formatted lightning balance for the settings status footer.
 pub fn stored_balance() -> Option<String> {
 	defaults_get::<String>(BALANCE_KEY).filter(|s| !s.is_empty())
@@ -113,11 +134,13 @@ pub fn take_just_logged_in() -> bool {
 	flag
 }
 
-/// Clears token, username, balance, and auth hints.
+/// Clears token, username, user id, balance, and auth hints.
 pub fn logout() {
 	defaults_set(TOKEN_KEY, DefaultValue::Null);
 	defaults_set(USERNAME_KEY, DefaultValue::Null);
+	defaults_set(USER_ID_KEY, DefaultValue::Null);
 	defaults_set(BALANCE_KEY, DefaultValue::Null);
+	defaults_set(BALANCE_AMOUNT_KEY, DefaultValue::Null);
 	defaults_set(JUST_LOGGED_IN_KEY, DefaultValue::Null);
 	clear_auth_hint();
 }
@@ -262,7 +285,7 @@ pub fn apply_token_from_settings() -> Result<bool> {
 	}
 }
 
-/// Refreshes username and lightning balance for the settings status footer.
+/// Refreshes username, user id and lightning balance for the settings status footer.
 pub fn refresh_account_info() -> Result<()> {
 	let Some(_) = access_token() else {
 		return Ok(());
@@ -272,15 +295,37 @@ pub fn refresh_account_info() -> Result<()> {
 		.remanga()
 		.json_owned::<UserEnvelope>()
 		&& let Some(content) = user.content
-		&& let Some(name) = content.username.filter(|s| !s.is_empty())
 	{
-		defaults_set(USERNAME_KEY, DefaultValue::String(name));
+		if let Some(name) = content.username.filter(|s| !s.is_empty()) {
+			defaults_set(USERNAME_KEY, DefaultValue::String(name));
+		}
+		if let Some(id) = content.id {
+			defaults_set(USER_ID_KEY, DefaultValue::String(format!("{id}")));
+		}
 	}
 
 	if let Ok(bal) = Request::get(format!("{API_V2}/billing/lightning-balance/"))?
 		.remanga()
 		.json_owned::<BalanceResponse>()
 	{
+		let amount = match (&bal.balance_free, &bal.balance_paid) {
+			(None, None) => bal
+				.balance
+				.as_ref()
+				.or(bal.amount.as_ref())
+				.or(bal.value.as_ref())
+				.and_then(FlexNumber::as_f64),
+			(free, paid) => Some(
+				free.as_ref().and_then(FlexNumber::as_f64).unwrap_or(0.0)
+					+ paid.as_ref().and_then(FlexNumber::as_f64).unwrap_or(0.0),
+			),
+		};
+		if let Some(amount) = amount {
+			defaults_set(
+				BALANCE_AMOUNT_KEY,
+				DefaultValue::String(format!("{amount}")),
+			);
+		}
 		let text = match (bal.balance_free, bal.balance_paid) {
 			(Some(free), Some(paid)) => Some(format!(
 				"бесплатно {} · куплено {}",
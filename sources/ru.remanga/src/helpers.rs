@@ -1,14 +1,14 @@
-use crate::auth::AuthedRequest;
+use crate::auth::{AuthedRequest, refresh_account_info, stored_balance_amount, stored_user_id};
 use crate::models::{
-	Branch, CatalogResponse, ChapterPages, ChaptersResponse, SearchResponse, TitleBranches,
-	TitleCard, TitleDetail,
+	BookmarkFolder, BookmarkFolders, Branch, CatalogResponse, ChapterPages, ChaptersResponse,
+	SearchResponse, TitleBranches, TitleCard, TitleDetail, TitleEntry, TitleListResponse,
 };
 use crate::settings::API_V2;
 use aidoku::helpers::uri::{QueryParameters, encode_uri_component};
 use aidoku::imports::net::Request;
 use aidoku::imports::std::send_partial_result;
 use aidoku::{FilterValue, Manga, MangaPageResult, Page, PageContent, Result, prelude::*};
-use alloc::{format, string::String, string::ToString, vec::Vec};
+use alloc::{format, string::String, string::ToString, vec, vec::Vec};
 
 const PAGE_SIZE: i32 = 30;
 const CHAPTER_PAGE_SIZE: i32 = 50;
@@ -36,6 +36,96 @@ pub fn search(
 	catalog(page, filters)
 }
 
+/// Default bookmark folders, used when the folder list can't be fetched.
+const DEFAULT_BOOKMARK_FOLDERS: [(i64, &str); 6] = [
+	(0, "Читаю"),
+	(1, "Буду читать"),
+	(2, "Прочитано"),
+	(3, "Брошено"),
+	(4, "Отложено"),
+	(5, "Не интересно"),
+];
+
+/// Pages for the `source.json` listings, top lists and `bookmarks:<folder>` listings.
+pub fn listing(id: &str, page: i32) -> Result<MangaPageResult> {
+	let sort_field = match id {
+		"popular" => Some("views"),
+		"new" => Some("id"),
+		"updated" => Some("chapter_date"),
+		_ => None,
+	};
+	if let Some(field) = sort_field {
+		let index = ORDER_FIELDS.iter().position(|f| *f == field).unwrap_or(0);
+		return catalog(
+			page,
+			vec![FilterValue::Sort {
+				id: "order".into(),
+				index: index as i32,
+				ascending: false,
+			}],
+		);
+	}
+
+	let url = if let Some(period) = id.strip_prefix("top-") {
+		format!("{API_V2}/titles/top/?period={period}&page={page}&count={PAGE_SIZE}")
+	} else if let Some(folder) = id.strip_prefix("bookmarks:") {
+		let user_id = user_id()?;
+		format!(
+			"{API_V2}/users/{user_id}/bookmarks/?type={}&page={page}&count={PAGE_SIZE}",
+			encode_uri_component(folder)
+		)
+	} else {
+		bail!("Неизвестный список: {id}");
+	};
+
+	let response = Request::get(url)?
+		.remanga()
+		.json_owned::<TitleListResponse<TitleEntry>>()?;
+	let entries = response
+		.results
+		.unwrap_or_default()
+		.into_iter()
+		.filter_map(TitleEntry::into_manga)
+		.collect::<Vec<_>>();
+	let has_next_page =
+		response.next.as_ref().is_some_and(|n| n.has_more()) || entries.len() as i32 >= PAGE_SIZE;
+
+	Ok(MangaPageResult {
+		entries,
+		has_next_page,
+	})
+}
+
+fn user_id() -> Result<String> {
+	if let Some(id) = stored_user_id() {
+		return Ok(id);
+	}
+	// sessions from older versions were stored before the id was saved
+	refresh_account_info()?;
+	stored_user_id().ok_or_else(|| error!("Войдите в аккаунт в настройках источника"))
+}
+
+/// Bookmark folders of the logged-in account, including custom ones.
+pub fn bookmark_folders() -> Vec<BookmarkFolder> {
+	let fetched = user_id().ok().and_then(|user_id| {
+		Request::get(format!("{API_V2}/users/{user_id}/bookmarks/types/"))
+			.ok()?
+			.remanga()
+			.json_owned::<BookmarkFolders>()
+			.ok()
+	});
+	match fetched.map(BookmarkFolders::into_vec) {
+		Some(folders) if !folders.is_empty() => folders,
+		_ => DEFAULT_BOOKMARK_FOLDERS
+			.iter()
+			.map(|(id, name)| BookmarkFolder {
+				id: *id,
+				name: Some((*name).into()),
+			})
+			.collect(),
+	}
+}
+
 fn search_query(query: &str, page: i32) -> Result<MangaPageResult> {
 	let mut params = QueryParameters::new();
 	params.push("query", Some(query));
@@ -204,6 +294,7 @@ pub fn fetch_chapters(manga: &mut Manga, branches: Option<Vec<Branch>>) -> Resul
 		.and_then(|p| p.into_iter().next())
 		.and_then(|p| p.name);
 
+	let balance = stored_balance_amount();
 	let mut chapters = Vec::new();
 	let mut page = 1;
 	loop {
@@ -223,7 +314,7 @@ pub fn fetch_chapters(manga: &mut Manga, branches: Option<Vec<Branch>>) -> Resul
 		}
 		let has_more = response.next.as_ref().is_some_and(|n| n.has_more());
 		for item in batch {
-			chapters.push(item.into_chapter(&slug, label.as_deref()));
+			chapters.push(item.into_chapter(&slug, label.as_deref(), balance));
 		}
 		manga.chapters = Some(chapters.clone());
 		send_partial_result(manga);
@@ -284,7 +375,9 @@ pub fn fetch_pages(chapter_id: &str) -> Result<Vec<Page>> {
 
 	if pages.is_empty() {
 		if paid && !bought {
-			bail!("Глава платная. Войдите с покупкой или дождитесь бесплатной публикации.");
+			bail!(
+				"Глава платная. Купите её в настройках источника или дождитесь бесплатной публикации."
+			);
 		}
 		bail!("Страницы главы недоступны");
 	}
@@ -0,0 +1,79 @@
+use crate::Remanga;
+use crate::helpers::listing;
+use aidoku::{
+	Home, HomeComponent, HomeComponentValue, HomeLayout, HomePartialResult, Listing, ListingKind,
+	Manga, Result,
+	alloc::{String, Vec, vec},
+	imports::std::send_partial_result,
+};
+
+/// Home sections as (title, listing id), in display order.
+const SECTIONS: [(&str, &str); 4] = [
+	("Топ дня", "top-day"),
+	("Популярное", "popular"),
+	("Новинки", "new"),
+	("Последние обновления", "updated"),
+];
+
+fn listing_for(name: &str, id: &str, kind: ListingKind) -> Listing {
+	Listing {
+		id: id.into(),
+		name: name.into(),
+		kind,
+	}
+}
+
+fn component(index: usize, entries: Vec<Manga>) -> HomeComponent {
+	let (name, id) = SECTIONS[index];
+	let title = Some(String::from(name));
+	let value = match index {
+		0 => HomeComponentValue::BigScroller {
+			entries,
+			auto_scroll_interval: Some(8.0),
+		},
+		1 => HomeComponentValue::MangaList {
+			ranking: true,
+			page_size: Some(3),
+			entries: entries.into_iter().map(Into::into).collect(),
+			listing: Some(listing_for(name, id, ListingKind::List)),
+		},
+		_ => HomeComponentValue::Scroller {
+			entries: entries.into_iter().map(Into::into).collect(),
+			listing: Some(listing_for(name, id, ListingKind::Default)),
+		},
+	};
+	HomeComponent {
+		title,
+		subtitle: None,
+		value,
+	}
+}
+
+impl Home for Remanga {
+	fn get_home(&self) -> Result<HomeLayout> {
+		send_partial_result(&HomePartialResult::Layout(HomeLayout {
+			components: vec![
+				component(0, Vec::new()),
+				component(1, Vec::new()),
+				component(2, Vec::new()),
+				component(3, Vec::new()),
+			],
+		}));
+
+		for (index, (_, id)) in SECTIONS.iter().enumerate() {
+			// a failing section shouldn't take the rest of the home page down
+			let Ok(result) = listing(id, 1) else {
+				continue;
+			};
+			if result.entries.is_empty() {
+				continue;
+			}
+			send_partial_result(&HomePartialResult::Component(component(
+				index,
+				result.entries,
+			)));
+		}
+
+		Ok(HomeLayout::default())
+	}
+}
@@ -3,21 +3,27 @@ extern crate alloc;
 
 mod auth;
 mod helpers;
+mod home;
 mod models;
+mod purchase;
 mod settings;
 
 use crate::auth::{
 	apply_token_from_settings, auth_hint, handle_web_login, is_logged_in, logout,
 	refresh_account_info, stored_balance, stored_username, take_just_logged_in,
 };
-use crate::helpers::{fetch_chapters, fetch_manga_with_branches, fetch_pages, search};
+use crate::helpers::{
+	bookmark_folders, fetch_chapters, fetch_manga_with_branches, fetch_pages, search,
+};
+use crate::purchase::{clear_purchase_status, handle_purchase, purchase_status};
 use crate::settings::{SITE_URL, USER_AGENT};
 use aidoku::imports::net::{Request, TimeUnit, set_rate_limit};
 use aidoku::imports::std::send_partial_result;
 use aidoku::{
-	Chapter, DeepLinkHandler, DeepLinkResult, DynamicSettings, FilterValue, GroupSetting, HashMap,
-	ImageRequestProvider, Manga, MangaPageResult, NotificationHandler, Page, PageContext, Result,
-	Setting, Source, WebLoginHandler,
+	Chapter, DeepLinkHandler, DeepLinkResult, DynamicListings, DynamicSettings, FilterValue,
+	GroupSetting, HashMap, Home, ImageRequestProvider, Listing, ListingProvider, Manga,
+	MangaPageResult, NotificationHandler, Page, PageContext, Result, Setting, Source,
+	WebLoginHandler,
 	alloc::{String, Vec, format, vec},
 	prelude::*,
 };
@@ -67,6 +73,31 @@ impl Source for Remanga {
 	}
 }
 
+impl ListingProvider for Remanga {
+	fn get_manga_list(&self, listing: Listing, page: i32) -> Result<MangaPageResult> {
+		helpers::listing(&listing.id, page)
+	}
+}
+
+impl DynamicListings for Remanga {
+	fn get_dynamic_listings(&self) -> Result<Vec<Listing>> {
+		if !is_logged_in() {
+			return Ok(Vec::new());
+		}
+		Ok(bookmark_folders()
+			.into_iter()
+			.map(|folder| Listing {
+				id: format!("bookmarks:{}", folder.id),
+				name: folder
+					.name
+					.filter(|s| !s.is_empty())
+					.unwrap_or_else(|| format!("Закладки {}", folder.id)),
+				..Default::default()
+			})
+			.collect())
+	}
+}
+
 impl DeepLinkHandler for Remanga {
 	fn handle_deep_link(&self, url: String) -> Result<Option<DeepLinkResult>> {
 		// Aidoku only forwards URLs matching `source.json` base URL.
@@ -120,6 +151,7 @@ impl NotificationHandler for Remanga {
 					let _ = refresh_account_info();
 				} else {
 					logout();
+					clear_purchase_status();
 				}
 			}
 			"token.changed"
@@ -127,6 +159,7 @@ impl NotificationHandler for Remanga {
 			{
 				logout();
 			}
+			"purchase" => handle_purchase(),
 			_ => {}
 		}
 	}
@@ -134,7 +167,7 @@ impl NotificationHandler for Remanga {
 
 impl DynamicSettings for Remanga {
 	fn get_dynamic_settings(&self) -> Result<Vec<Setting>> {
-		let footer = if is_logged_in() {
+		let mut footer = if is_logged_in() {
 			let name = stored_username().unwrap_or_else(|| "аккаунт".into());
 			match stored_balance() {
 				Some(balance) => format!("Вход выполнен: {name}\nБаланс: {balance}"),
@@ -143,6 +176,9 @@ impl DynamicSettings for Remanga {
 		} else {
 			auth_hint().unwrap_or_else(|| "Вход не выполнен.".into())
 		};
+		if let Some(status) = purchase_status() {
+			footer = format!("{footer}\n{status}");
+		}
 
 		Ok(vec![
 			GroupSetting {
@@ -159,6 +195,9 @@ impl DynamicSettings for Remanga {
 
 register_source!(
 	Remanga,
+	Home,
+	ListingProvider,
+	DynamicListings,
 	DeepLinkHandler,
 	ImageRequestProvider,
 	WebLoginHandler,
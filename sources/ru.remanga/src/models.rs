@@ -15,6 +15,53 @@ pub struct CatalogResponse {
 	pub results: Option<Vec<TitleCard>>,
 }
 
+/// Title lists outside the v2 catalog (tops, bookmarks); API v1 wraps items in `content`.
+#[derive(Deserialize)]
+pub struct TitleListResponse<T> {
+	pub next: Option<NextField>,
+	#[serde(alias = "content")]
+	pub results: Option<Vec<T>>,
+}
+
+/// Bookmark rows nest the card under `title`; top lists return the card itself.
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum TitleEntry {
+	Nested { title: TitleCard },
+	Card(TitleCard),
+}
+
+impl TitleEntry {
+	pub fn into_manga(self) -> Option<Manga> {
+		match self {
+			Self::Nested { title } | Self::Card(title) => title.into_manga(),
+		}
+	}
+}
+
+#[derive(Deserialize)]
+pub struct BookmarkFolder {
+	pub id: i64,
+	pub name: Option<String>,
+}
+
+/// Folder list is a bare array on v2 and wrapped in `content` on v1.
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum BookmarkFolders {
+	List(Vec<BookmarkFolder>),
+	Wrapped(TitleListResponse<BookmarkFolder>),
+}
+
+impl BookmarkFolders {
+	pub fn into_vec(self) -> Vec<BookmarkFolder> {
+		match self {
+			Self::List(list) => list,
+			Self::Wrapped(wrapped) => wrapped.results.unwrap_or_default(),
+		}
+	}
+}
+
 #[derive(Deserialize)]
 pub struct SearchResponse {
 	pub results: Option<Vec<TitleCard>>,
@@ -230,6 +277,7 @@ pub struct ChapterPages {
 	pub pages: Option<PagesField>,
 	pub is_paid: Option<bool>,
 	pub is_bought: Option<bool>,
+	pub price: Option<FlexString>,
 	pub server: Option<ServerInfo>,
 }
 
@@ -505,7 +553,13 @@ impl TitleDetail {
 }
 
 impl ApiChapter {
-	pub fn into_chapter(self, manga_key: &str, branch_label: Option<&str>) -> Chapter {
+	/// `balance` is the account's spendable balance, if known, to flag unaffordable chapters.
+	pub fn into_chapter(
+		self,
+		manga_key: &str,
+		branch_label: Option<&str>,
+		balance: Option<f64>,
+	) -> Chapter {
 		let paid = self.is_paid.unwrap_or(false);
 		let bought = self.is_bought.unwrap_or(false);
 		let locked = paid && !bought;
@@ -515,8 +569,15 @@ impl ApiChapter {
 			title_parts.push(name);
 		}
 		if show_paid_info() && locked {
-			if let Some(price) = self.price.map(|p| p.as_string()).filter(|s| !s.is_empty()) {
-				title_parts.push(format!("{price} RM"));
+			if let Some(price) = self.price.as_ref().filter(|p| !p.as_string().is_empty()) {
+				let short = balance
+					.zip(price.as_f32())
+					.is_some_and(|(balance, price)| (price as f64) > balance);
+				if short {
+					title_parts.push(format!("{} RM (не хватает баланса)", price.as_string()));
+				} else {
+					title_parts.push(format!("{} RM", price.as_string()));
+				}
 			} else {
 				title_parts.push("Платно".into());
 			}
@@ -0,0 +1,149 @@
+use crate::auth::{AuthedRequest, is_logged_in, refresh_account_info, stored_balance_amount};
+use crate::models::ChapterPages;
+use crate::settings::{
+	API_V2, allow_purchases, clear_purchase_chapter, disable_purchases, purchase_chapter,
+};
+use aidoku::{
+	Result,
+	alloc::{String, format},
+	imports::{
+		defaults::{DefaultValue, defaults_get, defaults_set},
+		error::AidokuError,
+		net::Request,
+	},
+	prelude::*,
+};
+use serde::Deserialize;
+
+const PURCHASE_STATUS_KEY: &str = "remanga.purchase_status";
+const PENDING_PURCHASE_KEY: &str = "remanga.pending_purchase";
+
+#[derive(Deserialize)]
+struct ErrorResponse {
+	msg: Option<String>,
+	detail: Option<String>,
+}
+
+/// Result of the last purchase attempt, shown in the settings status footer.
+pub fn purchase_status() -> Option<String> {
+	defaults_get::<String>(PURCHASE_STATUS_KEY).filter(|s| !s.is_empty())
+}
+
+fn set_purchase_status(message: &str) {
+	defaults_set(PURCHASE_STATUS_KEY, DefaultValue::String(message.into()));
+}
+
+pub fn clear_purchase_status() {
+	defaults_set(PURCHASE_STATUS_KEY, DefaultValue::Null);
+	clear_pending_purchase();
+}
+
+/// Chapter whose price was shown and which the next button press buys.
+fn pending_purchase() -> Option<String> {
+	defaults_get::<String>(PENDING_PURCHASE_KEY).filter(|s| !s.is_empty())
+}
+
+fn set_pending_purchase(chapter_id: &str) {
+	defaults_set(
+		PENDING_PURCHASE_KEY,
+		DefaultValue::String(chapter_id.into()),
+	);
+}
+
+fn clear_pending_purchase() {
+	defaults_set(PENDING_PURCHASE_KEY, DefaultValue::Null);
+}
+
+/// Chapter id from a chapter link (`/manga/<slug>/<id>`) or a bare id.
+fn parse_chapter_id(input: &str) -> Option<&str> {
+	let path = input.trim().split(['?', '#']).next()?;
+	let id = path.trim_end_matches('/').rsplit('/').next()?;
+	(!id.is_empty() && id.chars().all(|c| c.is_ascii_digit())).then_some(id)
+}
+
+/// Handles the purchase button and records the outcome for the status footer.
+///
+/// The first press only shows the chapter id and price, pressing again for the
+/// same chapter buys it.
+pub fn handle_purchase() {
+	let message = match buy_entered_chapter() {
+		Ok(message) => message,
+		Err(AidokuError::Message(reason)) => {
+			clear_pending_purchase();
+			format!("Покупка не удалась: {reason}")
+		}
+		Err(_) => {
+			clear_pending_purchase();
+			"Покупка не удалась: ошибка сети".into()
+		}
+	};
+	set_purchase_status(&message);
+}
+
+fn buy_entered_chapter() -> Result<String> {
+	if !allow_purchases() {
+		bail!("включите «Разрешить покупку глав»");
+	}
+	if !is_logged_in() {
+		bail!("войдите в аккаунт");
+	}
+	let Some(input) = purchase_chapter() else {
+		bail!("вставьте ссылку на главу");
+	};
+	let Some(chapter_id) = parse_chapter_id(&input) else {
+		bail!("ссылка должна вести на главу и заканчиваться её номером");
+	};
+
+	let chapter = Request::get(format!("{API_V2}/titles/chapters/{chapter_id}/"))?
+		.remanga()
+		.json_owned::<ChapterPages>()?;
+	if !chapter.is_paid.unwrap_or(false) {
+		clear_pending_purchase();
+		return Ok(format!("Глава {chapter_id} бесплатная"));
+	}
+	if chapter.is_bought.unwrap_or(false) {
+		clear_pending_purchase();
+		return Ok(format!("Глава {chapter_id} уже куплена"));
+	}
+	// check against the last known balance before spending anything
+	let _ = refresh_account_info();
+	let Some(price) = chapter.price.as_ref().and_then(|p| p.as_f32()) else {
+		bail!("не удалось узнать цену главы {chapter_id}");
+	};
+	if let Some(balance) = stored_balance_amount()
+		&& price as f64 > balance
+	{
+		bail!("недостаточно средств ({price} RM, баланс {balance})");
+	}
+
+	if pending_purchase().as_deref() != Some(chapter_id) {
+		set_pending_purchase(chapter_id);
+		return Ok(format!(
+			"Глава {chapter_id} стоит {price} RM. Нажмите «Купить главу» ещё раз, чтобы купить её."
+		));
+	}
+
+	// every purchase needs its own confirmation
+	clear_pending_purchase();
+	disable_purchases();
+
+	let response = Request::post(format!("{API_V2}/billing/buy-chapter/"))?
+		.remanga()
+		.header("Content-Type", "application/json")
+		.body(format!("{{\"chapter\":{chapter_id}}}"))
+		.send()?;
+	let status = response.status_code();
+	if !(200..300).contains(&status) {
+		let reason = response
+			.get_json_owned::<ErrorResponse>()
+			.ok()
+			.and_then(|e| e.msg.or(e.detail))
+			.filter(|s| !s.is_empty())
+			.unwrap_or_else(|| format!("HTTP {status}"));
+		bail!("{reason}");
+	}
+
+	clear_purchase_chapter();
+	let _ = refresh_account_info();
+	Ok(format!("Глава {chapter_id} куплена за {price} RM"))
+}
@@ -1,10 +1,12 @@
-use aidoku::imports::defaults::defaults_get;
+use aidoku::imports::defaults::{DefaultValue, defaults_get, defaults_set};
 use alloc::format;
 use alloc::string::String;
 
 const SHOW_PAID_INFO_KEY: &str = "showPaidInfo";
 const ENGLISH_TITLES_KEY: &str = "englishTitles";
 const ACCESS_TOKEN_SETTING_KEY: &str = "accessToken";
+const ALLOW_PURCHASES_KEY: &str = "allowPurchases";
+const PURCHASE_CHAPTER_KEY: &str = "purchaseChapter";
 
 pub const SITE_URL: &str = "https://remanga.org";
 pub const API_V1: &str = "https://api.remanga.org/api";
@@ -28,6 +30,25 @@ pub fn settings_access_token() -> Option<String> {
 	defaults_get::<String>(ACCESS_TOKEN_SETTING_KEY).filter(|s| !s.trim().is_empty())
 }
 
+/// Purchases only go through when the user has explicitly enabled them.
+pub fn allow_purchases() -> bool {
+	defaults_get::<bool>(ALLOW_PURCHASES_KEY).unwrap_or(false)
+}
+
+/// Switches purchases back off, so every purchase has to be enabled again.
+pub fn disable_purchases() {
+	defaults_set(ALLOW_PURCHASES_KEY, DefaultValue::Bool(false));
+}
+
+/// Chapter link entered for purchase.
+pub fn purchase_chapter() -> Option<String> {
+	defaults_get::<String>(PURCHASE_CHAPTER_KEY).filter(|s| !s.trim().is_empty())
+}
+
+pub fn clear_purchase_chapter() {
+	defaults_set(PURCHASE_CHAPTER_KEY, DefaultValue::String(String::new()));
+}
+
 /// Turns a relative media path into an absolute Remanga URL.
 pub fn media_url(path: &str) -> String {
 	if path.starts_with("http://") || path.starts_with("https://") {
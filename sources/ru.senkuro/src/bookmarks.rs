@@ -0,0 +1,145 @@
+use aidoku::{
+	AidokuError, Listing, ListingKind, MangaPageResult, Result,
+	alloc::{String, Vec},
+	imports::defaults::{DefaultValue, defaults_get, defaults_get_map, defaults_set},
+	prelude::*,
+};
+use serde::de::IgnoredAny;
+
+use crate::graphql::{
+	BOOKMARKS_QUERY, BookmarksVariables, MANGA_QUERY, READ_CHAPTER_MUTATION, ReadChapterVariables,
+	SlugVariables, UPDATE_BOOKMARK_MUTATION, UpdateBookmarkVariables,
+};
+use crate::models::{BookmarkConnection, BookmarkNode, BookmarksData, MangaData};
+use crate::{AUTH_KEY, BASE_URL, Senkuro};
+
+const SYNC_PROGRESS_KEY: &str = "syncReadProgress";
+const BOOKMARK_STATUS_KEY: &str = "bookmarkStatus";
+const BOOKMARK_MANGA_KEY: &str = "bookmarkManga";
+const BOOKMARK_RESULT_KEY: &str = "bookmarkResult";
+
+/// Bookmark statuses as (GraphQL enum value, listing name).
+pub(crate) const BOOKMARK_STATUSES: [(&str, &str); 5] = [
+	("READING", "Читаю"),
+	("PLANNED", "В планах"),
+	("COMPLETED", "Прочитано"),
+	("ON_HOLD", "Отложено"),
+	("DROPPED", "Брошено"),
+];
+
+pub(crate) fn is_logged_in() -> bool {
+	defaults_get_map(AUTH_KEY).is_some_and(|cookies| !cookies.is_empty())
+}
+
+/// Outcome of the last bookmark change made from settings, shown in their footer.
+pub(crate) fn bookmark_result() -> Option<String> {
+	defaults_get::<String>(BOOKMARK_RESULT_KEY).filter(|s| !s.is_empty())
+}
+
+pub(crate) fn bookmark_listings() -> Vec<Listing> {
+	BOOKMARK_STATUSES
+		.iter()
+		.map(|(status, name)| Listing {
+			id: format!("bookmarks:{status}"),
+			name: (*name).into(),
+			kind: ListingKind::Default,
+		})
+		.collect()
+}
+
+/// Slug from a title link (`/manga/<slug>`) or a bare slug.
+fn parse_manga_slug(input: &str) -> Option<&str> {
+	let input = input.trim();
+	let path = input
+		.strip_prefix(BASE_URL)
+		.unwrap_or(input)
+		.split(['?', '#'])
+		.next()?;
+	let mut parts = path.split('/').filter(|part| !part.is_empty());
+	match parts.next()? {
+		"manga" => parts.next(),
+		slug if !slug.contains(':') => Some(slug),
+		_ => None,
+	}
+}
+
+impl Senkuro {
+	pub(crate) fn bookmark_page(&self, status: &str, page: i32) -> Result<MangaPageResult> {
+		if !is_logged_in() {
+			bail!("Senkuro: войдите в аккаунт, чтобы видеть закладки");
+		}
+
+		Self::cursor_page(
+			page,
+			|after| {
+				let data: BookmarksData =
+					self.graphql(BOOKMARKS_QUERY, BookmarksVariables { status, after })?;
+				let BookmarkConnection { edges, page_info } = data.viewer.manga_bookmarks;
+				Ok((edges, page_info))
+			},
+			|node: BookmarkNode| Self::home_manga(node.manga),
+		)
+	}
+
+	/// Marks a chapter as read on the account when progress sync is enabled.
+	///
+	/// Aidoku has no read callback, so this runs whenever the chapter's pages are
+	/// loaded, which includes downloads. That's why the sync is opt-in.
+	pub(crate) fn sync_chapter_read(&self, chapter_id: &str) {
+		let enabled = defaults_get::<bool>(SYNC_PROGRESS_KEY).unwrap_or(false);
+		if !enabled || !is_logged_in() {
+			return;
+		}
+		// progress sync is best-effort and must never block reading
+		let _ = self
+			.graphql::<IgnoredAny, _>(READ_CHAPTER_MUTATION, ReadChapterVariables { chapter_id });
+	}
+
+	/// Applies the bookmark status chosen in settings to the title link entered
+	/// there and records the outcome for the settings footer.
+	pub(crate) fn handle_bookmark_setting(&self) {
+		let message = match self.update_entered_bookmark() {
+			Ok(Some(message)) => message,
+			Ok(None) => return,
+			Err(AidokuError::Message(reason)) => {
+				format!("Не удалось обновить закладку: {reason}")
+			}
+			Err(_) => "Не удалось обновить закладку: ошибка сети".into(),
+		};
+		defaults_set(BOOKMARK_RESULT_KEY, DefaultValue::String(message));
+	}
+
+	fn update_entered_bookmark(&self) -> Result<Option<String>> {
+		let Some(link) =
+			defaults_get::<String>(BOOKMARK_MANGA_KEY).filter(|s| !s.trim().is_empty())
+		else {
+			return Ok(None);
+		};
+		defaults_set(BOOKMARK_MANGA_KEY, DefaultValue::String(String::new()));
+
+		let slug = parse_manga_slug(&link)
+			.ok_or_else(|| error!("Senkuro: не удалось распознать ссылку на тайтл"))?;
+		let data: MangaData = self.graphql(MANGA_QUERY, SlugVariables { slug })?;
+		let manga_id = data
+			.manga
+			.id
+			.ok_or_else(|| error!("Senkuro: у тайтла нет идентификатора"))?;
+
+		let status = defaults_get::<String>(BOOKMARK_STATUS_KEY)
+			.unwrap_or_else(|| BOOKMARK_STATUSES[0].0.into());
+		self.graphql::<IgnoredAny, _>(
+			UPDATE_BOOKMARK_MUTATION,
+			UpdateBookmarkVariables {
+				manga_id: &manga_id,
+				status: Some(status.as_str()).filter(|s| *s != "NONE"),
+			},
+		)?;
+
+		Ok(Some(
+			match BOOKMARK_STATUSES.iter().find(|(value, _)| *value == status) {
+				Some((_, name)) => format!("Тайтл перенесён в закладку «{name}»"),
+				None => "Тайтл убран из закладок".into(),
+			},
+		))
+	}
+}
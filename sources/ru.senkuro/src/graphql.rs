@@ -62,6 +62,24 @@ pub(crate) struct HomeVariables<'a> {
 	pub(crate) after: Option<&'a str>,
 }
 
+#[derive(Serialize)]
+pub(crate) struct BookmarksVariables<'a> {
+	pub(crate) status: &'a str,
+	pub(crate) after: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct UpdateBookmarkVariables<'a> {
+	pub(crate) manga_id: &'a str,
+	/// `None` removes the title from bookmarks.
+	pub(crate) status: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+pub(crate) struct ReadChapterVariables<'a> {
+	pub(crate) chapter_id: &'a str,
+}
+
 pub(crate) const SEARCH_QUERY: &str = r#"query Search($query: String!, $type: SearchType!) {
   search(query: $query, type: $type, first: 10) {
     edges { node {
@@ -134,6 +152,31 @@ pub(crate) const HOME_RECOMMENDATIONS_QUERY: &str = r#"query HomeRecommendations
   }
 }"#;
 
+pub(crate) const BOOKMARKS_QUERY: &str = r#"query Bookmarks(
+  $status: MangaBookmarkStatus!, $after: String
+) {
+  viewer {
+    mangaBookmarks(first: 20 after: $after status: $status) {
+      edges { node { manga {
+        slug originalName { lang content }
+        titles { lang content }
+        status rating cover { original { url } }
+      }}}
+      pageInfo { hasNextPage endCursor }
+    }
+  }
+}"#;
+
+pub(crate) const UPDATE_BOOKMARK_MUTATION: &str = r#"mutation UpdateBookmark(
+  $manga_id: ID!, $status: MangaBookmarkStatus
+) {
+  updateMangaBookmark(input: { mangaId: $manga_id, status: $status }) { __typename }
+}"#;
+
+pub(crate) const READ_CHAPTER_MUTATION: &str = r#"mutation ReadChapter($chapter_id: ID!) {
+  readMangaChapter(input: { chapterId: $chapter_id }) { __typename }
+}"#;
+
 pub(crate) const CHAPTERS_QUERY: &str = r#"query Chapters(
   $branch_id: ID!, $number: Float, $after: String, $order_by: MangaChapterOrder!
 ) {
@@ -1,24 +1,28 @@
 #![no_std]
 
+mod bookmarks;
 mod graphql;
 mod helpers;
 mod models;
 
 use aidoku::{
-	Chapter, DeepLinkHandler, DeepLinkResult, FilterValue, Home, HomeLayout, Listing,
-	ListingProvider, Manga, MangaPageResult, Page, PageContent, Result, Source, WebLoginHandler,
-	alloc::{String, Vec},
+	Chapter, DeepLinkHandler, DeepLinkResult, DynamicListings, DynamicSettings, FilterValue,
+	GroupSetting, Home, HomeLayout, Listing, ListingProvider, Manga, MangaPageResult,
+	NotificationHandler, Page, PageContent, Result, Setting, Source, WebLoginHandler,
+	alloc::{String, Vec, vec},
 	imports::{net::Request, std::send_partial_result},
 	prelude::*,
 };
 
+use crate::bookmarks::{bookmark_listings, bookmark_result, is_logged_in};
 use crate::graphql::{
 	CHAPTERS_QUERY, ChaptersVariables, HOME_LATEST_QUERY, HOME_NEW_QUERY, HOME_POPULAR_QUERY,
 	HomeVariables, MANGA_QUERY, OrderBy, READER_QUERY, ReaderVariables, SEARCH_QUERY,
 	SearchVariables, SlugVariables,
 };
 use crate::models::{
-	ChapterConnection, ChaptersData, HomeMangasData, MangaData, MangaInfo, ReaderData, SearchData,
+	ChapterConnection, ChaptersData, Edge, HomeMangasData, MangaData, MangaInfo, PageInfo,
+	ReaderData, SearchData,
 };
 
 const BASE_URL: &str = "https://senkuro.me";
@@ -29,32 +33,29 @@ const USER_AGENT: &str = "Mozilla/5.0 (iPhone; CPU iPhone OS 18_5 like Mac OS X)
 struct Senkuro;
 
 impl Senkuro {
-	fn home_manga_page(&self, query: &str, page: i32) -> Result<MangaPageResult> {
+	/// Follows the cursors of a connection up to `page`, where `fetch` loads the
+	/// edges after a cursor and `into_manga` converts the nodes of the last one.
+	fn cursor_page<T>(
+		page: i32,
+		mut fetch: impl FnMut(Option<&str>) -> Result<(Vec<Edge<T>>, PageInfo)>,
+		into_manga: impl Fn(T) -> Manga,
+	) -> Result<MangaPageResult> {
 		let mut cursor: Option<String> = None;
 		let mut current_page = 1;
 		loop {
-			let data: HomeMangasData = self.graphql(
-				query,
-				HomeVariables {
-					after: cursor.as_deref(),
-				},
-			)?;
-			let connection = data.mangas;
-			let has_next_page = connection.page_info.has_next_page;
-			let next_cursor = connection.page_info.end_cursor;
+			let (edges, page_info) = fetch(cursor.as_deref())?;
 
 			if current_page == page {
 				return Ok(MangaPageResult {
-					entries: connection
-						.edges
+					entries: edges
 						.into_iter()
-						.map(|edge| Self::home_manga(edge.node))
+						.map(|edge| into_manga(edge.node))
 						.collect(),
-					has_next_page,
+					has_next_page: page_info.has_next_page,
 				});
 			}
 
-			if !has_next_page {
+			if !page_info.has_next_page {
 				return Ok(MangaPageResult {
 					entries: Vec::new(),
 					has_next_page: false,
@@ -62,12 +63,25 @@ impl Senkuro {
 			}
 
 			cursor = Some(
-				next_cursor.ok_or_else(|| error!("Senkuro: Home listing has no next cursor"))?,
+				page_info
+					.end_cursor
+					.ok_or_else(|| error!("Senkuro: listing has no next cursor"))?,
 			);
 			current_page += 1;
 		}
 	}
 
+	fn home_manga_page(&self, query: &str, page: i32) -> Result<MangaPageResult> {
+		Self::cursor_page(
+			page,
+			|after| {
+				let data: HomeMangasData = self.graphql(query, HomeVariables { after })?;
+				Ok((data.mangas.edges, data.mangas.page_info))
+			},
+			Self::home_manga,
+		)
+	}
+
 	fn chapter_list(&self, manga: &MangaInfo) -> Result<Vec<Chapter>> {
 		let branch = manga
 			.branches
@@ -234,8 +248,10 @@ impl Source for Senkuro {
 			},
 		)?;
 
-		Ok(data
-			.manga_chapter
+		let chapter = data.manga_chapter;
+		self.sync_chapter_read(&chapter.id);
+
+		Ok(chapter
 			.pages
 			.into_iter()
 			.filter_map(|page| {
@@ -251,6 +267,9 @@ impl Source for Senkuro {
 
 impl ListingProvider for Senkuro {
 	fn get_manga_list(&self, listing: Listing, page: i32) -> Result<MangaPageResult> {
+		if let Some(status) = listing.id.strip_prefix("bookmarks:") {
+			return self.bookmark_page(status, page);
+		}
 		match listing.id.as_str() {
 			"latest-updates" => self.home_manga_page(HOME_LATEST_QUERY, page),
 			"new-titles" => self.home_manga_page(HOME_NEW_QUERY, page),
@@ -267,6 +286,41 @@ impl ListingProvider for Senkuro {
 	}
 }
 
+impl DynamicListings for Senkuro {
+	fn get_dynamic_listings(&self) -> Result<Vec<Listing>> {
+		if !is_logged_in() {
+			return Ok(Vec::new());
+		}
+		Ok(bookmark_listings())
+	}
+}
+
+impl NotificationHandler for Senkuro {
+	fn handle_notification(&self, notification: String) {
+		if notification == "bookmark" {
+			self.handle_bookmark_setting();
+		}
+	}
+}
+
+impl DynamicSettings for Senkuro {
+	fn get_dynamic_settings(&self) -> Result<Vec<Setting>> {
+		let Some(result) = bookmark_result().filter(|_| is_logged_in()) else {
+			return Ok(Vec::new());
+		};
+		Ok(vec![
+			GroupSetting {
+				key: "bookmarkFooter".into(),
+				title: "Статус".into(),
+				items: Vec::new(),
+				footer: Some(result.into()),
+				..Default::default()
+			}
+			.into(),
+		])
+	}
+}
+
 impl Home for Senkuro {
 	fn get_home(&self) -> Result<HomeLayout> {
 		let latest = self.home_manga_page(HOME_LATEST_QUERY, 1)?.entries;
@@ -408,6 +462,9 @@ register_source!(
 	Senkuro,
 	WebLoginHandler,
 	ListingProvider,
+	DynamicListings,
 	Home,
-	DeepLinkHandler
+	DeepLinkHandler,
+	NotificationHandler,
+	DynamicSettings
 );
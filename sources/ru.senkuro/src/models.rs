@@ -56,6 +56,17 @@ pub(crate) struct ReaderData {
 	pub(crate) manga_chapter: ReaderChapter,
 }
 
+#[derive(Deserialize)]
+pub(crate) struct BookmarksData {
+	pub(crate) viewer: BookmarksViewer,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct BookmarksViewer {
+	#[serde(rename = "mangaBookmarks")]
+	pub(crate) manga_bookmarks: BookmarkConnection,
+}
+
 #[derive(Deserialize)]
 pub(crate) struct SearchConnection<T> {
 	pub(crate) edges: Vec<Edge<T>>,
@@ -68,6 +79,18 @@ pub(crate) struct HomeMangaConnection {
 	pub(crate) page_info: PageInfo,
 }
 
+#[derive(Deserialize)]
+pub(crate) struct BookmarkConnection {
+	pub(crate) edges: Vec<Edge<BookmarkNode>>,
+	#[serde(rename = "pageInfo")]
+	pub(crate) page_info: PageInfo,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct BookmarkNode {
+	pub(crate) manga: HomeManga,
+}
+
 #[derive(Deserialize)]
 pub(crate) struct ChapterConnection {
 	pub(crate) edges: Vec<Edge<RemoteChapter>>,
@@ -139,6 +162,7 @@ pub(crate) struct Label {
 
 #[derive(Deserialize)]
 pub(crate) struct MangaInfo {
+	pub(crate) id: Option<String>,
 	pub(crate) slug: String,
 	pub(crate) original_name: Localized,
 	pub(crate) titles: Vec<Localized>,
@@ -173,6 +197,7 @@ pub(crate) struct RemoteChapter {
 
 #[derive(Deserialize)]
 pub(crate) struct ReaderChapter {
+	pub(crate) id: String,
 	pub(crate) pages: Vec<ReaderPage>,
 }
 
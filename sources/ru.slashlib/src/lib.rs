@@ -12,6 +12,7 @@ impl Impl for SlashLib {
 	fn params(&self) -> Params {
 		Params {
 			site_id: Cow::Owned(2),
+			domains: Cow::Borrowed(&["slashlib.me", "v2.slashlib.me"]),
 		}
 	}
 }
@@ -19,7 +20,9 @@ impl Impl for SlashLib {
 register_source!(
 	LibGroup<SlashLib>,
 	ListingProvider,
+	DynamicListings,
 	Home,
+	DeepLinkHandler,
 	ImageRequestProvider,
 	AlternateCoverProvider,
 	MigrationHandler
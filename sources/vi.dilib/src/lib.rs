@@ -21,6 +21,7 @@ impl Impl for DiLib {
 	fn params(&self) -> Params {
 		Params {
 			base_url: BASE_URL.into(),
+			detect_domain_change: true,
 			custom_headers: Some(vec![(
 				"accept-language",
 				// should accept maybe > 0.5
@@ -97,11 +98,11 @@ impl Impl for DiLib {
 				Ok(format!("{}/search.php?{}", params.base_url, query))
 			},
 
-			manga_page: |_, manga| format!("{}/{}.html", BASE_URL, manga.key),
-			page_list_page: |_, manga, chapter| {
+			manga_page: |params, manga| format!("{}/{}.html", params.base_url, manga.key),
+			page_list_page: |params, manga, chapter| {
 				format!(
 					"{}/truyen-tranh/{}-chap-{}.html",
-					BASE_URL, manga.key, chapter.key
+					params.base_url, manga.key, chapter.key
 				)
 			},
 
@@ -246,4 +247,12 @@ impl Impl for DiLib {
 	}
 }
 
-register_source!(WpComics<DiLib>, ImageRequestProvider, DeepLinkHandler, Home);
+register_source!(
+	WpComics<DiLib>,
+	ImageRequestProvider,
+	PageImageProcessor,
+	DeepLinkHandler,
+	Home,
+	BaseUrlProvider,
+	MigrationHandler
+);
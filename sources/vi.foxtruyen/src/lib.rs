@@ -20,6 +20,7 @@ impl Impl for FoxTruyen {
 	fn params(&self) -> Params {
 		Params {
 			base_url: BASE_URL.into(),
+			detect_domain_change: true,
 			cookie: Some("type_book=1".to_string()),
 			next_page: ".page_redirect > a:nth-last-child(2) > p:not(.active)",
 			viewer: Viewer::RightToLeft,
@@ -140,6 +141,9 @@ impl Impl for FoxTruyen {
 register_source!(
 	WpComics<FoxTruyen>,
 	ImageRequestProvider,
+	PageImageProcessor,
 	DeepLinkHandler,
-	Home
+	Home,
+	BaseUrlProvider,
+	MigrationHandler
 );
@@ -20,6 +20,7 @@ impl Impl for NetTruyen {
 	fn params(&self) -> Params {
 		Params {
 			base_url: BASE_URL.into(),
+			detect_domain_change: true,
 
 			next_page: "li.active + li > a[title*=\"kết quả\"]",
 			viewer: Viewer::RightToLeft,
@@ -103,6 +104,9 @@ impl Impl for NetTruyen {
 			home_manga_cover_attr: "abs:data-original",
 			time_formats: Some(vec!["%d/%m/%Y", "%m-%d-%Y", "%Y-%d-%m"]),
 
+			followed_page: Some(|params, page| format!("{}/theo-doi?page={page}", params.base_url)),
+			history_page: Some(|params, page| format!("{}/lich-su?page={page}", params.base_url)),
+
 			..Default::default()
 		}
 	}
@@ -111,6 +115,12 @@ impl Impl for NetTruyen {
 register_source!(
 	WpComics<NetTruyen>,
 	ImageRequestProvider,
+	PageImageProcessor,
+	ListingProvider,
+	DynamicListings,
+	WebLoginHandler,
 	DeepLinkHandler,
-	Home
+	Home,
+	BaseUrlProvider,
+	MigrationHandler
 );
@@ -20,6 +20,7 @@ impl Impl for Truyen3Q {
 	fn params(&self) -> Params {
 		Params {
 			base_url: BASE_URL.into(),
+			detect_domain_change: true,
 			viewer: Viewer::RightToLeft,
 
 			next_page: ".page_redirect > a:nth-last-child(2) > p:not(.active)",
@@ -98,6 +99,9 @@ impl Impl for Truyen3Q {
 register_source!(
 	WpComics<Truyen3Q>,
 	ImageRequestProvider,
+	PageImageProcessor,
 	DeepLinkHandler,
-	Home
+	Home,
+	BaseUrlProvider,
+	MigrationHandler
 );
@@ -29,6 +29,7 @@ impl Impl for TruyenQQ {
 
 		Params {
 			base_url: BASE_URL.into(),
+			detect_domain_change: true,
 			cookie,
 			viewer: Viewer::RightToLeft,
 
@@ -127,6 +128,11 @@ impl Impl for TruyenQQ {
 
 			time_formats: Some(vec!["%d/%m/%Y", "%m-%d-%Y", "%Y-%d-%m"]),
 
+			followed_page: Some(|params, page| {
+				format!("{}/truyen-dang-theo-doi/trang-{page}", params.base_url)
+			}),
+			history_page: Some(|params, page| format!("{}/lich-su/trang-{page}", params.base_url)),
+
 			..Default::default()
 		}
 	}
@@ -135,6 +141,12 @@ impl Impl for TruyenQQ {
 register_source!(
 	WpComics<TruyenQQ>,
 	ImageRequestProvider,
+	PageImageProcessor,
+	ListingProvider,
+	DynamicListings,
+	WebLoginHandler,
 	DeepLinkHandler,
-	Home
+	Home,
+	BaseUrlProvider,
+	MigrationHandler
 );
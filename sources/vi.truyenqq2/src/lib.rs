@@ -29,6 +29,7 @@ impl Impl for TruyenQQ2 {
 
 		Params {
 			base_url: BASE_URL.into(),
+			detect_domain_change: true,
 			cookie,
 			viewer: Viewer::RightToLeft,
 
@@ -128,6 +129,9 @@ impl Impl for TruyenQQ2 {
 register_source!(
 	WpComics<TruyenQQ2>,
 	ImageRequestProvider,
+	PageImageProcessor,
 	DeepLinkHandler,
-	Home
+	Home,
+	BaseUrlProvider,
+	MigrationHandler
 );
@@ -4,7 +4,7 @@ use aidoku::{
 	Result, Source, Viewer,
 	alloc::{borrow::ToOwned, string::ToString, *},
 	helpers::uri::QueryParameters,
-	imports::{html::Element, net::Request, std::send_partial_result},
+	imports::{html::Element, std::send_partial_result},
 	prelude::*,
 };
 use wpcomics::{Cache, Impl, Params, WpComics};
@@ -24,6 +24,7 @@ impl Impl for ZetTruyen {
 	fn params(&self) -> Params {
 		Params {
 			base_url: BASE_URL.into(),
+			detect_domain_change: true,
 			next_page: "a[href*=nang-cao].items-center.bg-theme-color.cursor-pointer:last-child",
 			viewer: Viewer::RightToLeft,
 			manga_cell: ".grid > a",
@@ -208,7 +209,13 @@ impl Impl for ZetTruyen {
 			}
 		}
 
-		let top = Request::get(format!("{BASE_URL}/api/comics/top"))?
+		let top = self
+			.create_request(
+				cache,
+				params,
+				&format!("{}/api/comics/top", params.base_url),
+				None,
+			)?
 			.send()?
 			.get_json::<Top>()?;
 
@@ -220,7 +227,7 @@ impl Impl for ZetTruyen {
 					.data
 					.top_all
 					.into_iter()
-					.map(|v| Manga::from(v).into())
+					.map(|v| v.into_manga(&params.base_url).into())
 					.collect::<Vec<_>>(),
 				listing: None,
 			},
@@ -233,7 +240,7 @@ impl Impl for ZetTruyen {
 					.data
 					.top_day
 					.into_iter()
-					.map(|v| Manga::from(v).into())
+					.map(|v| v.into_manga(&params.base_url).into())
 					.collect::<Vec<_>>(),
 				listing: None,
 			},
@@ -246,7 +253,7 @@ impl Impl for ZetTruyen {
 					.data
 					.top_month
 					.into_iter()
-					.map(|v| Manga::from(v).into())
+					.map(|v| v.into_manga(&params.base_url).into())
 					.collect::<Vec<_>>(),
 				listing: None,
 			},
@@ -259,13 +266,19 @@ impl Impl for ZetTruyen {
 					.data
 					.top_week
 					.into_iter()
-					.map(|v| Manga::from(v).into())
+					.map(|v| v.into_manga(&params.base_url).into())
 					.collect::<Vec<_>>(),
 				listing: None,
 			},
 		}));
 
-		let related = Request::get(format!("{BASE_URL}/api/comics/related?limit=10"))?
+		let related = self
+			.create_request(
+				cache,
+				params,
+				&format!("{}/api/comics/related?limit=10", params.base_url),
+				None,
+			)?
 			.send()?
 			.get_json::<Related>()?
 			.data;
@@ -273,7 +286,10 @@ impl Impl for ZetTruyen {
 			title: Some("Mới cập nhật".to_owned()),
 			subtitle: None,
 			value: HomeComponentValue::MangaChapterList {
-				entries: related.into_iter().map(|v| v.into()).collect::<Vec<_>>(),
+				entries: related
+					.into_iter()
+					.map(|v| v.into_manga_with_chapter(&params.base_url))
+					.collect::<Vec<_>>(),
 				page_size: Some(4),
 				listing: None,
 			},
@@ -309,17 +325,27 @@ impl Impl for ZetTruyen {
 		Ok(manga)
 	}
 
-	fn get_chapter_list(&self, _: &mut Cache, _: &Params, key: String) -> Result<Vec<Chapter>> {
+	fn get_chapter_list(
+		&self,
+		cache: &mut Cache,
+		params: &Params,
+		key: String,
+	) -> Result<Vec<Chapter>> {
 		let limit = 100;
 		let mut page = 1;
 
 		let mut chapters: Vec<Chapter> = vec![];
 		loop {
 			let url = format!(
-				"{BASE_URL}/api/comics/{key}/chapters?page={page}&per_page={limit}&order=desc",
+				"{}/api/comics/{key}/chapters?page={page}&per_page={limit}&order=desc",
+				params.base_url
 			);
 
-			let data = Request::get(url)?.send()?.get_json::<ChaptersData>()?.data;
+			let data = self
+				.create_request(cache, params, &url, None)?
+				.send()?
+				.get_json::<ChaptersData>()?
+				.data;
 			let chunk: Vec<Chapter> = data
 				.chapters
 				.into_iter()
@@ -330,7 +356,7 @@ impl Impl for ZetTruyen {
 						.unwrap_or_else(|| c.chapter_slug.replace("chapter-", "chuong-"));
 					let mut chapter: Chapter = c.into();
 
-					chapter.url = Some(format!("{BASE_URL}/truyen-tranh/{key}/{slug}"));
+					chapter.url = Some(format!("{}/truyen-tranh/{key}/{slug}", params.base_url));
 
 					chapter
 				})
@@ -353,6 +379,9 @@ impl Impl for ZetTruyen {
 register_source!(
 	WpComics<ZetTruyen>,
 	ImageRequestProvider,
+	PageImageProcessor,
 	DeepLinkHandler,
-	Home
+	Home,
+	BaseUrlProvider,
+	MigrationHandler
 );
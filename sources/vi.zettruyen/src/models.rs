@@ -1,4 +1,3 @@
-use crate::BASE_URL;
 use aidoku::{Chapter, Manga, MangaWithChapter, alloc::*};
 use chrono::{DateTime, Utc};
 use serde::Deserialize;
@@ -31,26 +30,25 @@ pub struct Comic {
 	pub r#type: String,
 }
 
-impl From<Comic> for Manga {
-	fn from(value: Comic) -> Self {
-		Self {
-			url: Some(format!("{BASE_URL}/truyen-tranh/{}", value.slug)),
-			key: value.slug,
-			title: value.name,
-			cover: value.thumbnail,
-			artists: Some(vec![value.r#type]),
-			tags: Some(value.genres.into_iter().map(|t| t.name).collect::<Vec<_>>()),
-			chapters: value.last_chapter.map(|v| vec![v.into()]),
+impl Comic {
+	/// `base_url` is the current site domain, which may differ from the default.
+	pub fn into_manga(self, base_url: &str) -> Manga {
+		Manga {
+			url: Some(format!("{base_url}/truyen-tranh/{}", self.slug)),
+			key: self.slug,
+			title: self.name,
+			cover: self.thumbnail,
+			artists: Some(vec![self.r#type]),
+			tags: Some(self.genres.into_iter().map(|t| t.name).collect::<Vec<_>>()),
+			chapters: self.last_chapter.map(|v| vec![v.into()]),
 			..Default::default()
 		}
 	}
-}
 
-impl From<Comic> for MangaWithChapter {
-	fn from(value: Comic) -> Self {
-		Self {
-			chapter: value.last_chapter.clone().unwrap_or_default().into(),
-			manga: value.into(),
+	pub fn into_manga_with_chapter(self, base_url: &str) -> MangaWithChapter {
+		MangaWithChapter {
+			chapter: self.last_chapter.clone().unwrap_or_default().into(),
+			manga: self.into_manga(base_url),
 		}
 	}
 }
@@ -2,7 +2,7 @@ use crate::{BASE_URL, USER_AGENT};
 use aidoku::{
 	Chapter, Manga, MangaPageResult, MangaStatus, Page, PageContent, Result,
 	alloc::{String, Vec, string::ToString as _, vec},
-	imports::{html::Document, js::JsContext, net::Request},
+	imports::{html::Document, net::Request},
 	prelude::*,
 };
 use regex::Regex;
@@ -299,13 +299,14 @@ pub fn get_page_list(chapter_key: &str) -> Result<Vec<Page>> {
 			.header("Referer", base_url)
 			.string()?;
 
-		let result = JsContext::new().eval(&format!("{}\nJSON.stringify(d)", js_code))?;
-		let trimmed = result.trim().trim_start_matches('[').trim_end_matches(']');
-		for url_raw in trimmed.split(',') {
-			let url = url_raw.trim().trim_matches('"');
+		let urls = packer::unpack(&js_code)
+			.as_deref()
+			.and_then(packer::parse_image_urls)
+			.ok_or_else(|| error!("Failed to decode page {page_num}"))?;
+		for url in urls {
 			if url.starts_with("http") {
 				pages.push(Page {
-					content: PageContent::url(String::from(url)),
+					content: PageContent::url(url),
 					..Default::default()
 				});
 				if pages.len() >= image_count {
@@ -55,7 +55,7 @@ impl Home for JMComic {
 			components.push(component);
 		}
 
-		Ok(HomeLayout { components })
+		Ok(zhconv::localize(HomeLayout { components }))
 	}
 }
 
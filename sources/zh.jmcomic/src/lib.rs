@@ -1,7 +1,7 @@
 #![no_std]
 use aidoku::{
-	BasicLoginHandler, Chapter, DeepLinkHandler, DeepLinkResult, FilterValue, HashMap,
-	ImageRequestProvider, ImageResponse, Listing, ListingProvider, Manga, MangaPageResult,
+	BasicLoginHandler, Chapter, DeepLinkHandler, DeepLinkResult, DynamicListings, FilterValue,
+	HashMap, ImageRequestProvider, ImageResponse, Listing, ListingProvider, Manga, MangaPageResult,
 	NotificationHandler, Page, PageContent, PageContext, PageImageProcessor, Result, Source,
 	alloc::{String, Vec, vec},
 	canvas::Rect,
@@ -17,7 +17,7 @@ mod models;
 mod net;
 mod settings;
 
-use models::{AlbumResp, BlockState, ChapterResp, SearchResp};
+use models::{AlbumResp, BlockState, ChapterResp, FavoriteResp, SearchResp};
 use net::ApiContext;
 
 const WEB_URL: &str = "https://18comic.vip";
@@ -79,7 +79,7 @@ impl Source for JMComic {
 			manga.copy_from(resp.into_manga(&manga.key, &api.cdn_base));
 		}
 
-		Ok(manga)
+		Ok(zhconv::localize(manga))
 	}
 
 	fn get_page_list(&self, manga: Manga, chapter: Chapter) -> Result<Vec<Page>> {
@@ -91,7 +91,10 @@ impl Source for JMComic {
 
 		let resp: ChapterResp = api.get(&net::url::chapter(&chapter.key))?;
 		let ep_id: String = resp.episode_id(&chapter.key).into();
-		let cdn_base = &api.cdn_base;
+		let cdn_base = match resp.images.first() {
+			Some(filename) => api.image_base(&format!("/media/photos/{ep_id}/{filename}")),
+			None => api.cdn_base.clone(),
+		};
 
 		Ok(resp
 			.images
@@ -114,8 +117,19 @@ impl ListingProvider for JMComic {
 	fn get_manga_list(&self, listing: Listing, page: i32) -> Result<MangaPageResult> {
 		let api = net::context()?;
 		let block = block_ctx(None);
-		match listing.id.as_str() {
+		let result = match listing.id.as_str() {
 			id if id.starts_with("promo:") => home::listing_page(&api, &id[6..], page, &block),
+			"favorite" => favorite_result(&api, "0", page, &block),
+			id if id.starts_with("favorite:") => favorite_result(&api, &id[9..], page, &block),
+			"history" => {
+				require_login()?;
+				let resp: SearchResp = api.get(&net::url::history(page))?;
+				let count = resp.content.len() as i32;
+				Ok(MangaPageResult {
+					has_next_page: count > 0 && page * count < resp.total,
+					entries: resp.into_manga_list(&api.cdn_base, &block),
+				})
+			}
 			id => {
 				if let Some(q) = id.strip_prefix("q:")
 					&& block.is_blocked("", [q])
@@ -124,7 +138,40 @@ impl ListingProvider for JMComic {
 				}
 				search_result(&api, &Self::listing_url(id, page)?, &block)
 			}
+		};
+		result.map(zhconv::localize)
+	}
+}
+
+impl DynamicListings for JMComic {
+	fn get_dynamic_listings(&self) -> Result<Vec<Listing>> {
+		if settings::get_auth().is_none() {
+			return Ok(Vec::new());
+		}
+
+		let mut listings = vec![
+			Listing {
+				id: "favorite".into(),
+				name: "我的收藏".into(),
+				..Default::default()
+			},
+			Listing {
+				id: "history".into(),
+				name: "浏览历史".into(),
+				..Default::default()
+			},
+		];
+		let api = net::context()?;
+		if let Ok(resp) = api.get::<FavoriteResp>(&net::url::favorite("0", 1)) {
+			// folder 0 holds every favorite and is already listed above
+			listings.extend(
+				resp.folder_list
+					.iter()
+					.filter(|folder| folder.id != "0")
+					.map(|folder| folder.listing()),
+			);
 		}
+		Ok(listings)
 	}
 }
 
@@ -333,6 +380,33 @@ fn search_result(api: &ApiContext, path: &str, block: &BlockState) -> Result<Man
 	})
 }
 
+fn require_login() -> Result<()> {
+	if settings::get_auth().is_none() {
+		bail!("请先登录")
+	}
+	Ok(())
+}
+
+fn favorite_result(
+	api: &ApiContext,
+	folder_id: &str,
+	page: i32,
+	block: &BlockState,
+) -> Result<MangaPageResult> {
+	require_login()?;
+	let resp: FavoriteResp = api.get(&net::url::favorite(folder_id, page))?;
+	let count = resp.list.len() as i32;
+	Ok(MangaPageResult {
+		has_next_page: count > 0 && page * count < resp.total,
+		entries: resp
+			.list
+			.into_iter()
+			.filter(|item| !item.is_blocked(block))
+			.map(|item| item.into_manga(&api.cdn_base))
+			.collect(),
+	})
+}
+
 fn direct_manga_result(api: &ApiContext, key: &str, block: &BlockState) -> Result<MangaPageResult> {
 	let resp: AlbumResp = api.get(&net::url::album(key))?;
 	if resp.is_missing() || resp.is_blocked(key, block) {
@@ -356,7 +430,7 @@ fn finish_search_result(page: i32, result: MangaPageResult) -> Result<MangaPageR
 	if page <= 1 && result.entries.is_empty() {
 		bail!("没有找到这样的内容")
 	}
-	Ok(result)
+	Ok(zhconv::localize(result))
 }
 
 fn parse_manga_key(api: &ApiContext, query: &str) -> Result<Option<String>> {
@@ -390,6 +464,7 @@ register_source!(
 	JMComic,
 	Home,
 	ListingProvider,
+	DynamicListings,
 	ImageRequestProvider,
 	PageImageProcessor,
 	DeepLinkHandler,
@@ -121,6 +121,39 @@ pub struct SearchResp {
 	pub content: Vec<ComicItem>,
 }
 
+#[derive(Deserialize)]
+pub struct FavoriteResp {
+	#[serde(deserialize_with = "flex_i32")]
+	pub total: i32,
+	#[serde(default)]
+	pub list: Vec<ComicItem>,
+	#[serde(default)]
+	pub folder_list: Vec<FavoriteFolder>,
+}
+
+#[derive(Deserialize)]
+pub struct FavoriteFolder {
+	#[serde(rename = "FID", deserialize_with = "flex_string")]
+	pub id: String,
+	pub name: Option<String>,
+}
+
+impl FavoriteFolder {
+	pub fn listing(&self) -> Listing {
+		let name = self
+			.name
+			.as_deref()
+			.map(str::trim)
+			.filter(|name| !name.is_empty())
+			.unwrap_or(&self.id);
+		Listing {
+			id: format!("favorite:{}", self.id),
+			name: format!("收藏夹：{name}"),
+			..Default::default()
+		}
+	}
+}
+
 pub fn cover_url(cdn_base: &str, id: &str) -> String {
 	format!("{cdn_base}/media/albums/{id}_3x4.jpg")
 }
@@ -9,6 +9,7 @@ use aidoku::{
 	imports::net::{Request, Response},
 };
 use base64::{Engine, engine::general_purpose::STANDARD};
+use core::cell::RefCell;
 use serde::de::DeserializeOwned;
 
 use crate::models::{ApiOuter, AuthData, DomainRefreshResp, PromoteGroup, SearchResp, SettingData};
@@ -45,34 +46,134 @@ fn normalize_domain(domain: &str) -> Option<String> {
 }
 
 pub struct ApiContext {
-	domain: String,
+	domain: RefCell<String>,
 	pub cdn_base: String,
 }
 
 impl ApiContext {
 	pub fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
-		api_get_on_domain(&self.domain, path)
+		let auth = settings::get_auth();
+		let bearer = auth.as_ref().map(|auth| auth.jwt_token.as_str());
+		let ts = current_ts();
+
+		let current = self.domain.borrow().clone();
+		if let Some(resp) = send_on_domain(&current, path, ts, bearer) {
+			return parse_response(resp, ts);
+		}
+
+		// the current domain stopped responding, move on to the next reachable one
+		let (domain, resp) = fetch_domain_candidates()
+			.into_iter()
+			.filter(|domain| *domain != current)
+			.find_map(|domain| send_on_domain(&domain, path, ts, bearer).map(|r| (domain, r)))
+			.ok_or_else(|| error!("当前所有域名都暂时不可用"))?;
+		settings::set_api_domain(&domain);
+		*self.domain.borrow_mut() = domain;
+		parse_response(resp, ts)
+	}
+
+	fn domain(&self) -> String {
+		self.domain.borrow().clone()
+	}
+
+	/// Returns an image host that serves `path`, moving on to the next image
+	/// shunt when the current one fails, like [`ApiContext::get`] does for domains.
+	pub fn image_base(&self, path: &str) -> String {
+		if is_image_reachable(&self.cdn_base, path) {
+			return self.cdn_base.clone();
+		}
+
+		let domain = self.domain();
+		let ts = current_ts();
+		let current = settings::get_image_shunt();
+		PREFERRED_IMAGE_SHUNTS
+			.into_iter()
+			.filter(|shunt| Some(*shunt) != current)
+			.find_map(|shunt| {
+				let cdn_base = fetch_image_host(&domain, shunt, ts)
+					.filter(|cdn_base| *cdn_base != self.cdn_base)?;
+				is_image_reachable(&cdn_base, path).then_some((shunt, cdn_base))
+			})
+			.map(|(shunt, cdn_base)| {
+				settings::set_image_shunt(shunt);
+				settings::set_image_host(&cdn_base);
+				cdn_base
+			})
+			.unwrap_or_else(|| self.cdn_base.clone())
 	}
 }
 
 pub fn context() -> Result<ApiContext> {
+	// reuse the last working hosts, failing over once they stop responding
+	if let Some(domain) = settings::get_api_domain()
+		&& let Some(cdn_base) = settings::get_image_host()
+	{
+		return Ok(ApiContext {
+			domain: RefCell::new(domain),
+			cdn_base,
+		});
+	}
+
 	let ts = current_ts();
-	for domain in fetch_domain_candidates() {
-		for shunt in PREFERRED_IMAGE_SHUNTS {
-			if let Ok(setting) = api_get_on_domain_with_auth::<SettingData>(
-				&domain,
-				&url::setting_path(shunt),
-				ts,
-				None,
-			) && let Some(cdn_base) = setting.img_host.as_deref().and_then(normalize_cdn_base)
-			{
-				return Ok(ApiContext { domain, cdn_base });
-			}
+	for domain in domain_candidates() {
+		if let Some((shunt, cdn_base)) = probe_image_host(&domain, ts) {
+			settings::set_api_domain(&domain);
+			settings::set_image_shunt(shunt);
+			settings::set_image_host(&cdn_base);
+			return Ok(ApiContext {
+				domain: RefCell::new(domain),
+				cdn_base,
+			});
 		}
 	}
 	Err(error!("当前所有域名都暂时不可用"))
 }
 
+/// Yields the last working domain first, only fetching the published list if
+/// it is no longer reachable.
+fn domain_candidates() -> impl Iterator<Item = String> {
+	let saved = settings::get_api_domain();
+	let fallback = saved.clone();
+	saved.into_iter().chain(
+		core::iter::once_with(fetch_domain_candidates)
+			.flatten()
+			.filter(move |domain| fallback.as_ref() != Some(domain)),
+	)
+}
+
+fn probe_image_host(domain: &str, ts: u64) -> Option<(u8, String)> {
+	let saved = settings::get_image_shunt().filter(|s| PREFERRED_IMAGE_SHUNTS.contains(s));
+	let shunts = saved.into_iter().chain(
+		PREFERRED_IMAGE_SHUNTS
+			.into_iter()
+			.filter(|s| Some(*s) != saved),
+	);
+	for shunt in shunts {
+		if let Some(cdn_base) = fetch_image_host(domain, shunt, ts) {
+			return Some((shunt, cdn_base));
+		}
+	}
+	None
+}
+
+fn fetch_image_host(domain: &str, shunt: u8, ts: u64) -> Option<String> {
+	let resp = send_on_domain(domain, &url::setting_path(shunt), ts, None)?;
+	let setting = parse_response::<SettingData>(resp, ts).ok()?;
+	setting.img_host.as_deref().and_then(normalize_cdn_base)
+}
+
+fn is_image_reachable(cdn_base: &str, path: &str) -> bool {
+	Request::get(format!("{cdn_base}{path}"))
+		.map(with_user_agent)
+		.and_then(|request| {
+			request
+				.header("referer", "https://localhost/")
+				.header("range", "bytes=0-0")
+				.send()
+		})
+		.is_ok_and(|resp| (200..300).contains(&resp.status_code()))
+}
+
 pub mod url {
 	use aidoku::{
 		alloc::{String, format},
@@ -109,6 +210,14 @@ pub mod url {
 		}
 	}
 
+	pub fn favorite(folder_id: &str, page: i32) -> String {
+		format!("/favorite?page={}&folder_id={}&o=mr", page, folder_id)
+	}
+
+	pub fn history(page: i32) -> String {
+		format!("/watch_list?page={}", page)
+	}
+
 	pub fn promote(page: i32) -> String {
 		format!("/promote?page={}", page)
 	}
@@ -205,38 +314,26 @@ pub fn parse_response<T: DeserializeOwned>(resp: Response, ts: u64) -> Result<T>
 	serde_json::from_slice(&decrypted).map_err(|_| error!("内容数据解析失败"))
 }
 
-fn api_get_on_domain<T: DeserializeOwned>(domain: &str, path: &str) -> Result<T> {
-	let auth = settings::get_auth();
-	let ts = current_ts();
-	api_get_on_domain_with_auth(
-		domain,
-		path,
-		ts,
-		auth.as_ref().map(|auth| auth.jwt_token.as_str()),
-	)
-}
-
 pub fn home_data(ctx: &ApiContext) -> Result<(Vec<PromoteGroup>, SearchResp)> {
 	let auth = settings::get_auth();
 	let ts = current_ts();
 	let bearer = auth.as_ref().map(|a| a.jwt_token.as_str());
 
-	let req1 = get_request_on_domain(&ctx.domain, &url::promote(0), ts, bearer)?;
-	let req2 = get_request_on_domain(&ctx.domain, &url::filter("mr", "single", 1), ts, bearer)?;
-
-	let mut responses = Request::send_all([req1, req2]).into_iter();
-	let groups: Vec<PromoteGroup> = responses
-		.next()
-		.ok_or_else(|| error!("响应缺失"))?
-		.map_err(|_| error!("请求失败"))
-		.and_then(|r| parse_response(r, ts))?;
-	let single: SearchResp = responses
-		.next()
-		.ok_or_else(|| error!("响应缺失"))?
-		.map_err(|_| error!("请求失败"))
-		.and_then(|r| parse_response(r, ts))?;
-
-	Ok((groups, single))
+	let promote_path = url::promote(0);
+	let single_path = url::filter("mr", "single", 1);
+	let domain = ctx.domain();
+	let req1 = get_request_on_domain(&domain, &promote_path, ts, bearer)?;
+	let req2 = get_request_on_domain(&domain, &single_path, ts, bearer)?;
+
+	let mut responses = Request::send_all([req1, req2])
+		.into_iter()
+		.map(|resp| resp.ok().filter(is_reachable));
+	if let (Some(Some(groups)), Some(Some(single))) = (responses.next(), responses.next()) {
+		return Ok((parse_response(groups, ts)?, parse_response(single, ts)?));
+	}
+
+	// the current domain is blocked, go through the regular requests so they fail over
+	Ok((ctx.get(&promote_path)?, ctx.get(&single_path)?))
 }
 
 fn fetch_domain_candidates() -> Vec<String> {
@@ -277,14 +374,28 @@ fn fetch_domain_candidates() -> Vec<String> {
 	Vec::new()
 }
 
-fn api_get_on_domain_with_auth<T: DeserializeOwned>(
+/// Whether a response came from the API rather than a block page, so errors
+/// like 403 (geo block), 451 or a Cloudflare challenge move on to another domain.
+fn is_reachable(resp: &Response) -> bool {
+	let status = resp.status_code();
+	let challenged = resp
+		.get_header("cf-mitigated")
+		.is_some_and(|value| value == "challenge");
+	!challenged && !matches!(status, 403 | 451) && status < 500
+}
+
+// returns `None` when the domain can't be reached or is being blocked
+fn send_on_domain(
 	domain: &str,
 	path: &str,
 	ts: u64,
 	bearer_token: Option<&str>,
-) -> Result<T> {
-	let resp = get_request_on_domain(domain, path, ts, bearer_token)?.send()?;
-	parse_response(resp, ts)
+) -> Option<Response> {
+	let resp = get_request_on_domain(domain, path, ts, bearer_token)
+		.ok()?
+		.send()
+		.ok()?;
+	is_reachable(&resp).then_some(resp)
 }
 
 fn get_request_on_domain(
@@ -324,12 +435,8 @@ pub fn login(username: &str, password: &str) -> Result<AuthData> {
 		encode_uri_component(username),
 		encode_uri_component(password)
 	);
-	let domains = fetch_domain_candidates();
-	if domains.is_empty() {
-		return Err(error!("域名列表为空"));
-	}
 	let mut last_error = None;
-	for domain in domains {
+	for domain in domain_candidates() {
 		let ts = current_ts();
 		match api_post_on_domain_with_token_and_ts::<AuthData>(
 			&domain,
@@ -337,10 +444,13 @@ pub fn login(username: &str, password: &str) -> Result<AuthData> {
 			body.as_bytes(),
 			ts,
 		) {
-			Ok(auth) if auth.is_valid() => return Ok(auth),
+			Ok(auth) if auth.is_valid() => {
+				settings::set_api_domain(&domain);
+				return Ok(auth);
+			}
 			Ok(_) => last_error = Some(error!("登录响应无效")),
 			Err(err) => last_error = Some(err),
 		}
 	}
-	Err(last_error.unwrap_or_else(|| error!("登录失败，请稍后再试")))
+	Err(last_error.unwrap_or_else(|| error!("域名列表为空")))
 }
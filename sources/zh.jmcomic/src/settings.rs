@@ -8,6 +8,9 @@ use serde::{Serialize, de::DeserializeOwned};
 const AUTH_DATA_KEY: &str = "authData";
 const JUST_LOGGED_IN_KEY: &str = "justLoggedIn";
 const BLOCKED_CONTENT_KEY: &str = "blockedMetadataKeywords";
+const API_DOMAIN_KEY: &str = "apiDomain";
+const IMAGE_SHUNT_KEY: &str = "imageShunt";
+const IMAGE_HOST_KEY: &str = "imageHost";
 
 fn get_str(key: &str) -> Option<String> {
 	defaults_get::<String>(key).filter(|v| !v.is_empty())
@@ -52,6 +55,30 @@ pub fn clear_just_logged_in() {
 	defaults_set(JUST_LOGGED_IN_KEY, DefaultValue::Null);
 }
 
+pub fn get_api_domain() -> Option<String> {
+	get_str(API_DOMAIN_KEY)
+}
+
+pub fn set_api_domain(domain: &str) {
+	set_str(API_DOMAIN_KEY, domain);
+}
+
+pub fn get_image_shunt() -> Option<u8> {
+	defaults_get::<i32>(IMAGE_SHUNT_KEY).and_then(|v| u8::try_from(v).ok())
+}
+
+pub fn set_image_shunt(shunt: u8) {
+	defaults_set(IMAGE_SHUNT_KEY, DefaultValue::Int(shunt.into()));
+}
+
+pub fn get_image_host() -> Option<String> {
+	get_str(IMAGE_HOST_KEY)
+}
+
+pub fn set_image_host(cdn_base: &str) {
+	set_str(IMAGE_HOST_KEY, cdn_base);
+}
+
 pub fn block_id_cache(keywords: &[String]) -> Option<Vec<String>> {
 	let (kw, ids): (Vec<String>, Vec<String>) = get_json("blockIdCache")?;
 	(kw == keywords).then_some(ids)
@@ -226,7 +226,7 @@ impl KomiicSource {
 				.filter(|description| !description.trim().is_empty());
 			manga.tags = Self::names(value, "categories");
 		}
-		manga
+		zhconv::localize(manga)
 	}
 
 	fn manga_array<'a>(value: &'a Value, path: &[&str]) -> Result<&'a Vec<Value>> {
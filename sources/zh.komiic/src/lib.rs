@@ -18,6 +18,7 @@ use aidoku::{
 	prelude::*,
 };
 use serde_json::{Value, json};
+use zhconv::Script;
 
 const BASE_URL: &str = "https://komiic.com";
 const QUERY_URL: &str = "https://komiic.com/api/query";
@@ -97,7 +98,7 @@ impl KomiicSource {
 		let serial_number = serial.parse::<f32>().ok();
 		let is_book = value.get("type").and_then(Value::as_str) == Some("book");
 		let url = format!("{BASE_URL}/comic/{manga_key}/chapter/{key}/images/all");
-		Some(Chapter {
+		Some(zhconv::localize(Chapter {
 			key,
 			title: Self::chapter_title(value),
 			chapter_number: if is_book { None } else { serial_number },
@@ -105,7 +106,7 @@ impl KomiicSource {
 			url: Some(url),
 			language: Some(String::from("zh")),
 			..Default::default()
-		})
+		}))
 	}
 
 	fn select_chapter_version(
@@ -165,8 +166,11 @@ impl Source for KomiicSource {
 		&self,
 		query: Option<String>,
 		page: i32,
-		filters: Vec<FilterValue>,
+		mut filters: Vec<FilterValue>,
 	) -> Result<MangaPageResult> {
+		// komiic only matches traditional Chinese titles and categories
+		let query = query.map(|query| zhconv::convert(&query, Script::Traditional));
+		zhconv::normalize_filters(&mut filters, Script::Traditional);
 		let (order_by, status, category, keyword, author) =
 			Self::parse_search_filters(query.as_deref(), &filters);
 		if let Some(author) = author {
@@ -192,7 +192,7 @@ pub fn get_page_list(url: String) -> Result<Vec<Page>> {
 			.header("Referer", BASE_URL)
 			.header("User-Agent", USER_AGENT)
 			.string()?;
-		let urls = decode(content);
+		let urls = decode(content, page)?;
 		for url in urls.clone() {
 			if url == last_url {
 				break;
@@ -205,7 +205,7 @@ pub fn get_page_list(url: String) -> Result<Vec<Page>> {
 			});
 			page += 1;
 		}
-		if urls.len() == 1 {
+		if urls.len() <= 1 {
 			break;
 		}
 	}
@@ -213,69 +213,9 @@ pub fn get_page_list(url: String) -> Result<Vec<Page>> {
 	Ok(pages)
 }
 
-fn substring_after<'a>(s: &'a str, pat: &str) -> &'a str {
-	s.find(pat).map(|pos| &s[pos + pat.len()..]).unwrap_or("")
-}
-
-fn substring_before<'a>(s: &'a str, pat: &str) -> &'a str {
-	s.find(pat).map(|pos| &s[..pos]).unwrap_or("")
-}
-
-fn substring_before_last<'a>(s: &'a str, pat: &str) -> &'a str {
-	s.rfind(pat).map(|pos| &s[..pos]).unwrap_or("")
-}
-
-fn substring_after_last<'a>(s: &'a str, pat: &str) -> &'a str {
-	s.rfind(pat).map(|pos| &s[pos + pat.len()..]).unwrap_or("")
-}
-
-fn decode(encoded: String) -> Vec<String> {
-	let packed = substring_after(&encoded, "return p;}").to_string();
-	let k: Vec<&str> = substring_before(substring_after(&packed, ",\'"), "\'.")
-		.split('|')
-		.collect();
-
-	let chapter = decoded_with_k(
-		substring_before(substring_after(&packed, "=\""), "\";").to_string(),
-		k.clone(),
-	);
-	let query = decoded_with_k(
-		substring_after_last(substring_before_last(&packed, "\\\'"), "\\\'").to_string(),
-		k.clone(),
-	);
-
-	substring_before(substring_after(&packed, "=["), "];")
-		.split(',')
-		.map(|item| decoded_with_k(item.replace('\"', "").to_string(), k.clone()))
-		.map(|page| format!("{}{}{}", chapter, page, query))
-		.collect()
-}
-
-fn decoded_with_k(encoded: String, k: Vec<&str>) -> String {
-	let mut decoded = String::new();
-
-	for char in encoded.chars() {
-		let str = if char.is_digit(36) {
-			let index = if char.is_ascii_uppercase() {
-				(char as usize) - 29
-			} else {
-				char.to_digit(36).unwrap_or(0) as usize
-			};
-			if index < k.len() {
-				let s = k[index];
-				if s.is_empty() {
-					char.to_string()
-				} else {
-					s.to_string()
-				}
-			} else {
-				char.to_string()
-			}
-		} else {
-			char.to_string()
-		};
-		decoded.push_str(str.as_str());
-	}
-
-	decoded
+fn decode(encoded: String, page: i32) -> Result<Vec<String>> {
+	packer::unpack(&encoded)
+		.as_deref()
+		.and_then(packer::parse_image_urls)
+		.ok_or_else(|| error!("Failed to decode page {page}"))
 }
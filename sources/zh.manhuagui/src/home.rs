@@ -0,0 +1,134 @@
+use crate::{
+	Manhuagui,
+	html::ListingPage as _,
+	net::{RankPeriod, Url},
+};
+use aidoku::{
+	Home, HomeComponent, HomeComponentValue, HomeLayout, HomePartialResult, Listing, ListingKind,
+	Manga, MangaPageResult, Result,
+	alloc::{Vec, vec},
+	error,
+	imports::{
+		html::Document,
+		net::{Request, RequestError, Response},
+		std::send_partial_result,
+	},
+};
+
+fn send_component(component: HomeComponent) {
+	send_partial_result(&HomePartialResult::Component(component));
+}
+
+fn parse_entries(
+	response: core::result::Result<Response, RequestError>,
+	parse: fn(&Document) -> MangaPageResult,
+) -> Vec<Manga> {
+	response
+		.ok()
+		.and_then(|response| response.get_html().ok())
+		.map(|html| parse(&html).entries)
+		.unwrap_or_default()
+}
+
+fn listing(id: &str, name: &str) -> Option<Listing> {
+	Some(Listing {
+		id: id.into(),
+		name: name.into(),
+		kind: ListingKind::Default,
+	})
+}
+
+impl Home for Manhuagui {
+	fn get_home(&self) -> Result<HomeLayout> {
+		// send basic home layout
+		send_partial_result(&HomePartialResult::Layout(HomeLayout {
+			components: vec![
+				HomeComponent {
+					title: Some("日排行".into()),
+					subtitle: None,
+					value: HomeComponentValue::empty_big_scroller(),
+				},
+				HomeComponent {
+					title: Some("最新更新".into()),
+					subtitle: None,
+					value: HomeComponentValue::empty_scroller(),
+				},
+				HomeComponent {
+					title: Some("周排行".into()),
+					subtitle: None,
+					value: HomeComponentValue::empty_manga_list(),
+				},
+				HomeComponent {
+					title: Some("月排行".into()),
+					subtitle: None,
+					value: HomeComponentValue::empty_manga_list(),
+				},
+			],
+		}));
+
+		let responses: [core::result::Result<Response, RequestError>; 4] = Request::send_all([
+			Url::Rank {
+				period: RankPeriod::Day,
+			}
+			.request()?,
+			Url::Updates.request()?,
+			Url::Rank {
+				period: RankPeriod::Week,
+			}
+			.request()?,
+			Url::Rank {
+				period: RankPeriod::Month,
+			}
+			.request()?,
+		])
+		.try_into()
+		.map_err(|_| error!("Failed to convert requests vec to array"))?;
+		let [daily, latest, weekly, monthly] = responses;
+
+		let daily = parse_entries(daily, Document::ranking);
+		if !daily.is_empty() {
+			send_component(HomeComponent {
+				title: Some("日排行".into()),
+				subtitle: None,
+				value: HomeComponentValue::BigScroller {
+					entries: daily.into_iter().take(10).collect(),
+					auto_scroll_interval: Some(8.0),
+				},
+			});
+		}
+
+		let latest = parse_entries(latest, Document::updates);
+		if !latest.is_empty() {
+			send_component(HomeComponent {
+				title: Some("最新更新".into()),
+				subtitle: None,
+				value: HomeComponentValue::Scroller {
+					entries: latest.into_iter().map(|manga| manga.into()).collect(),
+					listing: listing("update", "最新更新"),
+				},
+			});
+		}
+
+		for (response, id, name) in [
+			(weekly, "rank-week", "周排行"),
+			(monthly, "rank-month", "月排行"),
+		] {
+			let entries = parse_entries(response, Document::ranking);
+			if entries.is_empty() {
+				continue;
+			}
+			send_component(HomeComponent {
+				title: Some(name.into()),
+				subtitle: None,
+				value: HomeComponentValue::MangaList {
+					ranking: true,
+					page_size: Some(3),
+					entries: entries.into_iter().map(|manga| manga.into()).collect(),
+					listing: listing(id, name),
+				},
+			});
+		}
+
+		Ok(HomeLayout::default())
+	}
+}
@@ -1,13 +1,10 @@
-use crate::{USER_AGENT, decoder::Decoder};
+use crate::net::Url;
 use aidoku::{
 	Chapter, ContentRating, Manga, MangaPageResult, MangaStatus, Page, PageContent, Result,
 	SelectFilter, Viewer,
-	alloc::{String, Vec, borrow::Cow, vec},
+	alloc::{String, Vec, borrow::Cow, string::ToString as _, vec},
 	helpers::uri::encode_uri,
-	imports::{
-		html::{Document, Html},
-		net::Request,
-	},
+	imports::html::{Document, Html},
 	prelude::*,
 };
 use regex::Regex;
@@ -39,6 +36,26 @@ pub trait GenresPage {
 	fn filter(&self) -> Result<SelectFilter>;
 }
 
+pub trait ListingPage {
+	fn ranking(&self) -> MangaPageResult;
+	fn updates(&self) -> MangaPageResult;
+}
+
+/// Fails with a readable message when an adult title's content is hidden
+/// behind the warning page
+pub fn check_adult_gate(document: &Document) -> Result<()> {
+	let is_gated = document.select_first("#checkAdult").is_some()
+		&& document.select_first("#__VIEWSTATE").is_none()
+		&& document.select_first(".chapter-list").is_none();
+	if is_gated {
+		if crate::settings::get_adult_content() {
+			bail!("成人内容警告确认失败，请稍后重试");
+		}
+		bail!("此漫画含有成人内容，请在设置中开启“显示成人内容”");
+	}
+	Ok(())
+}
+
 impl MangaPage for Document {
 	fn manga_page_result(&self) -> Result<MangaPageResult> {
 		let mut mangas: Vec<Manga> = Vec::new();
@@ -129,14 +146,11 @@ impl MangaPage for Document {
 		manga.authors = Some(authors.clone());
 		manga.artists = Some(authors);
 
-		let desc_element = self
+		// adult titles may only have the full description
+		manga.description = self
 			.select_first("#intro-cut")
-			.ok_or_else(|| error!("Failed to select description"))?;
-		manga.description = Some(
-			desc_element
-				.text()
-				.ok_or_else(|| error!("Failed to get description"))?,
-		);
+			.or_else(|| self.select_first("#intro-all"))
+			.and_then(|element| element.text());
 
 		let status_element = self
 			.select_first("li.status")
@@ -211,10 +225,7 @@ impl ChapterPage for Document {
 		let div_owned: Option<Document> = self
 			.select_first("#__VIEWSTATE")
 			.and_then(|el| el.attr("value"))
-			.and_then(|compressed| {
-				crate::decoder::decompress_from_base64(&compressed)
-					.and_then(|data| String::from_utf16(&data).ok())
-			})
+			.and_then(|compressed| packer::lz_string::decompress_from_base64(&compressed))
 			.filter(|s| !s.is_empty())
 			.and_then(|decompressed| Html::parse(&decompressed).ok());
 
@@ -317,6 +328,58 @@ impl ChapterPage for Document {
 	}
 }
 
+impl ListingPage for Document {
+	fn ranking(&self) -> MangaPageResult {
+		MangaPageResult {
+			entries: comic_links(self, ".rank-detail h5 > a"),
+			has_next_page: false,
+		}
+	}
+
+	fn updates(&self) -> MangaPageResult {
+		MangaPageResult {
+			entries: comic_links(self, ".latest-list li > a.cover"),
+			has_next_page: false,
+		}
+	}
+}
+
+fn comic_links(document: &Document, selector: &str) -> Vec<Manga> {
+	let Some(links) = document.select(selector) else {
+		return Vec::new();
+	};
+
+	let mut mangas: Vec<Manga> = Vec::new();
+	for link in links {
+		let Some(href) = link.attr("href") else {
+			continue;
+		};
+		let Some(key) = href
+			.split("/comic/")
+			.nth(1)
+			.and_then(|path| path.split('/').next())
+			.filter(|key| !key.is_empty())
+		else {
+			continue;
+		};
+		if mangas.iter().any(|manga| manga.key == key) {
+			continue;
+		}
+		let Some(title) = link.attr("title").or_else(|| link.text()) else {
+			continue;
+		};
+		mangas.push(Manga {
+			key: key.into(),
+			cover: Some(format!("https://cf.hamreus.com/cpic/b/{}.jpg", key)),
+			title,
+			url: Some(Url::Manga { key: key.into() }.to_string()),
+			..Default::default()
+		});
+	}
+
+	mangas
+}
+
 impl GenresPage for Document {
 	fn filter(&self) -> Result<SelectFilter> {
 		let genre_links = self
@@ -345,18 +408,20 @@ impl GenresPage for Document {
 	}
 }
 
-pub fn get_page_list(base_url: String) -> Result<Vec<Page>> {
+pub fn get_page_list(url: Url) -> Result<Vec<Page>> {
 	let mut pages: Vec<Page> = Vec::new();
 
-	let html_content = Request::get(base_url)?
-		.header("Referer", crate::settings::get_base_url())
-		.header("User-Agent", USER_AGENT)
-		.header("Accept-Language", "zh-CN,zh;q=0.9,en-US;q=0.8,en;q=0.7")
-		.header("Cookie", "device_view=pc; isAdult=1")
-		.string()?;
+	let html_content = url.request()?.string()?;
 
-	let decoder = Decoder::new(html_content);
-	let (path, pages_str) = decoder.decode();
+	let Some(script) = html_content.split(r#"window["\x65\x76\x61\x6c"]"#).nth(1) else {
+		if let Ok(document) = Html::parse(&html_content) {
+			check_adult_gate(&document)?;
+		}
+		bail!("Packed script not found");
+	};
+	let image_data = packer::unpack(script).ok_or_else(|| error!("Failed to unpack script"))?;
+	let (path, pages_str) =
+		parse_image_data(&image_data).ok_or_else(|| error!("Image data not found"))?;
 
 	for str in pages_str.into_iter() {
 		let encoded_path = encode_uri(&path);
@@ -369,3 +434,22 @@ pub fn get_page_list(base_url: String) -> Result<Vec<Page>> {
 
 	Ok(pages)
 }
+
+/// Reads the image path and file names from the unpacked
+/// `SMH.imgData({...}).preInit();` call
+fn parse_image_data(js: &str) -> Option<(String, Vec<String>)> {
+	let json = js.split_once(".imgData(")?.1.split_once(").preInit();")?.0;
+
+	let pages = json
+		.split_once(r#""files":["#)?
+		.1
+		.split_once(']')?
+		.0
+		.split(',')
+		.map(|file| file.trim().trim_matches('"').into())
+		.collect();
+
+	let path = json.split_once(r#""path":""#)?.1.split_once('"')?.0.into();
+
+	Some((path, pages))
+}
@@ -1,19 +1,19 @@
 #![no_std]
 
-mod decoder;
+mod home;
 mod html;
 mod net;
 mod settings;
 
 use aidoku::{
 	Chapter, DeepLinkHandler, DeepLinkResult, DynamicFilters, FilterValue, ImageRequestProvider,
-	Manga, MangaPageResult, Page, Result, Source,
+	Listing, ListingProvider, Manga, MangaPageResult, Page, Result, Source,
 	alloc::{String, Vec},
 	imports::net::Request,
 	prelude::*,
 };
-use html::{ChapterPage as _, GenresPage as _, MangaPage as _};
-use net::Url;
+use html::{ChapterPage as _, GenresPage as _, ListingPage as _, MangaPage as _};
+use net::{RankPeriod, Url};
 
 pub const USER_AGENT: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/138.0.0.0 Safari/537.36";
 
@@ -41,25 +41,22 @@ impl Source for Manhuagui {
 		needs_details: bool,
 		needs_chapters: bool,
 	) -> Result<Manga> {
+		if !needs_details && !needs_chapters {
+			return Ok(manga);
+		}
+
+		let html = Url::Manga {
+			key: manga.key.clone(),
+		}
+		.request()?
+		.html()?;
+		html::check_adult_gate(&html)?;
+
 		if needs_details {
-			let url = format!("{}/comic/{}", settings::get_base_url(), manga.key);
-			let html = Request::get(url)?
-				.header("Referer", settings::get_base_url())
-				.header("User-Agent", USER_AGENT)
-				.header("Accept-Language", "zh-CN,zh;q=0.9,en-US;q=0.8,en;q=0.7")
-				.header("Cookie", "device_view=pc; isAdult=1")
-				.html()?;
 			html.update_details(&mut manga)?;
 		}
 
 		if needs_chapters {
-			let url = format!("{}/comic/{}", settings::get_base_url(), manga.key);
-			let html = Request::get(url)?
-				.header("Referer", settings::get_base_url())
-				.header("User-Agent", USER_AGENT)
-				.header("Accept-Language", "zh-CN,zh;q=0.9,en-US;q=0.8,en;q=0.7")
-				.header("Cookie", "device_view=pc; isAdult=1")
-				.html()?;
 			manga.chapters = Some(html.chapters()?);
 		}
 
@@ -67,13 +64,27 @@ impl Source for Manhuagui {
 	}
 
 	fn get_page_list(&self, manga: Manga, chapter: Chapter) -> Result<Vec<Page>> {
-		let base_url = format!(
-			"{}/comic/{}/{}.html",
-			settings::get_base_url(),
-			manga.key,
-			chapter.key
-		);
-		crate::html::get_page_list(base_url)
+		crate::html::get_page_list(Url::Chapter {
+			manga_key: manga.key,
+			key: chapter.key,
+		})
+	}
+}
+
+impl ListingProvider for Manhuagui {
+	fn get_manga_list(&self, listing: Listing, page: i32) -> Result<MangaPageResult> {
+		// rankings and updates are single pages
+		if page > 1 {
+			return Ok(MangaPageResult::default());
+		}
+
+		if listing.id == "update" {
+			return Ok(Url::Updates.request()?.html()?.updates());
+		}
+
+		let period = RankPeriod::from_listing(&listing.id)
+			.ok_or_else(|| error!("Invalid listing: `{}`", listing.id))?;
+		Ok(Url::Rank { period }.request()?.html()?.ranking())
 	}
 }
 
@@ -87,7 +98,7 @@ impl ImageRequestProvider for Manhuagui {
 			.header("User-Agent", USER_AGENT)
 			.header("Referer", settings::get_base_url())
 			.header("Accept-Language", "zh-CN,zh;q=0.9,en-US;q=0.8,en;q=0.7")
-			.header("Cookie", "device_view=pc; isAdult=1"))
+			.header("Cookie", settings::get_cookie()))
 	}
 }
 
@@ -111,6 +122,8 @@ impl DynamicFilters for Manhuagui {
 
 register_source!(
 	Manhuagui,
+	Home,
+	ListingProvider,
 	ImageRequestProvider,
 	DeepLinkHandler,
 	DynamicFilters
@@ -25,6 +25,46 @@ pub enum Url {
 		page: i32,
 	},
 	GenresPage,
+	Manga {
+		key: String,
+	},
+	Chapter {
+		manga_key: String,
+		key: String,
+	},
+	Rank {
+		period: RankPeriod,
+	},
+	Updates,
+}
+
+#[derive(Clone, Copy)]
+pub enum RankPeriod {
+	Day,
+	Week,
+	Month,
+	Total,
+}
+
+impl RankPeriod {
+	pub fn from_listing(id: &str) -> Option<Self> {
+		match id {
+			"rank-day" => Some(Self::Day),
+			"rank-week" => Some(Self::Week),
+			"rank-month" => Some(Self::Month),
+			"rank-total" => Some(Self::Total),
+			_ => None,
+		}
+	}
+
+	fn path(self) -> &'static str {
+		match self {
+			Self::Day => "",
+			Self::Week => "week.html",
+			Self::Month => "month.html",
+			Self::Total => "total.html",
+		}
+	}
 }
 
 impl Url {
@@ -96,7 +136,7 @@ impl Url {
 			.header("Referer", crate::settings::get_base_url())
 			.header("User-Agent", USER_AGENT)
 			.header("Accept-Language", "zh-CN,zh;q=0.9,en-US;q=0.8,en;q=0.7")
-			.header("Cookie", "device_view=pc; isAdult=1"))
+			.header("Cookie", crate::settings::get_cookie()))
 	}
 }
 
@@ -146,6 +186,29 @@ impl Display for Url {
 			Url::GenresPage => {
 				write!(f, "{}list/", crate::settings::get_base_url())
 			}
+			Url::Manga { key } => {
+				write!(f, "{}comic/{}/", crate::settings::get_base_url(), key)
+			}
+			Url::Chapter { manga_key, key } => {
+				write!(
+					f,
+					"{}comic/{}/{}.html",
+					crate::settings::get_base_url(),
+					manga_key,
+					key
+				)
+			}
+			Url::Rank { period } => {
+				write!(
+					f,
+					"{}rank/{}",
+					crate::settings::get_base_url(),
+					period.path()
+				)
+			}
+			Url::Updates => {
+				write!(f, "{}update/", crate::settings::get_base_url())
+			}
 		}
 	}
 }
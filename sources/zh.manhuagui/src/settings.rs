@@ -10,3 +10,16 @@ pub fn get_base_url() -> &'static str {
 		"https://www.manhuagui.com/"
 	}
 }
+
+/// Whether to confirm the adult-content warning on behalf of the user
+pub fn get_adult_content() -> bool {
+	defaults_get("adultContent").unwrap_or(true)
+}
+
+pub fn get_cookie() -> &'static str {
+	if get_adult_content() {
+		"device_view=pc; isAdult=1"
+	} else {
+		"device_view=pc"
+	}
+}
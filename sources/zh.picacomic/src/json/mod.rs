@@ -26,6 +26,16 @@ pub struct ComicItem {
 	pub total_likes: Option<i32>,
 	#[serde(rename = "chineseTeam")]
 	pub chinese_team: Option<String>,
+	#[serde(rename = "epsCount")]
+	pub eps_count: Option<i32>,
+	#[serde(rename = "viewsCount")]
+	pub views_count: Option<i32>,
+	#[serde(rename = "totalViews")]
+	pub total_views: Option<i32>,
+	#[serde(rename = "isFavourite")]
+	pub is_favourite: Option<bool>,
+	#[serde(rename = "isLiked")]
+	pub is_liked: Option<bool>,
 	pub created_at: Option<String>,
 }
 
@@ -46,6 +56,17 @@ pub struct ComicData {
 	pub comic: ComicItem,
 }
 
+#[derive(Deserialize)]
+pub struct ActionResponse {
+	pub data: ActionData,
+}
+
+#[derive(Deserialize)]
+pub struct ActionData {
+	/// `favourite`, `un_favourite`, `like` or `unlike`
+	pub action: String,
+}
+
 #[derive(Deserialize)]
 pub struct ExploreResponse {
 	pub data: ExploreData,
@@ -154,21 +175,54 @@ impl From<ComicItem> for Manga {
 		all_tags.extend(item.categories);
 
 		let pages_text = item.pages_count.map(|count| format!("页数：{}P", count));
+		let eps_text = item.eps_count.map(|count| format!("章节：{}", count));
 		let likes_text = item
 			.total_likes
 			.or(item.likes_count)
 			.map(|count| format!("{} likes", count));
+		let views_text = item
+			.total_views
+			.or(item.views_count)
+			.map(|count| format!("{} views", count));
+		let team_text = item
+			.chinese_team
+			.filter(|team| !team.trim().is_empty())
+			.map(|team| format!("汉化：{}", team.trim()));
+		let state_text = [
+			(item.is_favourite == Some(true)).then_some("已收藏"),
+			(item.is_liked == Some(true)).then_some("已点赞"),
+		]
+		.into_iter()
+		.flatten()
+		.collect::<Vec<_>>()
+		.join(" · ");
 
 		let mut desc_parts = Vec::new();
 
-		if let Some(text) = likes_text {
-			desc_parts.push(text);
+		let counts = [likes_text, views_text]
+			.into_iter()
+			.flatten()
+			.collect::<Vec<_>>();
+		if !counts.is_empty() {
+			desc_parts.push(counts.join(" · "));
 		}
 
 		if let Some(text) = pages_text {
 			desc_parts.push(text);
 		}
 
+		if let Some(text) = eps_text {
+			desc_parts.push(text);
+		}
+
+		if let Some(text) = team_text {
+			desc_parts.push(text);
+		}
+
+		if !state_text.is_empty() {
+			desc_parts.push(state_text);
+		}
+
 		if let Some(desc) = item.description
 			&& !desc.trim().is_empty()
 		{
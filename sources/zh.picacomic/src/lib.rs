@@ -1,15 +1,18 @@
 #![no_std]
 
 use aidoku::{
-	BasicLoginHandler, Chapter, DeepLinkHandler, DeepLinkResult, FilterValue, Listing,
-	ListingProvider, Manga, MangaPageResult, Page, Result, Source,
-	alloc::{String, Vec},
+	BasicLoginHandler, Chapter, DeepLinkHandler, DeepLinkResult, DynamicSettings, FilterValue,
+	GroupSetting, Listing, ListingProvider, Manga, MangaPageResult, NotificationHandler, Page,
+	Result, Setting, Source,
+	alloc::{String, Vec, vec},
+	imports::error::AidokuError,
 	prelude::*,
 };
 
 mod crypto;
 mod home;
 mod json;
+mod link;
 mod net;
 mod settings;
 
@@ -180,8 +183,67 @@ impl ListingProvider for Picacomic {
 }
 
 impl DeepLinkHandler for Picacomic {
-	fn handle_deep_link(&self, _url: String) -> Result<Option<DeepLinkResult>> {
-		Ok(None)
+	fn handle_deep_link(&self, url: String) -> Result<Option<DeepLinkResult>> {
+		Ok(link::parse(&url))
+	}
+}
+
+impl NotificationHandler for Picacomic {
+	fn handle_notification(&self, notification: String) {
+		let key = notification.as_str();
+		if !matches!(key, settings::FAVOURITE_KEY | settings::LIKE_KEY) {
+			return;
+		}
+
+		let status = match toggle(key == settings::FAVOURITE_KEY) {
+			Ok(status) => status,
+			Err(AidokuError::Message(reason)) => format!("操作失败：{reason}"),
+			Err(_) => "操作失败：网络错误".into(),
+		};
+		settings::set_action_status(&status);
+	}
+}
+
+/// Toggles the favourite or like state of the comic entered in settings,
+/// describing the action the server performed.
+fn toggle(favourite: bool) -> Result<String> {
+	let link = settings::take_comic_link().ok_or_else(|| error!("请先输入漫画链接或 ID"))?;
+	let id = match link::parse(&link) {
+		Some(DeepLinkResult::Manga { key } | DeepLinkResult::Chapter { manga_key: key, .. }) => key,
+		_ => bail!("无法识别漫画链接：{link}"),
+	};
+
+	let url = if favourite {
+		net::Url::ToggleFavourite { id: id.clone() }
+	} else {
+		net::Url::ToggleLike { id: id.clone() }
+	};
+	let response: json::ActionResponse = net::request_json(url)?;
+	let action = match response.data.action.as_str() {
+		"favourite" => "已收藏",
+		"un_favourite" => "已取消收藏",
+		"like" => "已点赞",
+		"unlike" => "已取消点赞",
+		other => other,
+	};
+	Ok(format!("漫画 {id}：{action}"))
+}
+
+impl DynamicSettings for Picacomic {
+	fn get_dynamic_settings(&self) -> Result<Vec<Setting>> {
+		let Some(status) = settings::get_action_status() else {
+			return Ok(Vec::new());
+		};
+		Ok(vec![
+			GroupSetting {
+				key: "actionStatus".into(),
+				title: "操作结果".into(),
+				items: Vec::new(),
+				footer: Some(status.into()),
+				..Default::default()
+			}
+			.into(),
+		])
 	}
 }
 
@@ -202,5 +264,7 @@ register_source!(
 	Home,
 	ListingProvider,
 	DeepLinkHandler,
-	BasicLoginHandler
+	BasicLoginHandler,
+	NotificationHandler,
+	DynamicSettings
 );
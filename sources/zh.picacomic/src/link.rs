@@ -0,0 +1,111 @@
+use aidoku::{DeepLinkResult, alloc::String};
+
+/// Parses a comic id, a web link or an app share link into a comic and an
+/// optional chapter.
+///
+/// Supported formats:
+/// - `5821859b5f6b9a4f93dbf6e9`
+/// - `https://manhuabika.com/pcomicview/?cid=5821859b5f6b9a4f93dbf6e9`
+/// - `https://manhuabika.com/pchapter/?cid=5821859b5f6b9a4f93dbf6e9&chapter=2`
+/// - `https://picaapi.picacomic.com/comics/5821859b5f6b9a4f93dbf6e9/order/2/pages`
+/// - `pica://comic/5821859b5f6b9a4f93dbf6e9`
+pub fn parse(link: &str) -> Option<DeepLinkResult> {
+	let link = link.trim();
+
+	if is_comic_id(link) {
+		return Some(DeepLinkResult::Manga { key: link.into() });
+	}
+
+	let (path, query) = link.split_once('?').unwrap_or((link, ""));
+
+	let mut manga_key = query_value(query, "cid").filter(|id| is_comic_id(id));
+	let mut key = query_value(query, "chapter")
+		.or_else(|| query_value(query, "order"))
+		.filter(|order| order.parse::<i32>().is_ok());
+
+	if manga_key.is_none() {
+		let mut segments = path.split('/').filter(|segment| !segment.is_empty());
+		while let Some(segment) = segments.next() {
+			if !matches!(segment, "comic" | "comics") {
+				continue;
+			}
+			manga_key = segments.next().filter(|id| is_comic_id(id));
+			if segments.next() == Some("order") {
+				key = segments.next().filter(|order| order.parse::<i32>().is_ok());
+			}
+			break;
+		}
+	}
+
+	let manga_key: String = manga_key?.into();
+	Some(match key {
+		Some(key) => DeepLinkResult::Chapter {
+			manga_key,
+			key: key.into(),
+		},
+		None => DeepLinkResult::Manga { key: manga_key },
+	})
+}
+
+/// Comic ids are MongoDB object ids
+fn is_comic_id(id: &str) -> bool {
+	id.len() == 24 && id.bytes().all(|byte| byte.is_ascii_hexdigit())
+}
+
+fn query_value<'a>(query: &'a str, name: &str) -> Option<&'a str> {
+	query
+		.split('&')
+		.filter_map(|pair| pair.split_once('='))
+		.find_map(|(key, value)| (key == name).then_some(value))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use aidoku_test::aidoku_test;
+
+	const ID: &str = "5821859b5f6b9a4f93dbf6e9";
+
+	fn is_manga(result: Option<DeepLinkResult>) -> bool {
+		matches!(result, Some(DeepLinkResult::Manga { key }) if key == ID)
+	}
+
+	fn is_chapter(result: Option<DeepLinkResult>, order: &str) -> bool {
+		matches!(
+			result,
+			Some(DeepLinkResult::Chapter { manga_key, key }) if manga_key == ID && key == order
+		)
+	}
+
+	#[aidoku_test]
+	fn comic_links() {
+		assert!(is_manga(parse(ID)));
+		assert!(is_manga(parse(" 5821859b5f6b9a4f93dbf6e9\n")));
+		assert!(is_manga(parse(
+			"https://manhuabika.com/pcomicview/?cid=5821859b5f6b9a4f93dbf6e9"
+		)));
+		assert!(is_manga(parse("pica://comic/5821859b5f6b9a4f93dbf6e9")));
+	}
+
+	#[aidoku_test]
+	fn chapter_links() {
+		assert!(is_chapter(
+			parse("https://manhuabika.com/pchapter/?cid=5821859b5f6b9a4f93dbf6e9&chapter=2"),
+			"2"
+		));
+		assert!(is_chapter(
+			parse("https://picaapi.picacomic.com/comics/5821859b5f6b9a4f93dbf6e9/order/2/pages"),
+			"2"
+		));
+	}
+
+	#[aidoku_test]
+	fn invalid_links() {
+		assert!(parse("https://manhuabika.com/pcomicview/?cid=12345").is_none());
+		assert!(parse("https://manhuabika.com/").is_none());
+		// a bad chapter still opens the comic
+		assert!(is_manga(parse(
+			"https://manhuabika.com/pchapter/?cid=5821859b5f6b9a4f93dbf6e9&chapter=last"
+		)));
+	}
+}
@@ -69,6 +69,12 @@ pub enum Url {
 		chapter_id: String,
 		page: i32,
 	},
+	ToggleFavourite {
+		id: String,
+	},
+	ToggleLike {
+		id: String,
+	},
 }
 
 impl Url {
@@ -146,7 +152,9 @@ impl Url {
 	pub fn request(&self) -> Result<Request> {
 		let url = self.to_string();
 		let method = match self {
-			Url::Search { .. } => HttpMethod::Post,
+			Url::Search { .. } | Url::ToggleFavourite { .. } | Url::ToggleLike { .. } => {
+				HttpMethod::Post
+			}
 			_ => HttpMethod::Get,
 		};
 		let body = match self {
@@ -232,6 +240,12 @@ impl Display for Url {
 					API_URL, manga_id, chapter_id, page
 				)
 			}
+			Url::ToggleFavourite { id } => {
+				write!(f, "{}/comics/{}/favourite", API_URL, id)
+			}
+			Url::ToggleLike { id } => {
+				write!(f, "{}/comics/{}/like", API_URL, id)
+			}
 		}
 	}
 }
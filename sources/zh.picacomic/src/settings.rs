@@ -38,3 +38,25 @@ pub fn set_password(password: &str) -> Result<()> {
 	defaults_set("password", DefaultValue::String(password.to_string()));
 	Ok(())
 }
+
+pub const FAVOURITE_KEY: &str = "favouriteComic";
+pub const LIKE_KEY: &str = "likeComic";
+const COMIC_LINK_KEY: &str = "comicLink";
+const ACTION_STATUS_KEY: &str = "actionStatus";
+
+/// Reads the comic link entered in the action settings and clears the field so
+/// a second tap doesn't toggle the same comic back.
+pub fn take_comic_link() -> Option<String> {
+	let link = defaults_get::<String>(COMIC_LINK_KEY).filter(|link| !link.trim().is_empty())?;
+	defaults_set(COMIC_LINK_KEY, DefaultValue::String(String::new()));
+	Some(link)
+}
+
+/// Outcome of the last favourite or like action, shown in the settings footer.
+pub fn get_action_status() -> Option<String> {
+	defaults_get::<String>(ACTION_STATUS_KEY).filter(|status| !status.is_empty())
+}
+
+pub fn set_action_status(status: &str) {
+	defaults_set(ACTION_STATUS_KEY, DefaultValue::String(status.to_string()));
+}
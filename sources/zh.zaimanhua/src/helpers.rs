@@ -184,7 +184,7 @@ struct AuthorMatchResult {
 	match_type: MatchResult,
 }
 
-fn parse_manga_id(keyword: &str) -> Option<String> {
+pub fn parse_manga_id(keyword: &str) -> Option<String> {
 	let trimmed = keyword.trim();
 
 	if trimmed.chars().all(|c| c.is_ascii_digit()) && !trimmed.is_empty() {
@@ -7,7 +7,7 @@ use aidoku::{
 	Result, SelectFilter, Setting, Source,
 	alloc::{String, Vec, borrow::Cow, format, string::ToString},
 	helpers::uri::QueryParameters,
-	imports::net::Request,
+	imports::{error::AidokuError, net::Request},
 	prelude::*,
 };
 
@@ -17,6 +17,8 @@ mod models;
 mod net;
 mod settings;
 
+use zhconv::Script;
+
 pub const BASE_URL: &str = "https://www.zaimanhua.com";
 pub const V4_API_URL: &str = "https://v4api.zaimanhua.com/app/v1";
 pub const ACCOUNT_API: &str = "https://account-api.zaimanhua.com/v1";
@@ -36,77 +38,12 @@ impl Source for Zaimanhua {
 		&self,
 		query: Option<String>,
 		page: i32,
-		filters: Vec<FilterValue>,
+		mut filters: Vec<FilterValue>,
 	) -> Result<MangaPageResult> {
-		for filter in &filters {
-			if let FilterValue::Text { id, value } = filter {
-				if id == "author" {
-					return helpers::search_by_author(value, page);
-				}
-				return helpers::search_by_keyword(value, page);
-			}
-		}
-
-		if let Some(keyword) = query.as_deref()
-			&& !keyword.is_empty()
-		{
-			return helpers::search_by_keyword(keyword, page);
-		}
-
-		let mut sort_type: Option<&str> = None;
-		let mut zone: Option<&str> = None;
-		let mut status: Option<&str> = None;
-		let mut cate: Option<&str> = None;
-		let mut theme: Option<&str> = None;
-		let mut rank_mode: Option<&str> = None;
-		let mut genre: Option<&str> = None;
-
-		for filter in &filters {
-			if let FilterValue::Select { id, value } = filter {
-				match id.as_str() {
-					"排序" => sort_type = Some(value.as_str()),
-					"地区" => zone = Some(value.as_str()),
-					"状态" => status = Some(value.as_str()),
-					"受众" => cate = Some(value.as_str()),
-					"题材" => theme = Some(value.as_str()),
-					"榜单" => rank_mode = Some(value.as_str()),
-					"genre" => genre = Some(value.as_str()),
-					_ => {}
-				}
-			}
-		}
-
-		if let Some(mode @ ("1" | "2" | "3" | "4")) = rank_mode {
-			let by_time = match mode {
-				"2" => 1,
-				"3" => 2,
-				"4" => 3,
-				_ => 0,
-			};
-			let url = net::urls::rank(by_time, page);
-			let response: models::ApiResponse<Vec<models::RankItem>> =
-				net::auth_request(&url, settings::get_token().as_deref())?.json_owned()?;
-			let data = response.data.unwrap_or_default();
-			return Ok(models::manga_list_from_ranks(data));
-		}
-
-		let genre = genre.map(helpers::resolve_theme_id).transpose()?;
-
-		let mut qs = QueryParameters::new();
-		qs.push("sortType", Some(sort_type.unwrap_or("1")));
-		qs.push("cate", Some(cate.unwrap_or("0")));
-		qs.push("status", Some(status.unwrap_or("0")));
-		qs.push("zone", Some(zone.unwrap_or("0")));
-		qs.push("theme", Some(genre.as_deref().or(theme).unwrap_or("0")));
-
-		let url = format!("{}&size=20", net::urls::filter(&qs.to_string(), page));
-		let response: models::ApiResponse<models::FilterData> =
-			net::auth_request(&url, settings::get_token().as_deref())?.json_owned()?;
-		let data = response
-			.data
-			.map(|d| d.comic_list)
-			.ok_or_else(|| error!("筛选数据缺失"))?;
-		Ok(models::manga_list_from_filter(data))
+		// titles and themes are indexed in simplified Chinese
+		let query = query.map(|query| zhconv::convert(&query, Script::Simplified));
+		zhconv::normalize_filters(&mut filters, Script::Simplified);
+		search_manga_list(query, page, filters).map(zhconv::localize)
 	}
 
 	fn get_manga_update(
@@ -134,7 +71,7 @@ impl Source for Zaimanhua {
 			manga.copy_from(manga_detail.into_manga(manga.key.clone()));
 		}
 
-		Ok(manga)
+		Ok(zhconv::localize(manga))
 	}
 
 	fn get_page_list(&self, manga: Manga, chapter: Chapter) -> Result<Vec<Page>> {
@@ -166,6 +103,82 @@ impl Source for Zaimanhua {
 	}
 }
 
+fn search_manga_list(
+	query: Option<String>,
+	page: i32,
+	filters: Vec<FilterValue>,
+) -> Result<MangaPageResult> {
+	for filter in &filters {
+		if let FilterValue::Text { id, value } = filter {
+			if id == "author" {
+				return helpers::search_by_author(value, page);
+			}
+			return helpers::search_by_keyword(value, page);
+		}
+	}
+
+	if let Some(keyword) = query.as_deref()
+		&& !keyword.is_empty()
+	{
+		return helpers::search_by_keyword(keyword, page);
+	}
+
+	let mut sort_type: Option<&str> = None;
+	let mut zone: Option<&str> = None;
+	let mut status: Option<&str> = None;
+	let mut cate: Option<&str> = None;
+	let mut theme: Option<&str> = None;
+	let mut rank_mode: Option<&str> = None;
+	let mut genre: Option<&str> = None;
+
+	for filter in &filters {
+		if let FilterValue::Select { id, value } = filter {
+			match id.as_str() {
+				"排序" => sort_type = Some(value.as_str()),
+				"地区" => zone = Some(value.as_str()),
+				"状态" => status = Some(value.as_str()),
+				"受众" => cate = Some(value.as_str()),
+				"题材" => theme = Some(value.as_str()),
+				"榜单" => rank_mode = Some(value.as_str()),
+				"genre" => genre = Some(value.as_str()),
+				_ => {}
+			}
+		}
+	}
+
+	if let Some(mode @ ("1" | "2" | "3" | "4")) = rank_mode {
+		let by_time = match mode {
+			"2" => 1,
+			"3" => 2,
+			"4" => 3,
+			_ => 0,
+		};
+		let url = net::urls::rank(by_time, page);
+		let response: models::ApiResponse<Vec<models::RankItem>> =
+			net::auth_request(&url, settings::get_token().as_deref())?.json_owned()?;
+		let data = response.data.unwrap_or_default();
+		return Ok(models::manga_list_from_ranks(data));
+	}
+
+	let genre = genre.map(helpers::resolve_theme_id).transpose()?;
+
+	let mut qs = QueryParameters::new();
+	qs.push("sortType", Some(sort_type.unwrap_or("1")));
+	qs.push("cate", Some(cate.unwrap_or("0")));
+	qs.push("status", Some(status.unwrap_or("0")));
+	qs.push("zone", Some(zone.unwrap_or("0")));
+	qs.push("theme", Some(genre.as_deref().or(theme).unwrap_or("0")));
+
+	let url = format!("{}&size=20", net::urls::filter(&qs.to_string(), page));
+	let response: models::ApiResponse<models::FilterData> =
+		net::auth_request(&url, settings::get_token().as_deref())?.json_owned()?;
+	let data = response
+		.data
+		.map(|d| d.comic_list)
+		.ok_or_else(|| error!("筛选数据缺失"))?;
+	Ok(models::manga_list_from_filter(data))
+}
+
 impl ImageRequestProvider for Zaimanhua {
 	fn get_image_request(&self, url: String, _context: Option<PageContext>) -> Result<Request> {
 		let resolved = net::resolve_url(&url);
@@ -245,8 +258,24 @@ impl NotificationHandler for Zaimanhua {
 				settings::clear_token();
 				settings::clear_checkin_flag();
 				settings::clear_user_cache();
+				settings::clear_subscribe_status();
 				settings::reset_dependent_settings();
 			}
+		} else if let key @ (settings::SUBSCRIBE_KEY | settings::UNSUBSCRIBE_KEY) =
+			notification.as_str()
+			&& let Some(link) = settings::take_comic_link(key)
+		{
+			let subscribe = key == settings::SUBSCRIBE_KEY;
+			let action = if subscribe { "订阅" } else { "取消订阅" };
+			let status = match helpers::parse_manga_id(&link) {
+				Some(id) => match net::set_subscribed(&id, subscribe) {
+					Ok(()) => format!("漫画 {id} {action}成功"),
+					Err(AidokuError::Message(reason)) => format!("{action}失败：{reason}"),
+					Err(_) => format!("{action}失败：网络错误"),
+				},
+				None => format!("{action}失败：无法识别漫画 ID 或链接"),
+			};
+			settings::set_subscribe_status(&status);
 		}
 	}
 }
@@ -281,6 +310,10 @@ impl DynamicSettings for Zaimanhua {
 				footer_text = format!("{footer_text}\n※ 绑定手机号码访问更多内容");
 			}
 
+			if let Some(status) = settings::get_subscribe_status() {
+				footer_text = format!("{footer_text}\n{status}");
+			}
+
 			settings.push(
 				GroupSetting {
 					key: "userInfo".into(),
@@ -299,53 +332,69 @@ impl DynamicSettings for Zaimanhua {
 
 impl Home for Zaimanhua {
 	fn get_home(&self) -> Result<HomeLayout> {
-		home::get_home_layout()
+		home::get_home_layout().map(zhconv::localize)
 	}
 }
 
 impl ListingProvider for Zaimanhua {
 	fn get_manga_list(&self, listing: Listing, page: i32) -> Result<MangaPageResult> {
-		if listing.id == "rank-monthly" {
-			let url = net::urls::rank(2, page);
-			let response: models::ApiResponse<Vec<models::RankItem>> =
-				net::auth_request(&url, settings::get_token().as_deref())?.json_owned()?;
-			let data = response.data.unwrap_or_default();
-			return Ok(models::manga_list_from_ranks(data));
-		}
-
-		let filter_param = match listing.id.as_str() {
-			"latest" => "sortType=1",
-			"ongoing" => "status=2309",
-			"completed" => "status=2310",
-			"short" => "status=29205",
-			"shounen" => "cate=3262",
-			"shoujo" => "cate=3263",
-			"seinen" => "cate=3264",
-			"josei" => "cate=13626",
-			"subscribe" => {
-				let token = settings::get_token().ok_or_else(|| error!("请先登录"))?;
-
-				let url = net::urls::sub_list(page);
-				let response: models::ApiResponse<models::SubscribeData> =
-					net::auth_request(&url, Some(&token))?.json_owned()?;
-				let data = response
-					.data
-					.map(|d| d.sub_list)
-					.ok_or_else(|| error!("订阅数据缺失"))?;
-				return Ok(models::manga_list_from_subscribes(data));
-			}
-			_ => bail!("未知列表请求"),
-		};
+		manga_list(listing, page).map(zhconv::localize)
+	}
+}
 
-		let url = format!("{}&size=20", net::urls::filter(filter_param, page));
-		let response: models::ApiResponse<models::FilterData> =
+fn manga_list(listing: Listing, page: i32) -> Result<MangaPageResult> {
+	if listing.id == "rank-monthly" {
+		let url = net::urls::rank(2, page);
+		let response: models::ApiResponse<Vec<models::RankItem>> =
 			net::auth_request(&url, settings::get_token().as_deref())?.json_owned()?;
-		let data = response
-			.data
-			.map(|d| d.comic_list)
-			.ok_or_else(|| error!("筛选数据缺失"))?;
-		Ok(models::manga_list_from_filter(data))
+		let data = response.data.unwrap_or_default();
+		return Ok(models::manga_list_from_ranks(data));
 	}
+
+	let filter_param = match listing.id.as_str() {
+		"latest" => "sortType=1",
+		"ongoing" => "status=2309",
+		"completed" => "status=2310",
+		"short" => "status=29205",
+		"shounen" => "cate=3262",
+		"shoujo" => "cate=3263",
+		"seinen" => "cate=3264",
+		"josei" => "cate=13626",
+		"subscribe" => {
+			let token = settings::get_token().ok_or_else(|| error!("请先登录"))?;
+
+			let url = net::urls::sub_list(page);
+			let response: models::ApiResponse<models::SubscribeData> =
+				net::auth_request(&url, Some(&token))?.json_owned()?;
+			let data = response
+				.data
+				.map(|d| d.sub_list)
+				.ok_or_else(|| error!("订阅数据缺失"))?;
+			return Ok(models::manga_list_from_subscribes(data));
+		}
+		"history" => {
+			let token = settings::get_token().ok_or_else(|| error!("请先登录"))?;
+
+			let url = net::urls::history(page);
+			let response: models::ApiResponse<models::HistoryData> =
+				net::send_authed_request(&url, Some(&token))?;
+			let data = response
+				.data
+				.map(|d| d.list)
+				.ok_or_else(|| error!("历史数据缺失"))?;
+			return Ok(models::manga_list_from_history(data));
+		}
+		_ => bail!("未知列表请求"),
+	};
+
+	let url = format!("{}&size=20", net::urls::filter(filter_param, page));
+	let response: models::ApiResponse<models::FilterData> =
+		net::auth_request(&url, settings::get_token().as_deref())?.json_owned()?;
+	let data = response
+		.data
+		.map(|d| d.comic_list)
+		.ok_or_else(|| error!("筛选数据缺失"))?;
+	Ok(models::manga_list_from_filter(data))
 }
 
 impl DynamicFilters for Zaimanhua {
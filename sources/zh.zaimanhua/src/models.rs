@@ -319,6 +319,36 @@ impl From<SubscribeItem> for Manga {
 	}
 }
 
+#[derive(Deserialize)]
+pub struct HistoryData {
+	#[serde(alias = "historyList")]
+	pub list: Vec<HistoryItem>,
+}
+
+#[derive(Deserialize)]
+pub struct HistoryItem {
+	#[serde(alias = "comic_id")]
+	pub id: i64,
+	#[serde(alias = "comic_name")]
+	pub name: Option<String>,
+	pub cover: Option<String>,
+	pub chapter_name: Option<String>,
+}
+
+impl From<HistoryItem> for Manga {
+	fn from(item: HistoryItem) -> Self {
+		let key = item.id.to_string();
+
+		Self {
+			key,
+			title: item.name.unwrap_or_default(),
+			cover: item.cover,
+			description: item.chapter_name.map(|name| format!("上次看到：{name}")),
+			..Default::default()
+		}
+	}
+}
+
 #[derive(Deserialize)]
 pub struct ChapterData {
 	pub data: ChapterPageData,
@@ -390,6 +420,20 @@ pub fn manga_list_from_ranks(items: Vec<RankItem>) -> MangaPageResult {
 	}
 }
 
+pub fn manga_list_from_history(items: Vec<HistoryItem>) -> MangaPageResult {
+	// a full page means there may be more, see `net::urls::history`
+	let has_next_page = items.len() >= 50;
+	let entries: Vec<Manga> = items
+		.into_iter()
+		.filter(|item| item.id > 0)
+		.map(Into::into)
+		.collect();
+	MangaPageResult {
+		entries,
+		has_next_page,
+	}
+}
+
 pub fn manga_list_from_subscribes(items: Vec<SubscribeItem>) -> MangaPageResult {
 	let entries: Vec<Manga> = items
 		.into_iter()
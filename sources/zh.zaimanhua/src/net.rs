@@ -64,6 +64,18 @@ pub mod urls {
 		format!("{V4_API_URL}/comic/sub/list?status=0&firstLetter=&page={page}&size=50")
 	}
 
+	pub fn sub_add(comic_id: &str) -> String {
+		format!("{V4_API_URL}/comic/sub/add?comic_id={comic_id}")
+	}
+
+	pub fn sub_del(comic_id: &str) -> String {
+		format!("{V4_API_URL}/comic/sub/del?comic_id={comic_id}")
+	}
+
+	pub fn history(page: i32) -> String {
+		format!("{V4_API_URL}/comic/history/list?page={page}&size=50")
+	}
+
 	pub fn manga_news() -> String {
 		let news_url = crate::NEWS_URL;
 		format!("{news_url}/manhuaqingbao")
@@ -163,6 +175,23 @@ pub fn login(username: &str, password: &str) -> Result<Option<String>> {
 	Ok(response.data.and_then(|d| d.user).and_then(|u| u.token))
 }
 
+/// Adds the comic to, or removes it from, the account's subscriptions.
+pub fn set_subscribed(comic_id: &str, subscribed: bool) -> Result<()> {
+	let token = settings::get_token().ok_or_else(|| aidoku::error!("请先登录"))?;
+	let url = if subscribed {
+		urls::sub_add(comic_id)
+	} else {
+		urls::sub_del(comic_id)
+	};
+	let response: models::ApiResponse<aidoku::serde::de::IgnoredAny> =
+		send_authed_request(&url, Some(&token))?;
+	if response.errno.unwrap_or(-1) != 0 {
+		let errmsg = response.errmsg.as_deref().unwrap_or("未知错误");
+		aidoku::bail!("{errmsg}");
+	}
+	Ok(())
+}
+
 fn check_in(token: &str) -> Result<bool> {
 	let url = format!("{SIGN_API}/task/sign_in");
 	let response: models::ApiResponse<aidoku::serde::de::IgnoredAny> = post_request(&url)?
@@ -12,6 +12,9 @@ const AUTO_CHECKIN_KEY: &str = "autoCheckin";
 const LAST_CHECKIN_KEY: &str = "lastCheckin";
 const USE_PROXY_KEY: &str = "useProxy";
 const PROXY_URL_KEY: &str = "proxyUrl";
+pub const SUBSCRIBE_KEY: &str = "subscribeComic";
+pub const UNSUBSCRIBE_KEY: &str = "unsubscribeComic";
+const SUBSCRIBE_STATUS_KEY: &str = "subscribeStatus";
 
 pub fn get_credentials() -> Option<(String, String)> {
 	let username = defaults_get::<String>(USERNAME_KEY)?;
@@ -124,6 +127,27 @@ pub fn is_cache_stale() -> bool {
 	true
 }
 
+/// Reads a comic link entered in the subscription settings and clears the
+/// field so the same action isn't repeated.
+pub fn take_comic_link(key: &str) -> Option<String> {
+	let link = defaults_get::<String>(key).filter(|link| !link.trim().is_empty())?;
+	defaults_set(key, DefaultValue::String(String::new()));
+	Some(link)
+}
+
+/// Outcome of the last subscription change, shown in the account footer.
+pub fn get_subscribe_status() -> Option<String> {
+	defaults_get::<String>(SUBSCRIBE_STATUS_KEY).filter(|status| !status.is_empty())
+}
+
+pub fn set_subscribe_status(status: &str) {
+	defaults_set(SUBSCRIBE_STATUS_KEY, DefaultValue::String(status.into()));
+}
+
+pub fn clear_subscribe_status() {
+	defaults_set(SUBSCRIBE_STATUS_KEY, DefaultValue::Null);
+}
+
 pub fn reset_dependent_settings() {
 	defaults_set(AUTO_CHECKIN_KEY, DefaultValue::Null);
 }
@@ -0,0 +1,263 @@
+use crate::{
+	Params,
+	json::{EncryptedJson as _, MangaItem, page_list},
+	net::Url,
+};
+use aidoku::{
+	Manga, MangaPageResult, MangaStatus, Page, Result, SelectFilter,
+	alloc::{String, Vec, borrow::ToOwned as _, format, string::ToString as _},
+	error,
+	imports::{
+		html::{Document, Element, ElementList},
+		js::JsContext,
+	},
+};
+
+pub trait GenresPage {
+	fn filter(&self) -> Result<SelectFilter>;
+}
+
+impl GenresPage for Document {
+	fn filter(&self) -> Result<SelectFilter> {
+		let (mut options, mut ids) = self
+			.try_select("div#all a:not([disabled])")?
+			.filter_map(|element| {
+				let option = element.own_text()?.into();
+				let id = element.attr("href")?.rsplit_once('=')?.1.to_owned().into();
+				Some((option, id))
+			})
+			.collect::<(Vec<_>, Vec<_>)>();
+
+		options.insert(0, "全部".into());
+		ids.insert(0, "".into());
+
+		Ok(SelectFilter {
+			id: "題材".into(),
+			title: Some("題材".into()),
+			is_genre: true,
+			uses_tag_style: true,
+			options,
+			ids: Some(ids),
+			..Default::default()
+		})
+	}
+}
+
+pub trait FiltersPage {
+	fn manga_page_result(&self, base_url: &str) -> Result<MangaPageResult>;
+}
+
+impl FiltersPage for Document {
+	fn manga_page_result(&self, base_url: &str) -> Result<MangaPageResult> {
+		let items = match self
+			.select_first("div.exemptComic-box")
+			.and_then(|element| element.attr("list"))
+		{
+			Some(single_quoted_json) => {
+				let json =
+					JsContext::new().eval(&format!("JSON.stringify({single_quoted_json})"))?;
+				serde_json::from_str::<Vec<MangaItem>>(&json)?
+			}
+			None => self
+				.try_select("div.exemptComicItem")?
+				.filter_map(manga_item)
+				.collect(),
+		};
+		let entries = items
+			.into_iter()
+			.map(|item| item.into_manga(base_url))
+			.collect();
+
+		let has_next_page = self
+			.select("li.page-all-item")
+			.and_then(|mut elements| elements.next_back())
+			.is_some_and(|element| !element.has_class("active"));
+
+		Ok(MangaPageResult {
+			entries,
+			has_next_page,
+		})
+	}
+}
+
+fn manga_item(element: Element) -> Option<MangaItem> {
+	let link = element.select_first("div.exemptComicItem-txt a")?;
+	let path_word = link
+		.attr("href")?
+		.split_once("/comic/")?
+		.1
+		.trim_end_matches('/')
+		.into();
+
+	let name = link
+		.select_first("p.twoLines")?
+		.own_text()?
+		.trim()
+		.to_string();
+
+	let cover = element.select_first("img").and_then(|img| {
+		img.attr("data-src")
+			.filter(|src| !src.is_empty())
+			.or_else(|| img.attr("src"))
+	})?;
+
+	let author = element
+		.select_first("span.exemptComicItem-txt-span")
+		.and_then(|span| span.own_text())
+		.map(|text| text.trim_start_matches("作者：").trim().into())
+		.filter(|name: &String| !name.is_empty())
+		.map(|name| [crate::json::Author { name }].into())
+		.unwrap_or_default();
+
+	Some(MangaItem {
+		path_word,
+		name,
+		cover,
+		status: None,
+		author,
+	})
+}
+
+pub trait MangaPage {
+	fn update_details(&self, params: &Params, base_url: &str, manga: &mut Manga) -> Result<()>;
+}
+
+impl MangaPage for Document {
+	fn update_details(&self, params: &Params, base_url: &str, manga: &mut Manga) -> Result<()> {
+		manga.title = self
+			.try_select_first("h6")?
+			.text()
+			.ok_or_else(|| error!("Text not found"))?;
+
+		manga.cover = self
+			.try_select_first(&params.cover_selector)?
+			.attr("data-src")
+			.or_else(|| self.select_first(&params.cover_selector)?.attr("src"))
+			.map(|resized| resized.replace(".328x422.jpg", ""));
+
+		let authors = self
+			.try_select(&params.authors_selector)?
+			.filter_map(|element| element.text())
+			.collect();
+		manga.authors = Some(authors);
+
+		manga.description = self.try_select_first("p.intro")?.text();
+
+		manga.url = Some(Url::manga(&manga.key).with_base(base_url));
+
+		let tags = self
+			.try_select(&params.tags_selector)?
+			.filter_map(|element| {
+				let text = element.text()?;
+				let tag = text.strip_prefix('#').unwrap_or(&text).trim().into();
+				Some(tag)
+			})
+			.collect();
+		manga.tags = Some(tags);
+
+		manga.status = match self
+			.try_select_first(&params.status_selector)?
+			.text()
+			.as_deref()
+		{
+			Some("連載中") => MangaStatus::Ongoing,
+			Some("已完結" | "短篇") => MangaStatus::Completed,
+			_ => MangaStatus::Unknown,
+		};
+
+		Ok(())
+	}
+}
+
+pub trait KeyPage {
+	fn key(&self) -> Result<String>;
+	fn dnt(&self) -> Option<String>;
+}
+
+impl KeyPage for Document {
+	fn key(&self) -> Result<String> {
+		if let Some(key) = self
+			.select_first("div.disPass")
+			.and_then(|element| element.attr("contentKey"))
+		{
+			return Ok(key);
+		}
+
+		if let Some(key) = self
+			.select_first("div.disposablePass")
+			.and_then(|element| element.attr("disposable"))
+		{
+			return Ok(key);
+		}
+
+		let key = self
+			.script()?
+			.split('\'')
+			.nth(1)
+			.ok_or_else(|| error!("Key not found"))?
+			.into();
+		Ok(key)
+	}
+
+	fn dnt(&self) -> Option<String> {
+		self.select_first("#dnt")?.attr("value")
+	}
+}
+
+pub trait ChapterPage {
+	fn pages(&self) -> Result<Vec<Page>>;
+}
+
+impl ChapterPage for Document {
+	fn pages(&self) -> Result<Vec<Page>> {
+		let key = self.key()?;
+		let data = match self
+			.select_first("div.disData")
+			.and_then(|element| element.attr("contentKey"))
+		{
+			Some(data) => data,
+			None => self
+				.script()?
+				.split_once("var contentKey = '")
+				.ok_or_else(|| error!("String not found: `var contentKey = '`"))?
+				.1
+				.split_once("';")
+				.ok_or_else(|| error!("String not found: `';`"))?
+				.0
+				.into(),
+		};
+		let json = data.decrypt(&key)?;
+		serde_json::from_slice::<Vec<page_list::Item>>(&json)?
+			.into_iter()
+			.map(TryInto::try_into)
+			.collect()
+	}
+}
+
+trait TrySelect {
+	fn try_select<S: AsRef<str>>(&self, css_query: S) -> Result<ElementList>;
+	fn try_select_first<S: AsRef<str>>(&self, css_query: S) -> Result<Element>;
+	fn script(&self) -> Result<String>;
+}
+
+impl TrySelect for Document {
+	fn try_select<S: AsRef<str>>(&self, css_query: S) -> Result<ElementList> {
+		self.select(&css_query)
+			.ok_or_else(|| error!("No element found for selector: `{}`", css_query.as_ref()))
+	}
+
+	fn try_select_first<S: AsRef<str>>(&self, css_query: S) -> Result<Element> {
+		self.select_first(&css_query)
+			.ok_or_else(|| error!("No element found for selector: `{}`", css_query.as_ref()))
+	}
+
+	/// The inline script that declares the page variables
+	fn script(&self) -> Result<String> {
+		self.try_select("script:not([*])")?
+			.find_map(|element| {
+				let data = element.data()?;
+				data.contains("var").then_some(data)
+			})
+			.ok_or_else(|| error!("No script content contains `var`"))
+	}
+}
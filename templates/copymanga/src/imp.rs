@@ -0,0 +1,114 @@
+use crate::{
+	Params,
+	html::{ChapterPage as _, FiltersPage as _, GenresPage as _, KeyPage as _, MangaPage as _},
+	json::{chapter_list, search},
+	net::Url,
+};
+use aidoku::{
+	Chapter, DeepLinkResult, Filter, FilterValue, Manga, MangaPageResult, Page, Result,
+	alloc::{String, Vec},
+	imports::std::send_partial_result,
+};
+
+pub trait Impl {
+	fn new() -> Self;
+
+	fn params(&self) -> Params;
+
+	fn get_search_manga_list(
+		&self,
+		params: &Params,
+		query: Option<String>,
+		page: i32,
+		filters: Vec<FilterValue>,
+	) -> Result<MangaPageResult> {
+		let base_url = params.base_url()?;
+		let url = Url::from_query_or_filters(params, query.as_deref(), page, &filters)?;
+		let request = url.request(params)?;
+		let manga_page_result = if url.is_filters() {
+			request.html()?.manga_page_result(&base_url)?
+		} else {
+			request
+				.json_owned::<search::Root>()?
+				.into_manga_page_result(&base_url)
+		};
+		Ok(manga_page_result)
+	}
+
+	fn get_manga_update(
+		&self,
+		params: &Params,
+		mut manga: Manga,
+		needs_details: bool,
+		needs_chapters: bool,
+	) -> Result<Manga> {
+		let base_url = params.base_url()?;
+		let manga_page = Url::manga(&manga.key).request(params)?.html()?;
+		if needs_details {
+			manga_page.update_details(params, &base_url, &mut manga)?;
+
+			if needs_chapters {
+				send_partial_result(&manga);
+			} else {
+				return Ok(manga);
+			}
+		}
+
+		let key = manga_page.key()?;
+		let dnts = match &params.fixed_dnts {
+			Some(dnts) => dnts.clone().into(),
+			None => manga_page
+				.dnt()
+				.unwrap_or_else(|| params.default_dnts.clone().into()),
+		};
+		manga.chapters = Url::chapter_list(&manga.key)
+			.request(params)?
+			.header("dnts", &dnts)
+			.json_owned::<chapter_list::Root>()?
+			.chapters(&key, &base_url)?;
+
+		Ok(manga)
+	}
+
+	fn get_page_list(&self, params: &Params, manga: Manga, chapter: Chapter) -> Result<Vec<Page>> {
+		Url::chapter(&manga.key, &chapter.key)
+			.request(params)?
+			.html()?
+			.pages()
+	}
+
+	fn handle_deep_link(&self, _params: &Params, url: String) -> Result<Option<DeepLinkResult>> {
+		let mut splits = url.split('/').skip(3);
+		let deep_link_result = match splits.next() {
+			Some("comic") => match (splits.next(), splits.next(), splits.next()) {
+				(Some(key), None, None) => Some(DeepLinkResult::Manga { key: key.into() }),
+				(Some(manga_key), Some("chapter"), Some(key)) => Some(DeepLinkResult::Chapter {
+					manga_key: manga_key.into(),
+					key: key.into(),
+				}),
+				_ => None,
+			},
+
+			Some("h5") => match (splits.next(), splits.next(), splits.next()) {
+				(Some("details"), Some("comic"), Some(key)) => {
+					Some(DeepLinkResult::Manga { key: key.into() })
+				}
+				(Some("comicContent"), Some(manga_key), Some(key)) => {
+					Some(DeepLinkResult::Chapter {
+						manga_key: manga_key.into(),
+						key: key.into(),
+					})
+				}
+				_ => None,
+			},
+
+			_ => None,
+		};
+		Ok(deep_link_result)
+	}
+
+	fn get_dynamic_filters(&self, params: &Params) -> Result<Vec<Filter>> {
+		let genre = Url::GenresPage.request(params)?.html()?.filter()?.into();
+		Ok([genre].into())
+	}
+}
@@ -16,9 +16,9 @@ pub struct Root {
 }
 
 impl Root {
-	pub fn chapters(self, key: &str) -> Result<Option<Vec<Chapter>>> {
+	pub fn chapters(self, key: &str, base_url: &str) -> Result<Option<Vec<Chapter>>> {
 		let plain_text = self.results.decrypt(key)?;
-		let chapters = serde_json::from_slice::<Results>(&plain_text)?.into();
+		let chapters = serde_json::from_slice::<Results>(&plain_text)?.into_chapters(base_url);
 		Ok(chapters)
 	}
 }
@@ -37,12 +37,13 @@ struct Results {
 	groups: HashMap<String, Group>,
 }
 
-impl From<Results> for Option<Vec<Chapter>> {
-	fn from(results: Results) -> Self {
-		let mut groups = results
+impl Results {
+	fn into_chapters(self, base_url: &str) -> Option<Vec<Chapter>> {
+		let manga_key = self.build.path_word;
+		let mut groups = self
 			.groups
 			.into_values()
-			.map(|group| group.into_chapters(&results.build.path_word));
+			.map(|group| group.into_chapters(base_url, &manga_key));
 		let mut chapters = groups.next()?;
 		chapters.reverse();
 
@@ -80,10 +81,10 @@ struct Group {
 }
 
 impl Group {
-	fn into_chapters(self, manga_key: &str) -> Vec<Chapter> {
+	fn into_chapters(self, base_url: &str, manga_key: &str) -> Vec<Chapter> {
 		self.chapters
 			.into_iter()
-			.map(|chapter_item| chapter_item.into_chapter(manga_key, &self.name))
+			.map(|chapter_item| chapter_item.into_chapter(base_url, manga_key, &self.name))
 			.collect()
 	}
 }
@@ -96,7 +97,7 @@ struct ChapterItem {
 }
 
 impl ChapterItem {
-	fn into_chapter(self, manga_key: &str, group: &str) -> Chapter {
+	fn into_chapter(self, base_url: &str, manga_key: &str, group: &str) -> Chapter {
 		let key = self.id.to_string();
 
 		let (volume_number, chapter_number, title) = parse(self.r#type, self.name.trim());
@@ -108,7 +109,7 @@ impl ChapterItem {
 
 		let scanlators = [group.into()].into();
 
-		let url = Url::chapter(manga_key, &key).to_string().ok();
+		let url = Some(Url::chapter(manga_key, &key).with_base(base_url));
 
 		Chapter {
 			key,
@@ -23,25 +23,25 @@ pub struct MangaItem {
 	pub author: Vec<Author>,
 }
 
-impl From<MangaItem> for Manga {
-	fn from(item: MangaItem) -> Self {
-		let url = Url::manga(&item.path_word).into();
+impl MangaItem {
+	pub fn into_manga(self, base_url: &str) -> Manga {
+		let url = Url::manga(&self.path_word).with_base(base_url);
 
-		let key = item.path_word;
+		let key = self.path_word;
 
-		let title = item.name;
+		let title = self.name;
 
-		let cover = item.cover.replace(".328x422.jpg", "");
+		let cover = self.cover.replace(".328x422.jpg", "");
 
-		let authors = item.author.into_iter().map(|author| author.name).collect();
+		let authors = self.author.into_iter().map(|author| author.name).collect();
 
-		let status = match item.status {
+		let status = match self.status {
 			Some(0) => MangaStatus::Ongoing,
 			Some(1 | 2) => MangaStatus::Completed,
 			_ => MangaStatus::Unknown,
 		};
 
-		Self {
+		Manga {
 			key,
 			title,
 			cover: Some(cover),
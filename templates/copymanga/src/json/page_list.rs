@@ -17,11 +17,10 @@ impl TryFrom<Item> for Page {
 	fn try_from(page_item: Item) -> Result<Self> {
 		let quality = defaults_get_string("image.quality")?;
 		let format = defaults_get_string("image.format")?;
-		let url = page_item
-			.url
-			.rsplit_once('c')
-			.map(|(url, _)| format!("{url}{quality}.{format}"))
-			.unwrap_or(page_item.url);
+		let url = match strip_size(&page_item.url) {
+			Some(url) => format!("{url}.{quality}.{format}"),
+			None => page_item.url,
+		};
 		let content = PageContent::Url(url, None);
 		Ok(Self {
 			content,
@@ -30,6 +29,15 @@ impl TryFrom<Item> for Page {
 	}
 }
 
+/// Strips a trailing `.{size}.{ext}` suffix, where size looks like `c1500x` or
+/// `h800x`
+fn strip_size(url: &str) -> Option<&str> {
+	let (rest, _ext) = url.rsplit_once('.')?;
+	let (url, size) = rest.rsplit_once('.')?;
+	let digits = size.strip_prefix(char::is_alphabetic)?.strip_suffix('x')?;
+	(!digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())).then_some(url)
+}
+
 fn defaults_get_string(key: &str) -> Result<String> {
 	defaults_get(key).ok_or_else(|| error!("Default not exist or not string"))
 }
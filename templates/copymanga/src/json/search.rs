@@ -6,32 +6,32 @@ pub struct Root {
 	results: Results,
 }
 
-impl From<Root> for MangaPageResult {
-	fn from(root: Root) -> Self {
-		root.results.into()
-	}
-}
-
-#[derive(Deserialize)]
-struct Results {
-	list: Vec<MangaItem>,
-	total: u16,
-	limit: u16,
-	offset: u16,
-}
+impl Root {
+	pub fn into_manga_page_result(self, base_url: &str) -> MangaPageResult {
+		let results = self.results;
 
-impl From<Results> for MangaPageResult {
-	fn from(results: Results) -> Self {
-		let entries = results.list.into_iter().map(Into::into).collect();
+		let entries = results
+			.list
+			.into_iter()
+			.map(|item| item.into_manga(base_url))
+			.collect();
 
 		let has_next_page = results
 			.offset
 			.checked_add(results.limit)
 			.is_some_and(|current_total| current_total < results.total);
 
-		Self {
+		MangaPageResult {
 			entries,
 			has_next_page,
 		}
 	}
 }
+
+#[derive(Deserialize)]
+struct Results {
+	list: Vec<MangaItem>,
+	total: u16,
+	limit: u16,
+	offset: u16,
+}
@@ -0,0 +1,121 @@
+#![no_std]
+use aidoku::{
+	Chapter, DeepLinkHandler, DeepLinkResult, DynamicFilters, Filter, FilterValue, Manga,
+	MangaPageResult, Page, Result, Source,
+	alloc::{String, Vec, borrow::Cow},
+	error,
+	imports::defaults::defaults_get,
+};
+use zhconv::Script;
+
+mod html;
+mod imp;
+mod json;
+mod net;
+
+pub use imp::Impl;
+pub use net::Url;
+
+pub struct Params {
+	/// Site url; when empty, the `url` default (selected base url) is used
+	pub base_url: Cow<'static, str>,
+	pub user_agent: Cow<'static, str>,
+	/// Search API path; when empty, it is read from the search page
+	pub search_api: Cow<'static, str>,
+	/// Whether the search API accepts `q_type`, used for author search
+	pub search_by_type: bool,
+	/// Extra query parameters for every search request
+	pub search_params: &'static [(&'static str, &'static str)],
+	/// `dnts` header for the chapter list when the manga page has no `#dnt` input
+	pub default_dnts: Cow<'static, str>,
+	/// `dnts` header sent regardless of the manga page's `#dnt` input
+	pub fixed_dnts: Option<Cow<'static, str>>,
+	pub cover_selector: Cow<'static, str>,
+	pub authors_selector: Cow<'static, str>,
+	pub tags_selector: Cow<'static, str>,
+	pub status_selector: Cow<'static, str>,
+}
+
+impl Default for Params {
+	fn default() -> Self {
+		Self {
+			base_url: "".into(),
+			user_agent: "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) \
+			             AppleWebKit/605.1.15 (KHTML, like Gecko) Version/26.0.1 Safari/605.1.15"
+				.into(),
+			search_api: "".into(),
+			search_by_type: true,
+			search_params: &[],
+			default_dnts: "2".into(),
+			fixed_dnts: None,
+			cover_selector: "img[data-src]".into(),
+			authors_selector: "span.comicParticulars-right-txt > a".into(),
+			tags_selector: "span.comicParticulars-tag > a".into(),
+			status_selector: "li:contains(狀態：) > span.comicParticulars-right-txt".into(),
+		}
+	}
+}
+
+impl Params {
+	pub fn base_url(&self) -> Result<String> {
+		if !self.base_url.is_empty() {
+			return Ok(self.base_url.clone().into());
+		}
+		defaults_get::<String>("url")
+			.ok_or_else(|| error!("Default not exist or not string for key: `url`"))
+	}
+}
+
+pub struct CopyManga<T: Impl> {
+	inner: T,
+	params: Params,
+}
+
+impl<T: Impl> Source for CopyManga<T> {
+	fn new() -> Self {
+		let inner = T::new();
+		let params = inner.params();
+		Self { inner, params }
+	}
+
+	fn get_search_manga_list(
+		&self,
+		query: Option<String>,
+		page: i32,
+		mut filters: Vec<FilterValue>,
+	) -> Result<MangaPageResult> {
+		// titles and tags are indexed in simplified Chinese
+		let query = query.map(|query| zhconv::convert(&query, Script::Simplified));
+		zhconv::normalize_filters(&mut filters, Script::Simplified);
+		self.inner
+			.get_search_manga_list(&self.params, query, page, filters)
+			.map(zhconv::localize)
+	}
+
+	fn get_manga_update(
+		&self,
+		manga: Manga,
+		needs_details: bool,
+		needs_chapters: bool,
+	) -> Result<Manga> {
+		self.inner
+			.get_manga_update(&self.params, manga, needs_details, needs_chapters)
+			.map(zhconv::localize)
+	}
+
+	fn get_page_list(&self, manga: Manga, chapter: Chapter) -> Result<Vec<Page>> {
+		self.inner.get_page_list(&self.params, manga, chapter)
+	}
+}
+
+impl<T: Impl> DeepLinkHandler for CopyManga<T> {
+	fn handle_deep_link(&self, url: String) -> Result<Option<DeepLinkResult>> {
+		self.inner.handle_deep_link(&self.params, url)
+	}
+}
+
+impl<T: Impl> DynamicFilters for CopyManga<T> {
+	fn get_dynamic_filters(&self) -> Result<Vec<Filter>> {
+		self.inner.get_dynamic_filters(&self.params)
+	}
+}
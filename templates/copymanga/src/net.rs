@@ -1,10 +1,10 @@
-use crate::html::GenresPage as _;
+use crate::{Params, html::GenresPage as _};
 use aidoku::{
 	FilterValue, Result,
 	alloc::{String, format, string::ToString as _},
 	bail, error,
 	helpers::uri::QueryParameters,
-	imports::{defaults::defaults_get, net::Request},
+	imports::net::Request,
 };
 use core::fmt::{Display, Formatter, Result as FmtResult};
 use strum::{AsRefStr, Display, EnumIs, FromRepr};
@@ -28,30 +28,25 @@ pub enum Url<'a> {
 }
 
 impl Url<'_> {
-	pub fn to_string(&self) -> Result<String> {
-		let base_url = defaults_get::<String>("url")
-			.ok_or_else(|| error!("Default not exist or not string for key: `url`"))?;
-		Ok(format!("{base_url}{self}"))
+	pub fn with_base(&self, base_url: &str) -> String {
+		format!("{base_url}{self}")
 	}
 
-	pub fn request(&self) -> Result<Request> {
-		let url = self.to_string()?;
-		let request = Request::get(url)?.header(
-			"User-Agent",
-			"Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) \
-			 AppleWebKit/605.1.15 (KHTML, like Gecko) Version/26.0.1 Safari/605.1.15",
-		);
+	pub fn request(&self, params: &Params) -> Result<Request> {
+		let url = self.with_base(&params.base_url()?);
+		let request = Request::get(url)?.header("User-Agent", &params.user_agent);
 		Ok(request)
 	}
 
 	pub fn from_query_or_filters(
+		params: &Params,
 		query: Option<&str>,
 		page: i32,
 		filters: &[FilterValue],
 	) -> Result<Self> {
 		if let Some(keyword) = query {
-			let search_query = SearchQuery::new(page, keyword, SearchType::All);
-			let url = Self::search(search_query)?;
+			let search_query = SearchQuery::new(params, page, keyword, SearchType::All);
+			let url = Self::search(params, search_query)?;
 			return Ok(url);
 		}
 
@@ -66,8 +61,9 @@ impl Url<'_> {
 			match *filter {
 				FilterValue::Text { ref id, ref value } => match id.as_str() {
 					"author" => {
-						let search_query = SearchQuery::new(page, value, SearchType::Author);
-						let url = Self::search(search_query)?;
+						let search_query =
+							SearchQuery::new(params, page, value, SearchType::Author);
+						let url = Self::search(params, search_query)?;
 						return Ok(url);
 					}
 					_ => bail!("Invalid text filter ID: `{id}`"),
@@ -91,7 +87,7 @@ impl Url<'_> {
 					"狀態" => status = value,
 					"題材" => genre = value.into(),
 					"genre" => {
-						let genres = Self::GenresPage.request()?.html()?.filter()?;
+						let genres = Self::GenresPage.request(params)?.html()?.filter()?;
 						let genre_id = genres
 							.options
 							.iter()
@@ -111,9 +107,14 @@ impl Url<'_> {
 		Ok(Self::Filters(filters_query))
 	}
 
-	fn search(query: SearchQuery) -> Result<Self> {
+	fn search(params: &Params, query: SearchQuery) -> Result<Self> {
+		if !params.search_api.is_empty() {
+			let api = params.search_api.clone().into();
+			return Ok(Self::Search { api, query });
+		}
+
 		let api = Self::SearchPage
-			.request()?
+			.request(params)?
 			.string()?
 			.split_once(r#"const countApi = ""#)
 			.ok_or_else(|| error!(r#"String not found: `const countApi = "`"#))?
@@ -180,7 +181,7 @@ impl Display for FiltersQuery {
 pub struct SearchQuery(QueryParameters);
 
 impl SearchQuery {
-	fn new(page: i32, keyword: &str, r#type: SearchType) -> Self {
+	fn new(params: &Params, page: i32, keyword: &str, r#type: SearchType) -> Self {
 		let mut query = QueryParameters::new();
 
 		let limit = 12;
@@ -190,7 +191,13 @@ impl SearchQuery {
 		query.push_encoded("platform", Some("2"));
 		query.push_encoded("limit", Some(&limit.to_string()));
 		query.push("q", Some(keyword));
-		query.push_encoded("q_type", Some(r#type.as_ref()));
+		if params.search_by_type {
+			query.push_encoded("q_type", Some(r#type.as_ref()));
+		}
+
+		for (name, value) in params.search_params {
+			query.push_encoded(name, Some(value));
+		}
 
 		Self(query)
 	}
@@ -12,6 +12,8 @@ use aidoku::{
 static EMAIL_KEY: &str = "login.username";
 // static PASSWORD_KEY: &str = "login.password";
 static COOKIE_KEY: &str = "login.cookie";
+/// Per-episode purchase and rental state of the logged in account
+pub static ENTITLEMENT_CACHE_KEY: &str = "entitlements";
 
 pub fn login(base_url: &str, email: &str, password: &str) -> Result<bool> {
 	let url = format!("{base_url}/user_account/login");
@@ -41,6 +43,7 @@ pub fn login(base_url: &str, email: &str, password: &str) -> Result<bool> {
 
 pub fn logout() {
 	defaults_set(COOKIE_KEY, DefaultValue::Null);
+	defaults_set(ENTITLEMENT_CACHE_KEY, DefaultValue::Null);
 }
 
 pub fn is_logged_in() -> bool {
@@ -0,0 +1,197 @@
+use aidoku::{
+	HomeComponent, HomeComponentValue, HomeLayout, Link, LinkValue, Listing, ListingKind, Manga,
+	Result,
+	alloc::{String, Vec},
+	imports::{
+		html::{Document, ElementList},
+		net::Request,
+		std::current_date,
+	},
+	prelude::*,
+};
+
+use crate::{AuthedRequest, Params, parser::parse_card};
+
+/// Weekday names in the order GigaViewer lists them, starting on Monday
+const WEEKDAYS: [&str; 7] = ["月", "火", "水", "木", "金", "土", "日"];
+
+/// A list of series on the top page
+#[derive(Clone)]
+pub struct HomeSection {
+	pub title: &'static str,
+	pub subtitle: Option<&'static str>,
+	pub item_selector: &'static str,
+	pub title_selector: &'static str,
+	/// Shown as a numbered list instead of a scroller
+	pub ranking: bool,
+	/// Listing opened by the section's "see all" button
+	pub listing: Option<&'static str>,
+}
+
+pub fn get_home(params: &Params) -> Result<HomeLayout> {
+	let html = Request::get(params.base_url.as_ref())?.authed().html()?;
+
+	let mut components = Vec::new();
+
+	let banner = parse_banner(&html, params);
+	if !banner.is_empty() {
+		components.push(HomeComponent {
+			title: None,
+			subtitle: None,
+			value: HomeComponentValue::ImageScroller {
+				links: banner,
+				auto_scroll_interval: Some(5.0),
+				width: Some(params.home_banner_width),
+				height: Some(params.home_banner_height),
+			},
+		});
+	}
+
+	for section in params.home_sections.iter() {
+		let entries = parse_links(
+			html.select(section.item_selector),
+			params,
+			section.title_selector,
+		);
+		if entries.is_empty() {
+			continue;
+		}
+		let listing = section.listing.map(|id| Listing {
+			id: id.into(),
+			name: section.title.into(),
+			kind: ListingKind::List,
+		});
+		components.push(HomeComponent {
+			title: Some(section.title.into()),
+			subtitle: section.subtitle.map(String::from),
+			value: if section.ranking {
+				HomeComponentValue::MangaList {
+					ranking: true,
+					page_size: Some(3),
+					entries,
+					listing,
+				}
+			} else {
+				HomeComponentValue::Scroller { entries, listing }
+			},
+		});
+	}
+
+	if !params.home_daily_section_selector.is_empty() {
+		let daily_html = if params.home_daily_path.is_empty() {
+			None
+		} else {
+			Request::get(format!("{}/{}", params.base_url, params.home_daily_path))?
+				.authed()
+				.html()
+				.ok()
+		};
+		components.extend(parse_daily_sections(
+			daily_html.as_ref().unwrap_or(&html),
+			params,
+		));
+	}
+
+	Ok(HomeLayout { components })
+}
+
+fn parse_banner(html: &Document, params: &Params) -> Vec<Link> {
+	if params.home_banner_selector.is_empty() {
+		return Vec::new();
+	}
+	let episode_prefix = format!("{}/episode", params.base_url);
+	html.select(params.home_banner_selector.as_ref())
+		.map(|links| {
+			links
+				.filter_map(|element| {
+					let image = element.select("img")?.next_back()?.attr("src")?;
+					let url = element.attr("href")?;
+					let value = if url.starts_with(&episode_prefix) {
+						LinkValue::Manga(Manga {
+							key: url.strip_prefix(params.base_url.as_ref())?.into(),
+							..Default::default()
+						})
+					} else {
+						LinkValue::Url(url)
+					};
+					Some(Link {
+						title: String::default(),
+						image_url: Some(image),
+						value: Some(value),
+						..Default::default()
+					})
+				})
+				.collect()
+		})
+		.unwrap_or_default()
+}
+
+/// Series updated on each weekday, starting from today
+fn parse_daily_sections(html: &Document, params: &Params) -> Vec<HomeComponent> {
+	let Some(sections) = html.select(params.home_daily_section_selector.as_ref()) else {
+		return Vec::new();
+	};
+
+	let mut components = sections
+		.filter_map(|section| {
+			let title = section
+				.select_first(params.home_daily_title_selector.as_ref())
+				.and_then(|e| e.text())?;
+			let entries = parse_links(
+				section.select(params.home_daily_item_selector.as_ref()),
+				params,
+				&params.home_item_title_selector,
+			);
+			if entries.is_empty() {
+				return None;
+			}
+			Some(HomeComponent {
+				title: Some(title),
+				subtitle: None,
+				value: HomeComponentValue::Scroller {
+					entries,
+					listing: None,
+				},
+			})
+		})
+		.collect::<Vec<_>>();
+
+	// updates are published on Japan time
+	let today = WEEKDAYS[weekday(current_date() + 9 * 60 * 60)];
+	if let Some(index) = components.iter().position(|component| {
+		component
+			.title
+			.as_ref()
+			.is_some_and(|title| title.starts_with(today))
+	}) {
+		components.rotate_left(index);
+		components[0].subtitle = Some("今日更新".into());
+	}
+
+	components
+}
+
+/// Index into [`WEEKDAYS`] for a unix timestamp
+fn weekday(timestamp: i64) -> usize {
+	// 1970-01-01 was a Thursday
+	((timestamp.div_euclid(24 * 60 * 60) + 3) % 7) as usize
+}
+
+fn parse_links(items: Option<ElementList>, params: &Params, title_selector: &str) -> Vec<Link> {
+	items
+		.map(|items| {
+			items
+				.filter_map(|element| {
+					parse_card(
+						&element,
+						&params.base_url,
+						title_selector,
+						"img",
+						"data-src",
+					)
+					.map(Into::into)
+				})
+				.collect()
+		})
+		.unwrap_or_default()
+}
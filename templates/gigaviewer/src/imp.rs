@@ -1,4 +1,4 @@
-use super::{AuthedRequest, Params, auth, models::*, parser};
+use super::{AuthedRequest, Params, auth, home, models::*, parser};
 use aidoku::{
 	Chapter, DeepLinkResult, FilterValue, HomeLayout, ImageResponse, Listing, Manga,
 	MangaPageResult, Page, PageContent, PageContext, Result, Viewer,
@@ -6,6 +6,7 @@ use aidoku::{
 	helpers::uri::QueryParameters,
 	imports::{
 		canvas::{Canvas, ImageRef, Rect},
+		defaults::defaults_get,
 		error::AidokuError,
 		net::Request,
 		std::send_partial_result,
@@ -118,7 +119,7 @@ pub trait Impl {
 		}
 
 		if needs_chapters {
-			let chapters = if params.is_paginated {
+			let mut chapters = if params.is_paginated {
 				parser::parse_chapters_paginated(&html, &params.base_url, &url)?
 			} else {
 				parser::parse_chapters_single_page(
@@ -129,6 +130,10 @@ pub trait Impl {
 					&params.chapter_list_selector,
 				)?
 			};
+			parser::update_entitlements(&mut chapters);
+			if !defaults_get::<bool>("showLocked").unwrap_or(true) {
+				chapters.retain(|chapter| !chapter.locked);
+			}
 			new_manga.chapters = Some(chapters);
 		}
 
@@ -151,6 +156,10 @@ pub trait Impl {
 			.and_then(|e| e.attr("data-value"))
 			.ok_or(AidokuError::message("このチャプターは非公開です"))
 			.and_then(|v| Ok(serde_json::from_str::<GigaEpisode>(v.as_ref())?))?;
+		parser::remember_entitlement(
+			&chapter.key,
+			!episode.readable_product.page_structure.pages.is_empty(),
+		);
 
 		Ok(episode
 			.readable_product
@@ -230,8 +239,8 @@ pub trait Impl {
 		Ok(canvas.get_image())
 	}
 
-	fn get_home(&self, _params: &Params) -> Result<HomeLayout> {
-		Err(AidokuError::Unimplemented)
+	fn get_home(&self, params: &Params) -> Result<HomeLayout> {
+		home::get_home(params)
 	}
 
 	fn handle_basic_login(
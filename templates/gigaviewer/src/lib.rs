@@ -8,11 +8,13 @@ use aidoku::{
 };
 
 mod auth;
+mod home;
 mod imp;
 mod models;
 pub mod parser;
 
 pub use auth::AuthedRequest;
+pub use home::HomeSection;
 pub use imp::Impl;
 
 pub struct Params {
@@ -23,6 +25,16 @@ pub struct Params {
 	pub chapter_list_selector: Cow<'static, str>,
 	pub search_item_selector: Cow<'static, str>,
 	pub search_item_title_selector: Cow<'static, str>,
+	pub home_banner_selector: Cow<'static, str>,
+	pub home_banner_width: i32,
+	pub home_banner_height: i32,
+	pub home_sections: Cow<'static, [HomeSection]>,
+	/// Page listing series by weekday, relative to the base url; empty to use the top page
+	pub home_daily_path: Cow<'static, str>,
+	pub home_daily_section_selector: Cow<'static, str>,
+	pub home_daily_title_selector: Cow<'static, str>,
+	pub home_daily_item_selector: Cow<'static, str>,
+	pub home_item_title_selector: Cow<'static, str>,
 }
 
 impl Default for Params {
@@ -35,6 +47,32 @@ impl Default for Params {
 			chapter_list_selector: "li.episode".into(),
 			search_item_selector: "ul.search-series-list li, ul.series-list li".into(),
 			search_item_title_selector: "div.title-box p.series-title".into(),
+			home_banner_selector: ".swiper-slide:not(.swiper-slide-duplicate) a".into(),
+			home_banner_width: 340,
+			home_banner_height: 170,
+			home_sections: Cow::Borrowed(&[
+				HomeSection {
+					title: "ランキング",
+					subtitle: None,
+					item_selector: ".ranking-item, .series-ranking-item",
+					title_selector: ".ranking-title, h3, h4",
+					ranking: true,
+					listing: None,
+				},
+				HomeSection {
+					title: "新作読切",
+					subtitle: None,
+					item_selector: ".yomikiri-item-box",
+					title_selector: ".yomikiri-link-title h4",
+					ranking: false,
+					listing: None,
+				},
+			]),
+			home_daily_path: "series".into(),
+			home_daily_section_selector: ".daily".into(),
+			home_daily_title_selector: ".daily-header, h2, h3".into(),
+			home_daily_item_selector: ".daily-series-item".into(),
+			home_item_title_selector: ".daily-series-title, .series-title, h2, h3, h4".into(),
 		}
 	}
 }
@@ -30,6 +30,46 @@ pub struct GigaPage {
 	pub height: Option<i32>,
 }
 
+/// Episode JSON fetched only to check whether the user may read it
+#[derive(Default, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct GigaEpisodeAccess {
+	pub readable_product: GigaReadableProductAccess,
+}
+
+#[derive(Default, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct GigaReadableProductAccess {
+	pub has_purchased: bool,
+	pub purchase_info: Option<GigaPurchaseInfo>,
+	// only present when the pages can be viewed
+	pub page_structure: Option<GigaPageStructure>,
+}
+
+#[derive(Default, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct GigaPurchaseInfo {
+	pub is_free: bool,
+	pub has_purchased: bool,
+	pub has_rental_rights: bool,
+	pub rental_expire_at: Option<String>,
+	pub free_term_end_at: Option<String>,
+}
+
+impl GigaReadableProductAccess {
+	pub fn is_readable(&self) -> bool {
+		self.has_purchased
+			|| self
+				.page_structure
+				.as_ref()
+				.is_some_and(|structure| !structure.pages.is_empty())
+			|| self
+				.purchase_info
+				.as_ref()
+				.is_some_and(|info| info.is_free || info.has_purchased || info.has_rental_rights)
+	}
+}
+
 #[derive(Default, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase", default)]
 pub struct GigaReadMoreResponse {
@@ -49,7 +89,7 @@ pub struct GigaPaginationReadableProduct {
 
 #[derive(Default, Deserialize, Debug, Clone)]
 pub struct GigaPaginationReadableProductStatus {
-	pub label: Option<String>, // is_free, is_rentable, is_purchasable, unpublished, has_rented
+	pub label: Option<String>, // is_free, is_rentable, is_purchasable, unpublished, has_rented, has_purchased
 }
 
 impl From<GigaPaginationReadableProduct> for Chapter {
@@ -70,7 +110,9 @@ impl From<GigaPaginationReadableProduct> for Chapter {
 			locked: val
 				.status
 				.and_then(|status| status.label)
-				.map(|label| label != "is_free" && label != "has_rented")
+				.map(|label| {
+					label != "is_free" && label != "has_rented" && label != "has_purchased"
+				})
 				.unwrap_or_default(),
 			..Default::default()
 		}
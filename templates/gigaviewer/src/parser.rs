@@ -1,21 +1,38 @@
 use aidoku::{
-	alloc::{string::ToString, String, Vec},
+	alloc::{collections::BTreeMap, string::ToString, String, Vec},
 	helpers::uri::QueryParameters,
 	imports::{
-		defaults::defaults_get,
-		html::{Document, Html},
+		defaults::{defaults_get, defaults_set, DefaultValue},
+		html::{Document, Element, Html},
 		net::Request,
-		std::parse_local_date,
+		std::{current_date, parse_local_date},
 	},
 	prelude::*,
 	Chapter, Manga, Result,
 };
+use chrono::{DateTime, FixedOffset};
 
 use crate::{
-	models::{GigaPaginationReadableProduct, GigaReadMoreResponse},
+	auth,
+	models::{
+		GigaEpisodeAccess, GigaPaginationReadableProduct, GigaReadMoreResponse,
+		GigaReadableProductAccess,
+	},
 	AuthedRequest,
 };
 
+/// Number of newest episodes checked for a limited free period besides the locked ones
+const RECENT_EPISODE_CHECKS: usize = 30;
+const ENTITLEMENT_BATCH_SIZE: usize = 20;
+/// Uncached episodes checked per update; the rest are picked up by later updates
+const MAX_ENTITLEMENT_CHECKS: usize = 40;
+/// How long a locked result is trusted before the episode is checked again
+const UNREADABLE_RECHECK_SECONDS: i64 = 24 * 60 * 60;
+/// How long a purchase is trusted, so refunded or deleted episodes eventually drop out
+const PURCHASED_RECHECK_SECONDS: i64 = 30 * 24 * 60 * 60;
+/// Most episodes kept in the cache, across all series
+const MAX_CACHED_ENTITLEMENTS: usize = 1000;
+
 #[allow(clippy::too_many_arguments)]
 pub fn parse_response(
 	html: &Document,
@@ -62,6 +79,38 @@ pub fn parse_response(
 		.unwrap_or_default()
 }
 
+/// Parses a series card on the home page.
+pub fn parse_card(
+	element: &Element,
+	base_url: &str,
+	title_selector: &str,
+	cover_selector: &str,
+	cover_attr: &str,
+) -> Option<Manga> {
+	// also matches the item itself when it is a link
+	let link = element.select_first("a")?;
+	let key = link
+		.attr("href")?
+		.strip_prefix(base_url)
+		.map(String::from)?;
+	let title = element
+		.select_first(title_selector)
+		.and_then(|x| x.text())
+		.or_else(|| link.attr("data-series-name"))?;
+	let cover = element.select_first(cover_selector).and_then(|img| {
+		img.attr(cover_attr)
+			.filter(|src| !src.is_empty())
+			.or_else(|| img.attr("src"))
+	});
+
+	Some(Manga {
+		key,
+		title,
+		cover,
+		..Default::default()
+	})
+}
+
 pub fn parse_chapters_single_page(
 	html: &Document,
 	base_url: &str,
@@ -161,7 +210,6 @@ pub fn parse_chapter_elements(
 	chapter_list_selector: &str,
 ) -> Option<Vec<Chapter>> {
 	let document = Html::parse(html).ok()?;
-	let skip_locked = !defaults_get::<bool>("showLocked").unwrap_or(true);
 	document
 		.select(format!("ul.series-episode-list {chapter_list_selector}"))
 		.map(|episodes| {
@@ -173,10 +221,6 @@ pub fn parse_chapter_elements(
 
 					let locked = e.select_first(".series-episode-list-price").is_some();
 
-					if skip_locked && locked {
-						return None;
-					}
-
 					let info = e
 						.select_first("a.series-episode-list-container")
 						.unwrap_or(e);
@@ -221,6 +265,227 @@ pub fn parse_chapter_elements(
 		})
 }
 
+/// Update `locked` with the logged in user's purchases and rentals.
+///
+/// The episode list only shows the price of paid episodes, so the episode JSON
+/// is fetched for locked episodes and the newest ones, which may be temporarily free.
+/// Results are cached per episode so long series don't refetch every episode on
+/// each update, and uncached episodes are checked a few at a time.
+pub fn update_entitlements(chapters: &mut [Chapter]) {
+	if !auth::is_logged_in() {
+		return;
+	}
+
+	let now = current_date();
+	let mut cache = EntitlementCache::load();
+
+	let mut uncached = Vec::new();
+	for (index, chapter) in chapters.iter_mut().enumerate() {
+		if !(chapter.locked || index < RECENT_EPISODE_CHECKS) {
+			continue;
+		}
+		match cache.get(&chapter.key, now) {
+			Some(entry) => entry.apply(chapter),
+			None if chapter.url.is_some() => uncached.push(index),
+			None => {}
+		}
+	}
+	uncached.truncate(MAX_ENTITLEMENT_CHECKS);
+
+	for batch in uncached.chunks(ENTITLEMENT_BATCH_SIZE) {
+		// a url that fails to build only skips its own chapter
+		let (indices, requests): (Vec<usize>, Vec<Request>) = batch
+			.iter()
+			.filter_map(|&index| {
+				let url = chapters[index].url.as_ref()?;
+				Some((index, Request::get(format!("{url}.json")).ok()?.authed()))
+			})
+			.unzip();
+
+		for (index, response) in indices.into_iter().zip(Request::send_all(requests)) {
+			let Some(access) = response
+				.ok()
+				.and_then(|response| response.get_json_owned::<GigaEpisodeAccess>().ok())
+				.map(|episode| episode.readable_product)
+			else {
+				continue;
+			};
+
+			let entry = Entitlement::from_access(&access, now);
+			entry.apply(&mut chapters[index]);
+			cache.insert(chapters[index].key.clone(), entry);
+		}
+	}
+
+	cache.save();
+}
+
+/// Records whether an opened episode was readable, keeping the cache current
+/// without an extra request.
+pub fn remember_entitlement(key: &str, readable: bool) {
+	if !auth::is_logged_in() {
+		return;
+	}
+	let now = current_date();
+	let mut cache = EntitlementCache::load();
+	if cache
+		.get(key, now)
+		.is_some_and(|entry| entry.readable == readable)
+	{
+		return;
+	}
+	cache.insert(
+		key.into(),
+		Entitlement {
+			readable,
+			note: None,
+			valid_until: now + UNREADABLE_RECHECK_SECONDS,
+		},
+	);
+	cache.save();
+}
+
+/// Cached access to a single episode.
+struct Entitlement {
+	readable: bool,
+	/// Rental or free period note appended to the title
+	note: Option<String>,
+	valid_until: i64,
+}
+
+impl Entitlement {
+	fn from_access(access: &GigaReadableProductAccess, now: i64) -> Self {
+		let readable = access.is_readable();
+		let info = access.purchase_info.as_ref();
+		let (note, valid_until) = match info {
+			Some(info) if info.has_rental_rights && !info.has_purchased => {
+				let end = info.rental_expire_at.as_deref();
+				(
+					end.and_then(format_date)
+						.map(|date| format!("レンタル {date}まで")),
+					end.and_then(parse_iso_date),
+				)
+			}
+			Some(info) if info.is_free => {
+				let end = info.free_term_end_at.as_deref();
+				(
+					end.and_then(format_date)
+						.map(|date| format!("{date}まで無料")),
+					end.and_then(parse_iso_date),
+				)
+			}
+			_ => (None, None),
+		};
+		let purchased = access.has_purchased || info.is_some_and(|info| info.has_purchased);
+		let valid_until = valid_until.unwrap_or(if readable && purchased {
+			now + PURCHASED_RECHECK_SECONDS
+		} else {
+			now + UNREADABLE_RECHECK_SECONDS
+		});
+		Self {
+			readable,
+			note,
+			valid_until,
+		}
+	}
+
+	fn apply(&self, chapter: &mut Chapter) {
+		chapter.locked = !self.readable;
+		if let Some(note) = &self.note {
+			chapter.title = Some(match chapter.title.take() {
+				Some(title) => format!("{title}（{note}）"),
+				None => note.clone(),
+			});
+		}
+	}
+}
+
+/// Episode entitlements stored in defaults as `key\treadable\tvalid_until\tnote` lines.
+struct EntitlementCache(BTreeMap<String, Entitlement>);
+
+impl EntitlementCache {
+	fn load() -> Self {
+		Self::from_lines(
+			defaults_get::<Vec<String>>(auth::ENTITLEMENT_CACHE_KEY).unwrap_or_default(),
+		)
+	}
+
+	fn from_lines(lines: Vec<String>) -> Self {
+		let entries = lines
+			.into_iter()
+			.filter_map(|line| {
+				let mut parts = line.splitn(4, '\t');
+				let key = parts.next()?.into();
+				let readable = parts.next()? == "1";
+				let valid_until = parts.next()?.parse().ok()?;
+				let note = parts.next().filter(|s| !s.is_empty()).map(String::from);
+				Some((
+					key,
+					Entitlement {
+						readable,
+						note,
+						valid_until,
+					},
+				))
+			})
+			.collect();
+		Self(entries)
+	}
+
+	fn get(&self, key: &str, now: i64) -> Option<&Entitlement> {
+		self.0.get(key).filter(|entry| entry.valid_until > now)
+	}
+
+	fn insert(&mut self, key: String, entry: Entitlement) {
+		self.0.insert(key, entry);
+	}
+
+	fn save(self) {
+		defaults_set(
+			auth::ENTITLEMENT_CACHE_KEY,
+			DefaultValue::StringArray(self.into_lines(current_date())),
+		);
+	}
+
+	/// Serializes the entries still valid at `now`, dropping the ones that expire
+	/// soonest when there are more than [`MAX_CACHED_ENTITLEMENTS`].
+	fn into_lines(self, now: i64) -> Vec<String> {
+		let mut entries = self
+			.0
+			.into_iter()
+			.filter(|(_, entry)| entry.valid_until > now)
+			.collect::<Vec<_>>();
+		if entries.len() > MAX_CACHED_ENTITLEMENTS {
+			entries.sort_by_key(|(_, entry)| core::cmp::Reverse(entry.valid_until));
+			entries.truncate(MAX_CACHED_ENTITLEMENTS);
+		}
+		entries
+			.into_iter()
+			.map(|(key, entry)| {
+				format!(
+					"{key}\t{}\t{}\t{}",
+					if entry.readable { "1" } else { "0" },
+					entry.valid_until,
+					entry.note.unwrap_or_default()
+				)
+			})
+			.collect()
+	}
+}
+
+fn parse_iso_date(date: &str) -> Option<i64> {
+	DateTime::parse_from_rfc3339(date)
+		.ok()
+		.map(|date| date.timestamp())
+}
+
+/// Format an ISO 8601 date as month/day and time in Japan time
+fn format_date(date: &str) -> Option<String> {
+	let jst = FixedOffset::east_opt(9 * 60 * 60)?;
+	let date = DateTime::parse_from_rfc3339(date).ok()?.with_timezone(&jst);
+	Some(date.format("%-m/%-d %H:%M").to_string())
+}
+
 // Parse chapter number from title string containing japanese characters
 pub fn parse_chapter_number(title_str: String) -> Option<f32> {
 	let mut digits = String::new();
@@ -302,3 +567,6 @@ pub fn parse_chapter_number(title_str: String) -> Option<f32> {
 		num
 	}
 }
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,108 @@
+use super::*;
+use aidoku::{alloc::vec, prelude::*};
+use aidoku_test::aidoku_test;
+
+const NOW: i64 = 1_700_000_000;
+
+fn access(json: &str) -> GigaReadableProductAccess {
+	serde_json::from_str(json).unwrap()
+}
+
+#[aidoku_test]
+fn format_dates_in_japan_time() {
+	assert_eq!(
+		format_date("2024-03-31T15:30:00Z").as_deref(),
+		Some("4/1 00:30")
+	);
+	assert_eq!(
+		format_date("2024-12-05T09:05:00+09:00").as_deref(),
+		Some("12/5 09:05")
+	);
+	assert_eq!(format_date("tomorrow"), None);
+}
+
+#[aidoku_test]
+fn rental_expires_with_the_rental() {
+	let entry = Entitlement::from_access(
+		&access(
+			r#"{"purchaseInfo":{"hasRentalRights":true,"rentalExpireAt":"2024-03-31T15:30:00Z"}}"#,
+		),
+		NOW,
+	);
+	assert!(entry.readable);
+	assert_eq!(entry.note.as_deref(), Some("レンタル 4/1 00:30まで"));
+	assert_eq!(
+		entry.valid_until,
+		parse_iso_date("2024-03-31T15:30:00Z").unwrap()
+	);
+}
+
+#[aidoku_test]
+fn free_period_is_noted() {
+	let entry = Entitlement::from_access(
+		&access(r#"{"purchaseInfo":{"isFree":true,"freeTermEndAt":"2024-12-05T09:05:00+09:00"}}"#),
+		NOW,
+	);
+	assert!(entry.readable);
+	assert_eq!(entry.note.as_deref(), Some("12/5 09:05まで無料"));
+}
+
+#[aidoku_test]
+fn purchases_are_rechecked_eventually() {
+	let entry = Entitlement::from_access(&access(r#"{"hasPurchased":true}"#), NOW);
+	assert!(entry.readable);
+	assert_eq!(entry.note, None);
+	assert_eq!(entry.valid_until, NOW + PURCHASED_RECHECK_SECONDS);
+
+	let entry = Entitlement::from_access(&access(r#"{"purchaseInfo":{}}"#), NOW);
+	assert!(!entry.readable);
+	assert_eq!(entry.valid_until, NOW + UNREADABLE_RECHECK_SECONDS);
+}
+
+#[aidoku_test]
+fn cache_round_trips_and_drops_expired() {
+	let cache = EntitlementCache::from_lines(vec![
+		format!("/episode/1\t1\t{}\tレンタル 4/1 00:30まで", NOW + 10),
+		format!("/episode/2\t0\t{}\t", NOW - 10),
+		"broken".into(),
+	]);
+	assert!(
+		cache
+			.get("/episode/1", NOW)
+			.is_some_and(|entry| entry.readable)
+	);
+	assert!(cache.get("/episode/2", NOW).is_none());
+
+	assert_eq!(
+		cache.into_lines(NOW),
+		vec![format!(
+			"/episode/1\t1\t{}\tレンタル 4/1 00:30まで",
+			NOW + 10
+		)]
+	);
+}
+
+#[aidoku_test]
+fn cache_is_capped() {
+	let mut cache = EntitlementCache::from_lines(Vec::new());
+	for i in 0..MAX_CACHED_ENTITLEMENTS + 5 {
+		cache.insert(
+			format!("/episode/{i}"),
+			Entitlement {
+				readable: true,
+				note: None,
+				valid_until: NOW + i as i64,
+			},
+		);
+	}
+
+	let lines = cache.into_lines(NOW);
+	assert_eq!(lines.len(), MAX_CACHED_ENTITLEMENTS);
+	// the entries that expire soonest are dropped first
+	assert!(!lines.iter().any(|line| line.starts_with("/episode/1\t")));
+	assert!(
+		lines
+			.iter()
+			.any(|line| line.starts_with(&format!("/episode/{}\t", MAX_CACHED_ENTITLEMENTS + 4)))
+	);
+}
@@ -0,0 +1,94 @@
+use aidoku::alloc::{String, Vec, collections::btree_map::BTreeMap, string::ToString};
+use core::cmp::Reverse;
+
+use crate::models::chapter::{LibGroupChapterBranch, LibGroupChapterListItem};
+
+/// How chapters translated by several teams are presented
+#[derive(Debug, Clone, PartialEq)]
+pub enum BranchStrategy {
+	/// Show every branch of every chapter
+	All,
+	/// Prefer the given team, falling back to the most complete branch
+	PreferredTeam(String),
+	/// Show one branch per chapter, preferring the branch covering the most chapters
+	MostComplete,
+}
+
+#[derive(Default)]
+struct BranchStats {
+	first_seen: usize,
+	total: usize,
+	open: usize,
+}
+
+/// Branches without a `branch_id` are told apart by their teams
+fn branch_key(branch: &LibGroupChapterBranch) -> String {
+	match branch.branch_id {
+		Some(id) => id.to_string(),
+		None => branch.scanlators().join(", "),
+	}
+}
+
+/// Reduce every chapter to a single branch according to `strategy`.
+///
+/// For each chapter an unlocked branch always wins over a locked one; among those,
+/// the preferred team comes first, then the branch with the most readable chapters.
+pub fn select_branches(
+	items: Vec<LibGroupChapterListItem>,
+	strategy: &BranchStrategy,
+) -> Vec<LibGroupChapterListItem> {
+	let preferred_team = match strategy {
+		BranchStrategy::All => return items,
+		BranchStrategy::PreferredTeam(team) => {
+			Some(team.trim().to_lowercase()).filter(|team| !team.is_empty())
+		}
+		BranchStrategy::MostComplete => None,
+	};
+
+	let mut stats: BTreeMap<String, BranchStats> = BTreeMap::new();
+	for branch in items.iter().flat_map(|item| &item.branches) {
+		let next = stats.len();
+		let entry = stats.entry(branch_key(branch)).or_insert(BranchStats {
+			first_seen: next,
+			..Default::default()
+		});
+		entry.total += 1;
+		if !branch.is_locked() {
+			entry.open += 1;
+		}
+	}
+
+	let is_preferred = |branch: &LibGroupChapterBranch| {
+		preferred_team.as_ref().is_some_and(|team| {
+			branch
+				.scanlators()
+				.iter()
+				.any(|name| name.trim().to_lowercase() == *team)
+		})
+	};
+
+	let rank = |branch: &LibGroupChapterBranch| {
+		let entry = stats.get(&branch_key(branch));
+		(
+			!branch.is_locked(),
+			is_preferred(branch),
+			entry.map(|s| s.open).unwrap_or_default(),
+			entry.map(|s| s.total).unwrap_or_default(),
+			Reverse(entry.map(|s| s.first_seen).unwrap_or(usize::MAX)),
+		)
+	};
+
+	items
+		.into_iter()
+		.map(|mut item| {
+			if let Some(best) = (0..item.branches.len())
+				.rev()
+				.max_by_key(|&i| rank(&item.branches[i]))
+			{
+				let branch = item.branches.swap_remove(best);
+				item.branches = Vec::from([branch]);
+			}
+			item
+		})
+		.collect()
+}
@@ -5,6 +5,9 @@ use aidoku::{
 };
 use spin::{Once, RwLock};
 
+mod branches;
+pub use branches::{BranchStrategy, select_branches};
+
 use crate::{
 	auth::AuthRequest,
 	context::Context,
@@ -1,7 +1,8 @@
-use crate::chapters::{ChaptersCache, TimedVec};
+use crate::chapters::{BranchStrategy, ChaptersCache, TimedVec, select_branches};
 use crate::context::Context;
-use crate::models::chapter::LibGroupChapterListItem;
-use aidoku::alloc::{string::ToString, vec};
+use crate::models::chapter::{LibGroupChapterBranch, LibGroupChapterListItem};
+use crate::models::common::{LibGroupRestrictedView, LibGroupTeam};
+use aidoku::alloc::{Vec, string::ToString, vec};
 use aidoku_test::aidoku_test;
 
 fn test_context() -> Context {
@@ -95,3 +96,132 @@ fn clear_removes_all_entries() {
 	cache.clear();
 	assert!(cache.cache.read().is_empty());
 }
+
+fn make_branch(id: i32, branch_id: Option<i32>, team: &str, locked: bool) -> LibGroupChapterBranch {
+	LibGroupChapterBranch {
+		id,
+		branch_id,
+		teams: vec![LibGroupTeam {
+			name: team.to_string(),
+		}],
+		restricted_view: Some(LibGroupRestrictedView { is_open: !locked }),
+		..Default::default()
+	}
+}
+
+fn make_branched_item(
+	number: &str,
+	branches: Vec<LibGroupChapterBranch>,
+) -> LibGroupChapterListItem {
+	LibGroupChapterListItem {
+		branches,
+		..make_item(number)
+	}
+}
+
+fn selected_ids(items: &[LibGroupChapterListItem]) -> Vec<i32> {
+	items
+		.iter()
+		.flat_map(|item| item.branches.iter().map(|b| b.id))
+		.collect()
+}
+
+/// Team A translated chapters 1-3, team B only chapters 2 and 4
+fn branched_items() -> Vec<LibGroupChapterListItem> {
+	vec![
+		make_branched_item("1", vec![make_branch(11, Some(1), "Team A", false)]),
+		make_branched_item(
+			"2",
+			vec![
+				make_branch(22, Some(2), "Team B", false),
+				make_branch(21, Some(1), "Team A", false),
+			],
+		),
+		make_branched_item("3", vec![make_branch(31, Some(1), "Team A", false)]),
+		make_branched_item("4", vec![make_branch(42, Some(2), "Team B", false)]),
+	]
+}
+
+#[aidoku_test]
+fn all_strategy_keeps_every_branch() {
+	let items = select_branches(branched_items(), &BranchStrategy::All);
+	assert_eq!(selected_ids(&items), vec![11, 22, 21, 31, 42]);
+}
+
+#[aidoku_test]
+fn most_complete_prefers_largest_branch() {
+	let items = select_branches(branched_items(), &BranchStrategy::MostComplete);
+	assert_eq!(selected_ids(&items), vec![11, 21, 31, 42]);
+}
+
+#[aidoku_test]
+fn preferred_team_falls_back_per_chapter() {
+	let strategy = BranchStrategy::PreferredTeam(" team b ".to_string());
+	let items = select_branches(branched_items(), &strategy);
+	assert_eq!(selected_ids(&items), vec![11, 22, 31, 42]);
+}
+
+#[aidoku_test]
+fn empty_preferred_team_acts_as_most_complete() {
+	let strategy = BranchStrategy::PreferredTeam("".to_string());
+	let items = select_branches(branched_items(), &strategy);
+	assert_eq!(selected_ids(&items), vec![11, 21, 31, 42]);
+}
+
+#[aidoku_test]
+fn unlocked_branch_wins_over_locked() {
+	let mut items = branched_items();
+	items[1].branches[1]
+		.restricted_view
+		.as_mut()
+		.unwrap()
+		.is_open = false;
+
+	let items = select_branches(items, &BranchStrategy::MostComplete);
+	assert_eq!(selected_ids(&items), vec![11, 22, 31, 42]);
+
+	let strategy = BranchStrategy::PreferredTeam("Team A".to_string());
+	let items = select_branches(
+		vec![make_branched_item(
+			"1",
+			vec![
+				make_branch(11, Some(1), "Team A", true),
+				make_branch(12, Some(2), "Team B", false),
+			],
+		)],
+		&strategy,
+	);
+	assert_eq!(selected_ids(&items), vec![12]);
+}
+
+#[aidoku_test]
+fn locked_branch_kept_when_nothing_is_open() {
+	let items = select_branches(
+		vec![make_branched_item(
+			"1",
+			vec![
+				make_branch(12, Some(2), "Team B", true),
+				make_branch(11, Some(1), "Team A", true),
+			],
+		)],
+		&BranchStrategy::PreferredTeam("Team A".to_string()),
+	);
+	assert_eq!(selected_ids(&items), vec![11]);
+}
+
+#[aidoku_test]
+fn branches_without_id_are_grouped_by_team() {
+	let items = vec![
+		make_branched_item(
+			"1",
+			vec![
+				make_branch(12, None, "Team B", false),
+				make_branch(11, None, "Team A", false),
+			],
+		),
+		make_branched_item("2", vec![make_branch(21, None, "Team A", false)]),
+	];
+
+	let items = select_branches(items, &BranchStrategy::MostComplete);
+	assert_eq!(selected_ids(&items), vec![11, 21]);
+}
@@ -0,0 +1,230 @@
+use aidoku::{
+	Page, PageContent,
+	alloc::{String, Vec, string::ToString},
+	prelude::*,
+};
+use core::mem;
+
+use crate::context::Context;
+
+/// Longest text page (in bytes) before a chapter is split at a paragraph break
+pub const MAX_PAGE_LENGTH: usize = 16 * 1024;
+
+/// Title of spoiler blocks that don't name themselves
+pub const DEFAULT_SPOILER_TITLE: &str = "Спойлер";
+
+/// A part of a converted chapter
+#[derive(Debug, PartialEq)]
+pub enum ChapterSection {
+	Text(String),
+	Image(String),
+}
+
+/// Markdown output that keeps images and footnotes apart from the running text
+#[derive(Default)]
+pub struct ChapterDocument {
+	sections: Vec<ChapterSection>,
+	text: String,
+	footnotes: Vec<String>,
+	quotes: Vec<OpenQuote>,
+}
+
+/// A blockquote that is still being rendered
+struct OpenQuote {
+	/// Offset in `text` where the quoted text starts
+	start: usize,
+	/// Title that hasn't been written yet
+	title: Option<String>,
+}
+
+impl ChapterDocument {
+	pub fn push_str(&mut self, string: &str) {
+		self.text.push_str(string);
+	}
+
+	pub fn push(&mut self, ch: char) {
+		self.text.push(ch);
+	}
+
+	pub fn ends_with(&self, pattern: &str) -> bool {
+		self.text.ends_with(pattern)
+	}
+
+	/// Insert an image at the current position
+	pub fn push_image(&mut self, url: String) {
+		// quote the text before the image, the quotes carry on after it
+		let mut quotes = mem::take(&mut self.quotes);
+		for quote in quotes.iter_mut().rev() {
+			let title = quote.title.take();
+			self.quote_from(quote.start, title.as_deref());
+		}
+		self.flush_text();
+		for quote in &mut quotes {
+			quote.start = 0;
+		}
+		self.quotes = quotes;
+
+		self.sections.push(ChapterSection::Image(url));
+	}
+
+	/// Insert a footnote reference; the note itself is added to the page referencing it
+	pub fn push_footnote(&mut self, note: &str) {
+		let note = note.trim();
+		if note.is_empty() {
+			return;
+		}
+		self.footnotes.push(note.to_string());
+		self.text.push_str(&format!("[^{}]", self.footnotes.len()));
+	}
+
+	/// Render the output of `render` as a blockquote, with an optional bold title
+	pub fn push_quoted(&mut self, title: Option<&str>, render: impl FnOnce(&mut Self)) {
+		self.quotes.push(OpenQuote {
+			start: self.text.len(),
+			title: title
+				.map(str::trim)
+				.filter(|title| !title.is_empty())
+				.map(String::from),
+		});
+		render(self);
+
+		if let Some(quote) = self.quotes.pop() {
+			self.quote_from(quote.start, quote.title.as_deref());
+		}
+	}
+
+	/// Rewrite the text from `start` on as a blockquote, with an optional bold title
+	fn quote_from(&mut self, start: usize, title: Option<&str>) {
+		let body = self.text.split_off(start);
+		if body.trim().is_empty() && title.is_none() {
+			return;
+		}
+
+		if let Some(title) = title {
+			self.text.push_str(&format!("> **{title}**\n"));
+			if !body.trim().is_empty() {
+				self.text.push_str(">\n");
+			}
+		}
+		for line in body.trim().lines() {
+			if line.trim().is_empty() {
+				self.text.push_str(">\n");
+			} else {
+				self.text.push_str("> ");
+				self.text.push_str(line);
+				self.text.push('\n');
+			}
+		}
+		self.text.push('\n');
+	}
+
+	fn flush_text(&mut self) {
+		let text = mem::take(&mut self.text);
+		let text = text.trim();
+		if !text.is_empty() {
+			self.sections.push(ChapterSection::Text(text.to_string()));
+		}
+	}
+
+	/// Split the document into images and text pages of at most `max_len` bytes.
+	///
+	/// Paragraphs are never cut, and each text page ends with the footnotes it references.
+	pub fn into_sections(mut self, max_len: usize) -> Vec<ChapterSection> {
+		self.flush_text();
+
+		let footnotes = mem::take(&mut self.footnotes);
+		let mut result = Vec::new();
+		for section in self.sections {
+			match section {
+				ChapterSection::Text(text) => {
+					for mut page in split_text(&text, max_len) {
+						append_footnotes(&mut page, &footnotes);
+						result.push(ChapterSection::Text(page));
+					}
+				}
+				image => result.push(image),
+			}
+		}
+		result
+	}
+
+	pub fn into_pages(self) -> Vec<Page> {
+		self.into_sections(MAX_PAGE_LENGTH)
+			.into_iter()
+			.map(|section| Page {
+				content: match section {
+					ChapterSection::Text(text) => PageContent::text(text),
+					ChapterSection::Image(url) => PageContent::url(url),
+				},
+				..Default::default()
+			})
+			.collect()
+	}
+
+	/// Render the whole document as a single markdown string
+	pub fn into_markdown(self) -> String {
+		let mut markdown = self
+			.into_sections(usize::MAX)
+			.into_iter()
+			.map(|section| match section {
+				ChapterSection::Text(text) => text,
+				ChapterSection::Image(url) => format!("![]({url})"),
+			})
+			.collect::<Vec<_>>()
+			.join("\n\n");
+		markdown.truncate(markdown.trim_end().len());
+		markdown
+	}
+}
+
+fn split_text(text: &str, max_len: usize) -> Vec<String> {
+	let mut pages = Vec::new();
+	let mut current = String::new();
+
+	for paragraph in text.split("\n\n") {
+		if !current.is_empty() && current.len() + paragraph.len() + 2 > max_len {
+			pages.push(mem::take(&mut current));
+		}
+		if !current.is_empty() {
+			current.push_str("\n\n");
+		}
+		current.push_str(paragraph);
+	}
+	if !current.trim().is_empty() {
+		pages.push(current);
+	}
+
+	pages
+}
+
+fn append_footnotes(page: &mut String, footnotes: &[String]) {
+	let mut notes = Vec::new();
+	let mut rest = page.as_str();
+	while let Some(start) = rest.find("[^") {
+		rest = &rest[start + 2..];
+		if let Some(end) = rest.find(']')
+			&& let Ok(index) = rest[..end].parse::<usize>()
+			&& let Some(note) = index.checked_sub(1).and_then(|i| footnotes.get(i))
+		{
+			notes.push(format!("[^{index}]: {note}"));
+		}
+	}
+
+	if !notes.is_empty() {
+		page.push_str("\n\n---\n\n");
+		page.push_str(&notes.join("\n\n"));
+	}
+}
+
+/// Make an image path from chapter content absolute
+pub fn resolve_url(url: &str, ctx: &Context) -> String {
+	if url.starts_with("http://") || url.starts_with("https://") {
+		url.to_string()
+	} else if url.starts_with("//") {
+		format!("https:{url}")
+	} else if url.starts_with('/') {
+		format!("{}{}", ctx.base_url.trim_end_matches('/'), url)
+	} else {
+		format!("{}/{}", ctx.base_url.trim_end_matches('/'), url)
+	}
+}
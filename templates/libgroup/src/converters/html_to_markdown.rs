@@ -4,61 +4,74 @@ use aidoku::{
 	prelude::*,
 };
 
-pub fn convert_html_to_markdown(html: &str) -> String {
+use crate::context::Context;
+
+use super::document::{ChapterDocument, DEFAULT_SPOILER_TITLE, resolve_url};
+
+pub fn convert_html_to_document(html: &str, ctx: &Context) -> ChapterDocument {
 	match Html::parse(html) {
 		Ok(doc) => {
-			let mut markdown = String::new();
+			let mut markdown = ChapterDocument::default();
 
 			if let Some(body) = doc.select_first("body") {
-				convert_element_to_markdown(&body, &mut markdown, 0);
+				convert_element_to_markdown(&body, &mut markdown, ctx, 0);
 			} else if let Some(html_elem) = doc.select_first("html") {
 				for child in html_elem.children() {
-					convert_element_to_markdown(&child, &mut markdown, 0);
+					convert_element_to_markdown(&child, &mut markdown, ctx, 0);
 				}
 			} else if let Some(elements) = doc.select("body > *, html > *, > *") {
 				for element in elements {
-					convert_element_to_markdown(&element, &mut markdown, 0);
+					convert_element_to_markdown(&element, &mut markdown, ctx, 0);
 				}
 			} else {
-				return convert_html_fragment_to_markdown(html);
+				return convert_html_fragment_to_document(html, ctx);
 			}
 
-			markdown.trim().to_string()
+			markdown
 		}
-		Err(_) => convert_html_fragment_to_markdown(html),
+		Err(_) => convert_html_fragment_to_document(html, ctx),
 	}
 }
 
-fn convert_html_fragment_to_markdown(html: &str) -> String {
+fn convert_html_fragment_to_document(html: &str, ctx: &Context) -> ChapterDocument {
 	match Html::parse_fragment(html) {
 		Ok(doc) => {
-			let mut markdown = String::new();
+			let mut markdown = ChapterDocument::default();
 
 			if let Some(body) = doc.select_first("body") {
 				for child in body.children() {
-					convert_element_to_markdown(&child, &mut markdown, 0);
+					convert_element_to_markdown(&child, &mut markdown, ctx, 0);
 				}
 			} else if let Some(elements) = doc.select("*") {
 				for element in elements {
 					let tag = element.tag_name().unwrap_or_default();
 					if tag != "html" && tag != "body" {
-						convert_element_to_markdown(&element, &mut markdown, 0);
+						convert_element_to_markdown(&element, &mut markdown, ctx, 0);
 					}
 				}
 			}
 
-			markdown.trim().to_string()
+			markdown
+		}
+		Err(_) => {
+			let mut markdown = ChapterDocument::default();
+			markdown.push_str(&Html::unescape(html).unwrap_or_else(|| html.to_string()));
+			markdown
 		}
-		Err(_) => Html::unescape(html).unwrap_or_else(|| html.to_string()),
 	}
 }
 
-fn convert_element_to_markdown(element: &Element, output: &mut String, depth: usize) {
+fn convert_element_to_markdown(
+	element: &Element,
+	output: &mut ChapterDocument,
+	ctx: &Context,
+	depth: usize,
+) {
 	let tag = element.tag_name().unwrap_or_default();
 
 	match tag.as_str() {
 		"p" => {
-			convert_children_to_markdown(element, output, depth);
+			convert_children_to_markdown(element, output, ctx, depth);
 			output.push_str("\n\n");
 		}
 		"br" => {
@@ -66,77 +79,80 @@ fn convert_element_to_markdown(element: &Element, output: &mut String, depth: us
 		}
 		"h1" => {
 			output.push_str("# ");
-			convert_children_to_markdown(element, output, depth);
+			convert_children_to_markdown(element, output, ctx, depth);
 			output.push_str("\n\n");
 		}
 		"h2" => {
 			output.push_str("## ");
-			convert_children_to_markdown(element, output, depth);
+			convert_children_to_markdown(element, output, ctx, depth);
 			output.push_str("\n\n");
 		}
 		"h3" => {
 			output.push_str("### ");
-			convert_children_to_markdown(element, output, depth);
+			convert_children_to_markdown(element, output, ctx, depth);
 			output.push_str("\n\n");
 		}
 		"h4" => {
 			output.push_str("#### ");
-			convert_children_to_markdown(element, output, depth);
+			convert_children_to_markdown(element, output, ctx, depth);
 			output.push_str("\n\n");
 		}
 		"h5" => {
 			output.push_str("##### ");
-			convert_children_to_markdown(element, output, depth);
+			convert_children_to_markdown(element, output, ctx, depth);
 			output.push_str("\n\n");
 		}
 		"h6" => {
 			output.push_str("###### ");
-			convert_children_to_markdown(element, output, depth);
+			convert_children_to_markdown(element, output, ctx, depth);
 			output.push_str("\n\n");
 		}
 		"strong" | "b" => {
 			output.push_str("**");
-			convert_children_to_markdown(element, output, depth);
+			convert_children_to_markdown(element, output, ctx, depth);
 			output.push_str("**");
 		}
 		"em" | "i" => {
 			output.push('*');
-			convert_children_to_markdown(element, output, depth);
+			convert_children_to_markdown(element, output, ctx, depth);
 			output.push('*');
 		}
 		"u" => {
 			output.push_str("__");
-			convert_children_to_markdown(element, output, depth);
+			convert_children_to_markdown(element, output, ctx, depth);
 			output.push_str("__");
 		}
 		"s" | "strike" | "del" => {
 			output.push_str("~~");
-			convert_children_to_markdown(element, output, depth);
+			convert_children_to_markdown(element, output, ctx, depth);
 			output.push_str("~~");
 		}
 		"code" => {
 			output.push('`');
-			convert_children_to_markdown(element, output, depth);
+			convert_children_to_markdown(element, output, ctx, depth);
 			output.push('`');
 		}
 		"pre" => {
 			output.push_str("```\n");
-			convert_children_to_markdown(element, output, depth);
+			convert_children_to_markdown(element, output, ctx, depth);
 			output.push_str("\n```\n\n");
 		}
 		"img" => {
-			if let Some(src) = element.attr("src") {
-				let alt = element.attr("alt").unwrap_or_default();
-				output.push_str(&format!("![{alt}]({src})\n\n"));
+			if let Some(src) = element
+				.attr("data-src")
+				.or_else(|| element.attr("src"))
+				.filter(|src| !src.is_empty())
+			{
+				output.push_image(resolve_url(&src, ctx));
 			}
 		}
 		"a" => {
 			if let Some(href) = element.attr("href") {
 				output.push('[');
-				convert_children_to_markdown(element, output, depth);
+				convert_children_to_markdown(element, output, ctx, depth);
 				output.push_str(&format!("]({href})"));
 			} else {
-				convert_children_to_markdown(element, output, depth);
+				convert_children_to_markdown(element, output, ctx, depth);
 			}
 		}
 		"ul" | "ol" => {
@@ -147,28 +163,52 @@ fn convert_element_to_markdown(element: &Element, output: &mut String, depth: us
 					} else {
 						output.push_str("- ");
 					}
-					convert_children_to_markdown(&child, output, depth + 1);
+					convert_children_to_markdown(&child, output, ctx, depth + 1);
 					output.push('\n');
 				}
 			}
 			output.push('\n');
 		}
 		"blockquote" => {
-			output.push_str("> ");
-			convert_children_to_markdown(element, output, depth);
-			output.push_str("\n\n");
+			output.push_quoted(None, |output| {
+				convert_children_to_markdown(element, output, ctx, depth);
+			});
+		}
+		"details" => {
+			let title = element
+				.select_first("summary")
+				.and_then(|summary| summary.text())
+				.unwrap_or_else(|| DEFAULT_SPOILER_TITLE.to_string());
+			output.push_quoted(Some(&title), |output| {
+				for child in element.children() {
+					if child.tag_name().as_deref() != Some("summary") {
+						convert_element_to_markdown(&child, output, ctx, depth);
+					}
+				}
+			});
+		}
+		_ if element.has_class("spoiler") => {
+			output.push_quoted(Some(DEFAULT_SPOILER_TITLE), |output| {
+				convert_children_to_markdown(element, output, ctx, depth);
+			});
+		}
+		"sup" | "span" | "abbr" if footnote_text(element).is_some() => {
+			convert_children_to_markdown(element, output, ctx, depth);
+			if let Some(note) = footnote_text(element) {
+				output.push_footnote(&note);
+			}
 		}
 		"hr" => {
 			output.push_str("---\n\n");
 		}
 		"div" | "section" | "article" | "header" | "footer" | "main" | "aside" => {
-			convert_children_to_markdown(element, output, depth);
+			convert_children_to_markdown(element, output, ctx, depth);
 			if !output.ends_with("\n\n") && !output.ends_with("\n") {
 				output.push('\n');
 			}
 		}
 		"span" => {
-			convert_children_to_markdown(element, output, depth);
+			convert_children_to_markdown(element, output, ctx, depth);
 		}
 		_ => {
 			if let Some(text) = element.own_text()
@@ -176,12 +216,17 @@ fn convert_element_to_markdown(element: &Element, output: &mut String, depth: us
 			{
 				output.push_str(&text);
 			}
-			convert_children_to_markdown(element, output, depth);
+			convert_children_to_markdown(element, output, ctx, depth);
 		}
 	}
 }
 
-fn convert_children_to_markdown(element: &Element, output: &mut String, depth: usize) {
+fn convert_children_to_markdown(
+	element: &Element,
+	output: &mut ChapterDocument,
+	ctx: &Context,
+	depth: usize,
+) {
 	if let Some(text) = element.own_text()
 		&& !text.trim().is_empty()
 	{
@@ -189,6 +234,15 @@ fn convert_children_to_markdown(element: &Element, output: &mut String, depth: u
 	}
 
 	for child in element.children() {
-		convert_element_to_markdown(&child, output, depth);
+		convert_element_to_markdown(&child, output, ctx, depth);
 	}
 }
+
+/// Translator notes are attached to the marked text as an attribute
+fn footnote_text(element: &Element) -> Option<String> {
+	element
+		.attr("data-note")
+		.or_else(|| element.attr("data-footnote"))
+		.or_else(|| element.attr("title"))
+		.filter(|note| !note.trim().is_empty())
+}
@@ -1,5 +1,9 @@
+mod document;
 mod html_to_markdown;
 mod model_to_markdown;
 
-pub use html_to_markdown::convert_html_to_markdown;
-pub use model_to_markdown::convert_model_to_markdown;
+pub use html_to_markdown::convert_html_to_document;
+pub use model_to_markdown::{convert_model_to_document, convert_model_to_markdown};
+
+#[cfg(test)]
+mod test;
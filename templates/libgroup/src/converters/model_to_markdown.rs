@@ -6,40 +6,50 @@ use aidoku::{
 use crate::{
 	cdn::get_selected_image_server_url,
 	context::Context,
-	models::chapter::{
-		LibGroupAttachment, LibGroupContentModel, LibGroupContentNode, LibGroupTextNode,
-	},
+	models::chapter::{LibGroupAttachment, LibGroupContentModel, LibGroupContentNode},
 };
 
+use super::document::{ChapterDocument, DEFAULT_SPOILER_TITLE, resolve_url};
+
 pub fn convert_model_to_markdown(
 	model: &LibGroupContentModel,
 	attachments: &[LibGroupAttachment],
 	ctx: &Context,
 ) -> String {
-	let mut markdown = String::new();
+	convert_model_to_document(model, attachments, ctx).into_markdown()
+}
+
+pub fn convert_model_to_document(
+	model: &LibGroupContentModel,
+	attachments: &[LibGroupAttachment],
+	ctx: &Context,
+) -> ChapterDocument {
+	let mut document = ChapterDocument::default();
 
 	if let Some(content) = &model.content {
 		for node in content {
-			convert_node_to_markdown(node, &mut markdown, attachments, ctx);
+			convert_node_to_markdown(node, &mut document, attachments, ctx);
 		}
 	}
 
-	markdown.trim().to_string()
+	document
 }
 
 fn convert_node_to_markdown(
 	node: &LibGroupContentNode,
-	output: &mut String,
+	output: &mut ChapterDocument,
 	attachments: &[LibGroupAttachment],
 	ctx: &Context,
 ) {
 	match node.node_type.as_str() {
+		"text" => {
+			convert_text_node_to_markdown(node, output);
+		}
+		"hardBreak" | "hard_break" => {
+			output.push_str("  \n");
+		}
 		"paragraph" => {
-			if let Some(content) = &node.content {
-				for text_node in content {
-					convert_text_node_to_markdown(text_node, output);
-				}
-			}
+			convert_children_to_markdown(node, output, attachments, ctx);
 			output.push_str("\n\n");
 		}
 		"image" | "images" => {
@@ -57,16 +67,7 @@ fn convert_node_to_markdown(
 									.map(|name| name == img.image)
 									.unwrap_or(false)
 						})
-						.map(|attachment| {
-							let url = &attachment.url;
-							if url.starts_with("http://") || url.starts_with("https://") {
-								url.clone()
-							} else if url.starts_with('/') {
-								format!("{}{}", ctx.base_url.trim_end_matches('/'), url)
-							} else {
-								format!("{}/{}", ctx.base_url.trim_end_matches('/'), url)
-							}
-						})
+						.map(|attachment| resolve_url(&attachment.url, ctx))
 						.unwrap_or_else(|| {
 							if img.image.starts_with("http://") || img.image.starts_with("https://")
 							{
@@ -76,75 +77,125 @@ fn convert_node_to_markdown(
 							}
 						});
 
-					output.push_str(&format!("![]({image_url})\n\n"));
+					output.push_image(image_url);
 				}
 			}
 		}
 		"heading" => {
 			output.push_str("## ");
-			if let Some(content) = &node.content {
-				for text_node in content {
-					convert_text_node_to_markdown(text_node, output);
-				}
-			}
+			convert_children_to_markdown(node, output, attachments, ctx);
 			output.push_str("\n\n");
 		}
 		"horizontalRule" | "hr" => {
 			output.push_str("---\n\n");
 		}
 		"blockquote" => {
-			output.push_str("> ");
-			if let Some(content) = &node.content {
-				for text_node in content {
-					convert_text_node_to_markdown(text_node, output);
+			output.push_quoted(None, |output| {
+				convert_children_to_markdown(node, output, attachments, ctx);
+			});
+		}
+		"spoiler" | "details" => {
+			let summary = node.content.as_ref().and_then(|content| {
+				content
+					.iter()
+					.find(|child| child.node_type == "detailsSummary")
+			});
+			let title = summary
+				.map(node_text)
+				.or_else(|| {
+					node.attrs
+						.as_ref()
+						.and_then(|attrs| attrs.label())
+						.map(|label| label.to_string())
+				})
+				.unwrap_or_else(|| DEFAULT_SPOILER_TITLE.to_string());
+
+			output.push_quoted(Some(&title), |output| {
+				for child in node.content.iter().flatten() {
+					if child.node_type != "detailsSummary" {
+						convert_node_to_markdown(child, output, attachments, ctx);
+					}
 				}
-			}
-			output.push_str("\n\n");
+			});
+		}
+		"footnote" | "note" => {
+			let note = node
+				.attrs
+				.as_ref()
+				.and_then(|attrs| attrs.label())
+				.map(|label| label.to_string())
+				.unwrap_or_else(|| node_text(node));
+			output.push_footnote(&note);
 		}
 		"codeBlock" | "code_block" => {
 			output.push_str("```\n");
-			if let Some(content) = &node.content {
-				for text_node in content {
-					if let Some(text) = &text_node.text {
-						output.push_str(text);
-					}
-				}
-			}
+			output.push_str(&node_text(node));
 			output.push_str("\n```\n\n");
 		}
 		"bulletList" | "bullet_list" => {
-			if let Some(content) = &node.content {
-				for item in content.iter() {
-					output.push_str("- ");
-					convert_text_node_to_markdown(item, output);
-					output.push('\n');
-				}
+			for item in node.content.iter().flatten() {
+				output.push_str("- ");
+				convert_list_item_to_markdown(item, output, attachments, ctx);
+				output.push('\n');
 			}
 			output.push('\n');
 		}
 		"orderedList" | "ordered_list" => {
-			if let Some(content) = &node.content {
-				for (i, item) in content.iter().enumerate() {
-					output.push_str(&format!("{}. ", i + 1));
-					convert_text_node_to_markdown(item, output);
-					output.push('\n');
-				}
+			for (i, item) in node.content.iter().flatten().enumerate() {
+				output.push_str(&format!("{}. ", i + 1));
+				convert_list_item_to_markdown(item, output, attachments, ctx);
+				output.push('\n');
 			}
 			output.push('\n');
 		}
 		_ => {
-			if let Some(content) = &node.content {
-				for text_node in content {
-					convert_text_node_to_markdown(text_node, output);
-				}
+			if node.text.is_some() {
+				convert_text_node_to_markdown(node, output);
 			}
+			convert_children_to_markdown(node, output, attachments, ctx);
 		}
 	}
 }
 
-fn convert_text_node_to_markdown(text_node: &LibGroupTextNode, output: &mut String) {
+fn convert_children_to_markdown(
+	node: &LibGroupContentNode,
+	output: &mut ChapterDocument,
+	attachments: &[LibGroupAttachment],
+	ctx: &Context,
+) {
+	for child in node.content.iter().flatten() {
+		convert_node_to_markdown(child, output, attachments, ctx);
+	}
+}
+
+/// List items wrap their text in paragraphs, which are rendered inline here
+fn convert_list_item_to_markdown(
+	item: &LibGroupContentNode,
+	output: &mut ChapterDocument,
+	attachments: &[LibGroupAttachment],
+	ctx: &Context,
+) {
+	for child in item.content.iter().flatten() {
+		if child.node_type == "paragraph" {
+			convert_children_to_markdown(child, output, attachments, ctx);
+		} else {
+			convert_node_to_markdown(child, output, attachments, ctx);
+		}
+	}
+}
+
+fn node_text(node: &LibGroupContentNode) -> String {
+	let mut text = node.text.clone().unwrap_or_default();
+	for child in node.content.iter().flatten() {
+		text.push_str(&node_text(child));
+	}
+	text
+}
+
+fn convert_text_node_to_markdown(text_node: &LibGroupContentNode, output: &mut ChapterDocument) {
 	if let Some(text) = &text_node.text {
 		let mut formatted_text = text.clone();
+		let mut footnote = None;
 
 		if let Some(marks) = &text_node.marks {
 			for mark in marks.iter().rev() {
@@ -164,6 +215,9 @@ fn convert_text_node_to_markdown(text_node: &LibGroupTextNode, output: &mut Stri
 					"code" => {
 						formatted_text = format!("`{formatted_text}`");
 					}
+					"footnote" | "note" | "tooltip" => {
+						footnote = mark.attrs.as_ref().and_then(|attrs| attrs.label());
+					}
 					"link" => {}
 					_ => {}
 				}
@@ -171,5 +225,8 @@ fn convert_text_node_to_markdown(text_node: &LibGroupTextNode, output: &mut Stri
 		}
 
 		output.push_str(&formatted_text);
+		if let Some(note) = footnote {
+			output.push_footnote(note);
+		}
 	}
 }
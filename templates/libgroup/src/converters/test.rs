@@ -0,0 +1,143 @@
+use crate::context::Context;
+use crate::models::chapter::{LibGroupAttachment, LibGroupContentModel};
+use aidoku::alloc::{String, string::ToString, vec};
+use aidoku_test::aidoku_test;
+
+use super::convert_model_to_document;
+use super::document::{ChapterDocument, ChapterSection};
+
+fn test_context() -> Context {
+	Context {
+		api_url: "http://fake.api".to_string(),
+		base_url: "http://fake.base".to_string(),
+		site_id: 3,
+		cover_quality: "high".to_string(),
+	}
+}
+
+fn text(text: &str) -> ChapterSection {
+	ChapterSection::Text(text.to_string())
+}
+
+#[aidoku_test]
+fn images_split_text_sections() {
+	let mut document = ChapterDocument::default();
+	document.push_str("Before\n\n");
+	document.push_image("http://fake.base/1.png".to_string());
+	document.push_str("After\n\n");
+
+	assert_eq!(
+		document.into_sections(1024),
+		vec![
+			text("Before"),
+			ChapterSection::Image("http://fake.base/1.png".to_string()),
+			text("After"),
+		]
+	);
+}
+
+#[aidoku_test]
+fn long_text_is_split_at_paragraphs() {
+	let mut document = ChapterDocument::default();
+	document.push_str("aaaa\n\nbbbb\n\ncccc");
+
+	assert_eq!(
+		document.into_sections(10),
+		vec![text("aaaa\n\nbbbb"), text("cccc")]
+	);
+}
+
+#[aidoku_test]
+fn footnotes_follow_their_page() {
+	let mut document = ChapterDocument::default();
+	document.push_str("first");
+	document.push_footnote("note one");
+	document.push_str("\n\nsecond");
+	document.push_footnote("note two");
+
+	assert_eq!(
+		document.into_sections(10),
+		vec![
+			text("first[^1]\n\n---\n\n[^1]: note one"),
+			text("second[^2]\n\n---\n\n[^2]: note two"),
+		]
+	);
+}
+
+#[aidoku_test]
+fn quoted_text_keeps_paragraphs() {
+	let mut document = ChapterDocument::default();
+	document.push_quoted(Some("Spoiler"), |document| {
+		document.push_str("one\n\ntwo\n\n");
+	});
+
+	assert_eq!(
+		document.into_markdown(),
+		"> **Spoiler**\n>\n> one\n>\n> two".to_string()
+	);
+}
+
+#[aidoku_test]
+fn model_keeps_images_footnotes_and_spoilers() {
+	let model: LibGroupContentModel = serde_json::from_str(
+		r#"{"type":"doc","content":[
+			{"type":"paragraph","content":[
+				{"type":"text","text":"Hello"},
+				{"type":"footnote","attrs":{"note":"a greeting"}}
+			]},
+			{"type":"image","attrs":{"images":[{"image":"pic"}]}},
+			{"type":"spoiler","content":[
+				{"type":"paragraph","content":[{"type":"text","text":"secret","marks":[{"type":"bold"}]}]}
+			]}
+		]}"#,
+	)
+	.unwrap();
+	let attachments = [LibGroupAttachment {
+		url: "/uploads/pic.jpg".to_string(),
+		name: Some("pic".to_string()),
+		filename: None,
+	}];
+
+	let sections =
+		convert_model_to_document(&model, &attachments, &test_context()).into_sections(1024);
+
+	assert_eq!(
+		sections,
+		vec![
+			text("Hello[^1]\n\n---\n\n[^1]: a greeting"),
+			ChapterSection::Image("http://fake.base/uploads/pic.jpg".to_string()),
+			ChapterSection::Text(String::from("> **Спойлер**\n>\n> **secret**")),
+		]
+	);
+}
+
+#[aidoku_test]
+fn spoiler_stays_quoted_around_images() {
+	let model: LibGroupContentModel = serde_json::from_str(
+		r#"{"type":"doc","content":[
+			{"type":"spoiler","content":[
+				{"type":"paragraph","content":[{"type":"text","text":"before"}]},
+				{"type":"image","attrs":{"images":[{"image":"pic"}]}},
+				{"type":"paragraph","content":[{"type":"text","text":"after"}]}
+			]}
+		]}"#,
+	)
+	.unwrap();
+	let attachments = [LibGroupAttachment {
+		url: "/uploads/pic.jpg".to_string(),
+		name: Some("pic".to_string()),
+		filename: None,
+	}];
+
+	let sections =
+		convert_model_to_document(&model, &attachments, &test_context()).into_sections(1024);
+
+	assert_eq!(
+		sections,
+		vec![
+			text("> **Спойлер**\n>\n> before"),
+			ChapterSection::Image("http://fake.base/uploads/pic.jpg".to_string()),
+			text("> after"),
+		]
+	);
+}
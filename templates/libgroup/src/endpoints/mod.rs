@@ -6,6 +6,20 @@ use aidoku::{
 /// URL builder for API endpoints
 pub struct Url;
 
+/// Manga or chapter referenced by a site URL
+#[derive(Debug, PartialEq)]
+pub enum SiteLink {
+	Manga {
+		slug_url: String,
+	},
+	Chapter {
+		slug_url: String,
+		volume: String,
+		number: String,
+		branch_id: Option<i32>,
+	},
+}
+
 impl Url {
 	const BASE_PATH: &'static str = "/api";
 
@@ -116,6 +130,26 @@ impl Url {
 		)
 	}
 
+	/// Build bookmark folders URL for a user
+	pub fn bookmark_folders(base_url: &str, user_id: i32) -> String {
+		format!(
+			"{}{}/bookmarks/folder/{}",
+			Self::normalize_base(base_url),
+			Self::BASE_PATH,
+			user_id
+		)
+	}
+
+	/// Create bookmarks URL with query parameters
+	pub fn bookmarks_with_params(base_url: &str, params: &[(&str, &str)]) -> String {
+		let base = format!(
+			"{}{}/bookmarks",
+			Self::normalize_base(base_url),
+			Self::BASE_PATH
+		);
+		Self::append_query_params(base, params)
+	}
+
 	/// Create manga search URL with query parameters
 	pub fn manga_search_with_params(base_url: &str, params: &[(&str, &str)]) -> String {
 		let base = Self::manga_search(base_url);
@@ -168,6 +202,51 @@ impl Url {
 		Self::append_query_params(base, params)
 	}
 
+	/// Host of an absolute URL, without a leading `www.`
+	pub fn host(url: &str) -> Option<&str> {
+		let (_, rest) = url.split_once("://")?;
+		let host = rest.split(['/', '?', '#']).next()?;
+		Some(host.strip_prefix("www.").unwrap_or(host)).filter(|host| !host.is_empty())
+	}
+
+	/// Parse a manga or chapter page URL on one of `domains`
+	pub fn parse_site_link(url: &str, domains: &[&str]) -> Option<SiteLink> {
+		if !domains.contains(&Self::host(url)?) {
+			return None;
+		}
+		let (_, rest) = url.split_once("://")?;
+		let (_, path) = rest.split_once('/')?;
+		let (path, query) = path.split_once('?').unwrap_or((path, ""));
+		let path = path.split('#').next().unwrap_or_default();
+
+		let mut segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+		// drop the locale prefix (/ru/...)
+		if segments
+			.first()
+			.is_some_and(|s| s.len() == 2 && s.chars().all(|c| c.is_ascii_lowercase()))
+		{
+			segments.remove(0);
+		}
+
+		match segments.as_slice() {
+			// /ru/manga/{slug}, /ru/book/{slug}
+			["manga" | "book", slug, ..] => Some(SiteLink::Manga {
+				slug_url: (*slug).into(),
+			}),
+			// /ru/{slug}/read/v{volume}/c{number}?bid={branch_id}
+			[slug, "read", volume, number, ..] => Some(SiteLink::Chapter {
+				slug_url: (*slug).into(),
+				volume: volume.strip_prefix('v')?.into(),
+				number: number.strip_prefix('c')?.into(),
+				branch_id: query
+					.split('&')
+					.find_map(|pair| pair.strip_prefix("bid="))
+					.and_then(|id| id.parse().ok()),
+			}),
+			_ => None,
+		}
+	}
+
 	/// Normalize base URL by removing trailing slash
 	fn normalize_base(base_url: &str) -> &str {
 		base_url.trim_end_matches('/')
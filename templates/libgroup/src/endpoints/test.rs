@@ -2,6 +2,13 @@ use super::*;
 use aidoku_test::aidoku_test;
 
 const TEST_BASE_URL: &str = "https://api.cdnlibs.org";
+const TEST_DOMAINS: &[&str] = &[
+	"mangalib.me",
+	"mangalib.org",
+	"ranobelib.me",
+	"hentailib.me",
+	"slashlib.me",
+];
 
 #[aidoku_test]
 fn basic_urls() {
@@ -139,6 +146,23 @@ fn constants_with_fields() {
 	assert_eq!(empty_url, "https://api.cdnlibs.org/api/constants");
 }
 
+#[aidoku_test]
+fn bookmark_urls() {
+	assert_eq!(
+		Url::bookmark_folders(TEST_BASE_URL, 42),
+		"https://api.cdnlibs.org/api/bookmarks/folder/42"
+	);
+
+	let url = Url::bookmarks_with_params(
+		TEST_BASE_URL,
+		&[("page", "2"), ("status", "5"), ("user_id", "42")],
+	);
+	assert_eq!(
+		url,
+		"https://api.cdnlibs.org/api/bookmarks?page=2&status=5&user_id=42"
+	);
+}
+
 #[aidoku_test]
 fn trailing_slash_handling() {
 	let base_with_slash = "https://api.example.com/";
@@ -150,3 +174,125 @@ fn trailing_slash_handling() {
 	assert_eq!(url1, url2);
 	assert_eq!(url1, "https://api.example.com/api/manga");
 }
+
+#[aidoku_test]
+fn parse_manga_links() {
+	assert_eq!(
+		Url::parse_site_link("https://mangalib.me/ru/manga/206--one-piece", TEST_DOMAINS),
+		Some(SiteLink::Manga {
+			slug_url: "206--one-piece".into()
+		})
+	);
+
+	assert_eq!(
+		Url::parse_site_link(
+			"https://mangalib.org/ru/manga/206--one-piece?section=chapters",
+			TEST_DOMAINS
+		),
+		Some(SiteLink::Manga {
+			slug_url: "206--one-piece".into()
+		})
+	);
+
+	assert_eq!(
+		Url::parse_site_link(
+			"https://ranobelib.me/ru/book/165329--kusuriya-no-hitorigoto-ln",
+			TEST_DOMAINS
+		),
+		Some(SiteLink::Manga {
+			slug_url: "165329--kusuriya-no-hitorigoto-ln".into()
+		})
+	);
+}
+
+#[aidoku_test]
+fn parse_chapter_links() {
+	assert_eq!(
+		Url::parse_site_link(
+			"https://mangalib.me/ru/206--one-piece/read/v1/c1",
+			TEST_DOMAINS
+		),
+		Some(SiteLink::Chapter {
+			slug_url: "206--one-piece".into(),
+			volume: "1".into(),
+			number: "1".into(),
+			branch_id: None,
+		})
+	);
+
+	assert_eq!(
+		Url::parse_site_link(
+			"https://hentailib.me/ru/1234--test/read/v2/c10.5?bid=5678&ui=42",
+			TEST_DOMAINS
+		),
+		Some(SiteLink::Chapter {
+			slug_url: "1234--test".into(),
+			volume: "2".into(),
+			number: "10.5".into(),
+			branch_id: Some(5678),
+		})
+	);
+}
+
+#[aidoku_test]
+fn parse_chapter_page_roundtrip() {
+	let url = Url::chapter_page(
+		"https://slashlib.me",
+		"1234--test",
+		Some(3.0),
+		Some(12.0),
+		Some(99),
+		&Some(42),
+	);
+
+	assert_eq!(
+		Url::parse_site_link(&url, TEST_DOMAINS),
+		Some(SiteLink::Chapter {
+			slug_url: "1234--test".into(),
+			volume: "3".into(),
+			number: "12".into(),
+			branch_id: Some(99),
+		})
+	);
+}
+
+#[aidoku_test]
+fn parse_unsupported_links() {
+	assert_eq!(
+		Url::parse_site_link("https://mangalib.me/", TEST_DOMAINS),
+		None
+	);
+	assert_eq!(
+		Url::parse_site_link("https://mangalib.me/ru/catalog", TEST_DOMAINS),
+		None
+	);
+	assert_eq!(
+		Url::parse_site_link("https://mangalib.me/ru/user/42", TEST_DOMAINS),
+		None
+	);
+	assert_eq!(Url::parse_site_link("not a url", TEST_DOMAINS), None);
+}
+
+#[aidoku_test]
+fn parse_links_from_other_hosts() {
+	assert_eq!(
+		Url::parse_site_link("https://example.com/ru/manga/206--one-piece", TEST_DOMAINS),
+		None
+	);
+	assert_eq!(
+		Url::parse_site_link(
+			"https://mangalib.me.example.com/ru/manga/206--one-piece",
+			TEST_DOMAINS
+		),
+		None
+	);
+	assert_eq!(
+		Url::parse_site_link(
+			"https://www.mangalib.me/ru/manga/206--one-piece",
+			TEST_DOMAINS
+		),
+		Some(SiteLink::Manga {
+			slug_url: "206--one-piece".into()
+		})
+	);
+}
@@ -1,29 +1,33 @@
 use aidoku::{
-	AidokuError, Chapter, FilterValue, HomeLayout, Listing, Manga, MangaPageResult, Page,
-	PageContext, Result,
+	AidokuError, Chapter, DeepLinkResult, FilterValue, HomeLayout, Listing, Manga, MangaPageResult,
+	Page, PageContext, Result,
 	alloc::{String, Vec, string::ToString, vec},
 	imports::{net::Request, std::send_partial_result},
+	prelude::*,
 };
 
 use crate::{
 	auth::{AuthRequest, USER_AGENT, clear_user_id, get_user_id},
-	chapters::get_chapters_cache,
+	chapters::{get_chapters_cache, select_branches},
 	context::Context,
-	endpoints::Url,
+	endpoints::{SiteLink, Url},
 	filters::FilterProcessor,
 	home,
 	json::ResponseJsonExt,
 	models::{
 		chapter::LibGroupChapterListItem,
 		responses::{
-			ChapterResponse, ChaptersResponse, MangaCoversResponse, MangaDetailResponse,
-			MangaListResponse,
+			BookmarkFoldersResponse, BookmarksResponse, ChapterResponse, ChaptersResponse,
+			MangaCoversResponse, MangaDetailResponse, MangaListResponse,
 		},
 	},
+	settings::get_branch_strategy,
 };
 
 use super::Params;
 
+const BOOKMARKS_LISTING_PREFIX: &str = "bookmarks.";
+
 pub trait Impl {
 	fn new() -> Self;
 
@@ -114,11 +118,13 @@ pub trait Impl {
 		if needs_chapters {
 			let chapters_url = Url::manga_chapters(ctx.api_url.as_str(), &slug_url);
 
+			let items = Request::get(chapters_url)?
+				.authed(&ctx)?
+				.parse_json::<ChaptersResponse>()?
+				.data;
+
 			let chapters = LibGroupChapterListItem::flatten_chapters(
-				Request::get(chapters_url)?
-					.authed(&ctx)?
-					.parse_json::<ChaptersResponse>()?
-					.data,
+				select_branches(items, &get_branch_strategy()),
 				ctx.base_url.as_str(),
 				&slug_url,
 				&user_id,
@@ -183,6 +189,10 @@ pub trait Impl {
 		let site_id_str = ctx.site_id.to_string();
 		let page_str = page.to_string();
 
+		if let Some(folder_id) = listing.id.strip_prefix(BOOKMARKS_LISTING_PREFIX) {
+			return self.get_bookmarks(&ctx, folder_id, page);
+		}
+
 		match listing.id.as_str() {
 			"popular" => {
 				// Popular manga
@@ -267,6 +277,60 @@ pub trait Impl {
 		}
 	}
 
+	fn get_dynamic_listings(&self, params: &Params) -> Result<Vec<Listing>> {
+		let ctx = Context::from_params(params);
+		let Some(user_id) = get_user_id(&ctx) else {
+			return Ok(Vec::new());
+		};
+
+		let folders = Request::get(Url::bookmark_folders(&ctx.api_url, user_id))?
+			.authed(&ctx)?
+			.parse_json::<BookmarkFoldersResponse>()?
+			.data;
+
+		Ok(folders
+			.into_iter()
+			.map(|folder| Listing {
+				id: format!("{BOOKMARKS_LISTING_PREFIX}{}", folder.id),
+				name: folder.name,
+				..Default::default()
+			})
+			.collect())
+	}
+
+	/// Lists the contents of one of the user's bookmark folders
+	fn get_bookmarks(&self, ctx: &Context, folder_id: &str, page: i32) -> Result<MangaPageResult> {
+		let user_id = get_user_id(ctx)
+			.ok_or_else(|| AidokuError::message("Войдите в аккаунт, чтобы видеть закладки"))?
+			.to_string();
+		let page_str = page.to_string();
+
+		let bookmarks_params: Vec<(&str, &str)> = vec![
+			("page", page_str.as_str()),
+			("sort_by", "name"),
+			("sort_type", "asc"),
+			("status", folder_id),
+			("user_id", user_id.as_str()),
+		];
+
+		let response = Request::get(Url::bookmarks_with_params(&ctx.api_url, &bookmarks_params))?
+			.authed(ctx)?
+			.parse_json::<BookmarksResponse>()?;
+
+		let entries: Vec<Manga> = response
+			.data
+			.into_iter()
+			.map(|bookmark| bookmark.media.into_manga(ctx))
+			.collect();
+
+		let has_next_page = response.meta.has_next_page.unwrap_or_default();
+
+		Ok(MangaPageResult {
+			entries,
+			has_next_page,
+		})
+	}
+
 	fn get_image_request(
 		&self,
 		params: &Params,
@@ -296,6 +360,53 @@ pub trait Impl {
 			.collect())
 	}
 
+	fn handle_deep_link(&self, params: &Params, url: String) -> Result<Option<DeepLinkResult>> {
+		// a custom web domain from settings counts as one of the source's own
+		let ctx = Context::from_params(params);
+		let mut domains: Vec<&str> = params.domains.to_vec();
+		domains.extend(Url::host(&ctx.base_url));
+
+		let Some(link) = Url::parse_site_link(&url, &domains) else {
+			return Ok(None);
+		};
+
+		match link {
+			SiteLink::Manga { slug_url } => Ok(Some(DeepLinkResult::Manga { key: slug_url })),
+			SiteLink::Chapter {
+				slug_url,
+				volume,
+				number,
+				branch_id,
+			} => {
+				let chapters = get_chapters_cache(Some(3600)).get_chapters(&slug_url, &ctx)?;
+
+				// chapter keys are the ids of the per-branch entries
+				let key = chapters
+					.into_iter()
+					.find(|c| c.number == number && c.volume == volume)
+					.and_then(|mut c| {
+						let linked = branch_id
+							.and_then(|id| c.branches.iter().position(|b| b.branch_id == Some(id)));
+						match linked {
+							Some(index) => Some(c.branches.swap_remove(index)),
+							None => select_branches(vec![c], &get_branch_strategy())
+								.pop()
+								.and_then(|c| c.branches.into_iter().next()),
+						}
+					})
+					.map(|b| b.id.to_string());
+
+				Ok(Some(match key {
+					Some(key) => DeepLinkResult::Chapter {
+						manga_key: slug_url,
+						key,
+					},
+					None => DeepLinkResult::Manga { key: slug_url },
+				}))
+			}
+		}
+	}
+
 	fn handle_manga_migration(&self, _params: &Params, key: String) -> Result<String> {
 		Ok(key)
 	}
@@ -318,13 +429,13 @@ pub trait Impl {
 		let chapters = get_chapters_cache(None).get_chapters(&manga_key, &ctx)?;
 
 		let chapter = chapters
-			.iter()
+			.into_iter()
 			.find(|c| c.number == chapter_number && c.volume == volume_number)
 			.ok_or_else(|| AidokuError::message("Chapter not found"))?;
 
-		let branch_id = chapter
-			.branches
-			.first()
+		let branch_id = select_branches(vec![chapter], &get_branch_strategy())
+			.pop()
+			.and_then(|c| c.branches.into_iter().next())
 			.map(|b| b.id)
 			.ok_or_else(|| AidokuError::message("No branch ID found"))?;
 
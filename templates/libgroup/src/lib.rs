@@ -1,8 +1,8 @@
 #![no_std]
 use aidoku::{
-	AlternateCoverProvider, Chapter, FilterValue, Home, HomeLayout, ImageRequestProvider, Listing,
-	ListingProvider, Manga, MangaPageResult, MigrationHandler, NotificationHandler, Page,
-	PageContext, Result, Source,
+	AlternateCoverProvider, Chapter, DeepLinkHandler, DeepLinkResult, DynamicListings, FilterValue,
+	Home, HomeLayout, ImageRequestProvider, Listing, ListingProvider, Manga, MangaPageResult,
+	MigrationHandler, NotificationHandler, Page, PageContext, Result, Source,
 	alloc::{String, Vec, borrow::Cow},
 	imports::net::Request,
 };
@@ -24,6 +24,8 @@ pub use imp::Impl;
 
 pub struct Params {
 	pub site_id: Cow<'static, u8>,
+	/// Hosts of the site and its mirrors, accepted for deep links
+	pub domains: Cow<'static, [&'static str]>,
 }
 
 pub struct LibGroup<T: Impl> {
@@ -68,6 +70,13 @@ impl<T: Impl> ListingProvider for LibGroup<T> {
 		self.inner.get_manga_list(&self.params, listing, page)
 	}
 }
+
+impl<T: Impl> DynamicListings for LibGroup<T> {
+	fn get_dynamic_listings(&self) -> Result<Vec<Listing>> {
+		self.inner.get_dynamic_listings(&self.params)
+	}
+}
+
 impl<T: Impl> Home for LibGroup<T> {
 	fn get_home(&self) -> Result<HomeLayout> {
 		self.inner.get_home(&self.params)
@@ -86,6 +95,12 @@ impl<T: Impl> AlternateCoverProvider for LibGroup<T> {
 	}
 }
 
+impl<T: Impl> DeepLinkHandler for LibGroup<T> {
+	fn handle_deep_link(&self, url: String) -> Result<Option<DeepLinkResult>> {
+		self.inner.handle_deep_link(&self.params, url)
+	}
+}
+
 impl<T: Impl> MigrationHandler for LibGroup<T> {
 	fn handle_manga_migration(&self, key: String) -> Result<String> {
 		self.inner.handle_manga_migration(&self.params, key)
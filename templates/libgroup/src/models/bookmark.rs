@@ -0,0 +1,17 @@
+use aidoku::alloc::String;
+use serde::Deserialize;
+
+use super::manga::LibGroupManga;
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+pub struct LibGroupBookmarkFolder {
+	pub id: i32,
+	pub name: String,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+pub struct LibGroupBookmark {
+	pub media: LibGroupManga,
+}
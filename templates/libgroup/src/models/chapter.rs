@@ -10,7 +10,7 @@ use serde_json::Value;
 use crate::{
 	cdn::get_selected_image_server_url,
 	context::Context,
-	converters::{convert_html_to_markdown, convert_model_to_markdown},
+	converters::{convert_html_to_document, convert_model_to_document},
 	endpoints::Url,
 	models::common::LibGroupModerated,
 };
@@ -101,15 +101,10 @@ pub struct LibGroupContentModel {
 pub struct LibGroupContentNode {
 	#[serde(rename = "type")]
 	pub node_type: String,
-	pub content: Option<Vec<LibGroupTextNode>>,
-	pub attrs: Option<LibGroupNodeAttrs>,
-}
-
-#[derive(Default, Deserialize)]
-#[serde(default)]
-pub struct LibGroupTextNode {
 	pub text: Option<String>,
 	pub marks: Option<Vec<LibGroupMark>>,
+	pub content: Option<Vec<LibGroupContentNode>>,
+	pub attrs: Option<LibGroupNodeAttrs>,
 }
 
 #[derive(Default, Deserialize)]
@@ -117,12 +112,26 @@ pub struct LibGroupTextNode {
 pub struct LibGroupMark {
 	#[serde(rename = "type")]
 	pub mark_type: String,
+	pub attrs: Option<LibGroupNodeAttrs>,
 }
 
 #[derive(Default, Deserialize)]
 #[serde(default)]
 pub struct LibGroupNodeAttrs {
 	pub images: Option<Vec<LibGroupImageAttr>>,
+	pub text: Option<String>,
+	pub title: Option<String>,
+	pub note: Option<String>,
+}
+
+impl LibGroupNodeAttrs {
+	/// Text of a footnote or the title of a spoiler
+	pub fn label(&self) -> Option<&str> {
+		self.note
+			.as_deref()
+			.or(self.text.as_deref())
+			.or(self.title.as_deref())
+	}
 }
 
 #[derive(Default, Deserialize)]
@@ -172,6 +181,30 @@ impl<'de> Deserialize<'de> for LibGroupContentType {
 	}
 }
 
+impl LibGroupChapterBranch {
+	/// Team names, or the uploader's username for team-less uploads
+	pub fn scanlators(&self) -> Vec<String> {
+		if self.teams.is_empty() {
+			vec![self.user.username.clone()]
+		} else {
+			self.teams.iter().map(|team| team.name.clone()).collect()
+		}
+	}
+
+	/// Whether the chapter is paywalled or still on moderation
+	pub fn is_locked(&self) -> bool {
+		self.restricted_view
+			.as_ref()
+			.map(|rv| !rv.is_open)
+			.unwrap_or(false)
+			|| self
+				.moderation
+				.as_ref()
+				.map(|m| m.label == "На модерации")
+				.unwrap_or(false)
+	}
+}
+
 impl LibGroupChapterListItem {
 	pub fn into_chapters(
 		self,
@@ -185,22 +218,8 @@ impl LibGroupChapterListItem {
 				let chapter_number = self.number.parse::<f32>().ok();
 				let volume_number = self.volume.parse::<f32>().ok();
 
-				let scanlators: Vec<String> = if branch.teams.is_empty() {
-					vec![branch.user.username.clone()]
-				} else {
-					branch.teams.iter().map(|team| team.name.clone()).collect()
-				};
-
-				let locked = branch
-					.restricted_view
-					.as_ref()
-					.map(|rv| !rv.is_open)
-					.unwrap_or(false)
-					|| branch
-						.moderation
-						.as_ref()
-						.map(|m| m.label == "На модерации")
-						.unwrap_or(false);
+				let scanlators = branch.scanlators();
+				let locked = branch.is_locked();
 
 				Chapter {
 					key: branch.id.to_string(),
@@ -267,16 +286,13 @@ impl LibGroupImageChapter {
 
 impl LibGroupTextChapter {
 	pub fn into_pages(self, ctx: &Context) -> Vec<Page> {
-		let markdown = match self.content {
-			LibGroupContentType::Html(html) => convert_html_to_markdown(&html),
+		let document = match self.content {
+			LibGroupContentType::Html(html) => convert_html_to_document(&html, ctx),
 			LibGroupContentType::Model(model) => {
-				convert_model_to_markdown(&model, &self.attachments, ctx)
+				convert_model_to_document(&model, &self.attachments, ctx)
 			}
 		};
 
-		vec![Page {
-			content: PageContent::text(markdown),
-			..Default::default()
-		}]
+		document.into_pages()
 	}
 }
@@ -1,3 +1,4 @@
+pub mod bookmark;
 pub mod chapter;
 pub mod common;
 pub mod constants;
@@ -1,7 +1,12 @@
 use aidoku::alloc::{String, Vec};
 use serde::{Deserialize, Serialize};
 
-use crate::models::{common::LibGroupMeta, manga::LibGroupCoverItem, user::LibGroupUser};
+use crate::models::{
+	bookmark::{LibGroupBookmark, LibGroupBookmarkFolder},
+	common::LibGroupMeta,
+	manga::LibGroupCoverItem,
+	user::LibGroupUser,
+};
 
 use super::{
 	chapter::{LibGroupChapterData, LibGroupChapterListItem},
@@ -46,6 +51,19 @@ pub struct MangaCoversResponse {
 	pub data: Vec<LibGroupCoverItem>,
 }
 
+#[derive(Default, Deserialize)]
+#[serde(default)]
+pub struct BookmarkFoldersResponse {
+	pub data: Vec<LibGroupBookmarkFolder>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+pub struct BookmarksResponse {
+	pub data: Vec<LibGroupBookmark>,
+	pub meta: LibGroupMeta,
+}
+
 #[derive(Default, Deserialize)]
 #[serde(default)]
 pub struct ConstantsResponse {
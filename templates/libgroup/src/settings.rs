@@ -1,10 +1,14 @@
 use aidoku::{alloc::String, imports::defaults::defaults_get};
 
+use crate::chapters::BranchStrategy;
+
 const BASE_URL_KEY: &str = "baseUrl";
 
 const API_URL_KEY: &str = "apiUrl";
 const IMAGE_SERVER_KEY: &str = "imageServerUrl";
 const COVER_QUALITY_KEY: &str = "coverQuality";
+const BRANCH_MODE_KEY: &str = "branchMode";
+const PREFERRED_TEAM_KEY: &str = "preferredTeam";
 
 const DEFAULT_API_URL: &str = "https://api.imglib.info";
 const DEFAULT_IMAGE_SERVER: &str = "compress";
@@ -29,3 +33,14 @@ pub fn get_image_server_url() -> String {
 pub fn get_cover_quality_url() -> String {
 	defaults_get::<String>(COVER_QUALITY_KEY).unwrap_or_else(|| DEFAULT_COVER_QUALITY.into())
 }
+
+/// Get the chapter branch strategy
+pub fn get_branch_strategy() -> BranchStrategy {
+	match defaults_get::<String>(BRANCH_MODE_KEY).as_deref() {
+		Some("preferred") => BranchStrategy::PreferredTeam(
+			defaults_get::<String>(PREFERRED_TEAM_KEY).unwrap_or_default(),
+		),
+		Some("complete") => BranchStrategy::MostComplete,
+		_ => BranchStrategy::All,
+	}
+}
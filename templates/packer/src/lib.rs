@@ -0,0 +1,163 @@
+//! Decoding for scripts obfuscated with Dean Edwards' packer, the
+//! `eval(function(p,a,c,k,e,d){...}(...))` wrapper used by dm5-style sites,
+//! without running any JavaScript.
+#![no_std]
+use aidoku::{
+	alloc::{String, Vec},
+	prelude::*,
+};
+
+pub mod lz_string;
+
+#[cfg(test)]
+mod test;
+
+/// Unpacks the first packed script in `source`.
+///
+/// The keywords may be a plain `'...'.split('|')` list or an LZString base64
+/// payload, as served by manhuagui.
+pub fn unpack(source: &str) -> Option<String> {
+	// the arguments follow the end of the unpacking function
+	let args = source.match_indices("return p").find_map(|(index, _)| {
+		source[index + "return p".len()..]
+			.trim_start_matches(';')
+			.trim_start()
+			.strip_prefix('}')?
+			.trim_start()
+			.strip_prefix('(')
+	})?;
+
+	let (payload, rest) = parse_string(args)?;
+	let (radix, rest) = parse_int(skip_comma(rest)?)?;
+	let (count, rest) = parse_int(skip_comma(rest)?)?;
+	let (keywords, rest) = parse_string(skip_comma(rest)?)?;
+
+	let keywords = if rest.starts_with(".split(") {
+		keywords
+	} else {
+		// `String.prototype.splic` decompresses before splitting
+		lz_string::decompress_from_base64(&keywords)?
+	};
+	let keywords = keywords.split('|').collect::<Vec<_>>();
+
+	Some(substitute(&payload, radix, count, &keywords))
+}
+
+/// Builds the image urls from an unpacked dm5-style `chapterfun.ashx` or
+/// `chapterimage.ashx` response, which joins `pix`, each entry of `pvalue` and
+/// a query string
+pub fn parse_image_urls(js: &str) -> Option<Vec<String>> {
+	let pix = js.split_once("var pix=\"")?.1.split_once('"')?.0;
+	let query = js.split_once("pix+pvalue[i]+")?.1;
+	let quote = query.chars().next().filter(|c| matches!(c, '\'' | '"'))?;
+	let query = query[1..].split_once(quote)?.0;
+
+	let urls = js
+		.split_once("var pvalue=[")?
+		.1
+		.split_once(']')?
+		.0
+		.split(',')
+		.map(|value| value.trim().trim_matches('"'))
+		.filter(|value| !value.is_empty())
+		.map(|value| format!("{pix}{value}{query}"))
+		.collect();
+	Some(urls)
+}
+
+/// Replaces every encoded word in `payload` with its keyword
+fn substitute(payload: &str, radix: usize, count: usize, keywords: &[&str]) -> String {
+	let mut result = String::with_capacity(payload.len());
+	let mut word_start = None;
+
+	for (index, char) in payload.char_indices() {
+		let is_word = char.is_ascii_alphanumeric() || char == '_';
+		match (word_start, is_word) {
+			(None, true) => word_start = Some(index),
+			(Some(start), false) => {
+				result.push_str(lookup(&payload[start..index], radix, count, keywords));
+				result.push(char);
+				word_start = None;
+			}
+			(None, false) => result.push(char),
+			(Some(_), true) => {}
+		}
+	}
+	if let Some(start) = word_start {
+		result.push_str(lookup(&payload[start..], radix, count, keywords));
+	}
+
+	result
+}
+
+fn lookup<'a>(word: &'a str, radix: usize, count: usize, keywords: &[&'a str]) -> &'a str {
+	decode_word(word, radix)
+		.filter(|index| *index < count)
+		.and_then(|index| keywords.get(index))
+		.filter(|keyword| !keyword.is_empty())
+		.copied()
+		.unwrap_or(word)
+}
+
+/// Inverse of the packer's `e(c)` encoder, which writes digits above 35 as
+/// `String.fromCharCode(c + 29)`
+fn decode_word(word: &str, radix: usize) -> Option<usize> {
+	if word.len() > 1 && word.starts_with('0') {
+		return None;
+	}
+	word.bytes().try_fold(0usize, |value, byte| {
+		let digit = match byte {
+			b'0'..=b'9' => byte - b'0',
+			b'a'..=b'z' => byte - b'a' + 10,
+			b'A'..=b'Z' => byte - b'A' + 36,
+			_ => return None,
+		};
+		let digit = usize::from(digit);
+		(digit < radix).then(|| value.checked_mul(radix)?.checked_add(digit))?
+	})
+}
+
+fn skip_comma(s: &str) -> Option<&str> {
+	Some(s.trim_start().strip_prefix(',')?.trim_start())
+}
+
+fn parse_int(s: &str) -> Option<(usize, &str)> {
+	let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+	Some((s[..end].parse().ok()?, &s[end..]))
+}
+
+/// Parses a JavaScript string literal, returning its value and the remaining
+/// input
+fn parse_string(s: &str) -> Option<(String, &str)> {
+	let mut chars = s.char_indices();
+	let quote = match chars.next()? {
+		(_, quote @ ('\'' | '"')) => quote,
+		_ => return None,
+	};
+
+	let mut value = String::new();
+	while let Some((index, char)) = chars.next() {
+		match char {
+			'\\' => match chars.next()?.1 {
+				'n' => value.push('\n'),
+				'r' => value.push('\r'),
+				't' => value.push('\t'),
+				'x' => value.push(parse_hex(&mut chars, 2)?),
+				'u' => value.push(parse_hex(&mut chars, 4)?),
+				escaped => value.push(escaped),
+			},
+			_ if char == quote => return Some((value, &s[index + 1..])),
+			_ => value.push(char),
+		}
+	}
+
+	None
+}
+
+fn parse_hex(chars: &mut core::str::CharIndices, len: usize) -> Option<char> {
+	let mut code = 0;
+	for _ in 0..len {
+		code = code * 16 + chars.next()?.1.to_digit(16)?;
+	}
+	char::from_u32(code)
+}
@@ -0,0 +1,63 @@
+use super::*;
+use aidoku::{alloc::vec, prelude::*};
+use aidoku_test::aidoku_test;
+
+#[aidoku_test]
+fn unpack_split_keywords() {
+	let script = r#"eval(function(p,a,c,k,e,d){e=function(c){return c.toString(36)};if(!''.replace(/^/,String)){while(c--){d[c.toString(a)]=k[c]||c.toString(a)}k=[function(e){return d[e]}];e=function(){return'\\w+'};c=1};while(c--){if(k[c]){p=p.replace(new RegExp('\\b'+e(c)+'\\b','g'),k[c])}}return p}('0 1="2://3.4/5";0 6=["/7.8","/9.8"];a(0 i=e;i<6.b;i++){6[i]=1+6[i]+\'?c=d\'}',15,15,'var|pix|https|img|example|c|pvalue|1_1|jpg|2_2|for|length|cid|42|0'.split('|'),0,{}))"#;
+	assert_eq!(
+		unpack(script).as_deref(),
+		Some(
+			r#"var pix="https://img.example/c";var pvalue=["/1_1.jpg","/2_2.jpg"];for(var i=0;i<pvalue.length;i++){pvalue[i]=pix+pvalue[i]+'?cid=42'}"#
+		)
+	);
+}
+
+#[aidoku_test]
+fn image_urls() {
+	let js = r#"var pix="https://img.example/c";var pvalue=["/1_1.jpg","/2_2.jpg"];for(var i=0;i<pvalue.length;i++){pvalue[i]=pix+pvalue[i]+'?cid=42'}"#;
+	assert_eq!(
+		parse_image_urls(js),
+		Some(vec![
+			String::from("https://img.example/c/1_1.jpg?cid=42"),
+			String::from("https://img.example/c/2_2.jpg?cid=42"),
+		])
+	);
+}
+
+#[aidoku_test]
+fn unpack_keeps_unknown_words() {
+	// `x` is beyond the keyword count and `01` is not a valid encoding
+	let script = r"}('0 x 01 1',2,2,'foo|'.split('|'),0,{}))";
+	assert_eq!(
+		unpack(&format!("return p{script}")).as_deref(),
+		Some("foo x 01 1")
+	);
+}
+
+#[aidoku_test]
+fn unpack_radix_62() {
+	let mut keywords = vec![""; 40];
+	keywords[37] = "pages";
+	let script = format!(
+		"return p;}}('B.1b',62,{},'{}'.split('|'),0,{{}}))",
+		keywords.len(),
+		keywords.join("|")
+	);
+	// `B` is 37 and `1b` is 62 + 11, past the keywords
+	assert_eq!(unpack(&script).as_deref(), Some("pages.1b"));
+}
+
+#[aidoku_test]
+fn unpack_lz_string_keywords() {
+	let script = r"window['\x65\x76\x61\x6c'](function(p,a,c,k,e,d){return p;}('0 1=\'5\';',6,6,'G4QwTgPgDglgHtUAbArgUwgCwC7agZwhgFsBzCQWjlBw0yA='['\x73\x70\x6c\x69\x63']('\x7c'),0,{}))";
+	assert_eq!(unpack(script).as_deref(), Some("var pix='中文';"));
+}
+
+#[aidoku_test]
+fn lz_string() {
+	assert_eq!(
+		lz_string::decompress_from_base64("BIUwNmD2A0AEDukBOYAmQ===").as_deref(),
+		Some("Hello, world")
+	);
+}
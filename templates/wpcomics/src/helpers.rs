@@ -4,6 +4,7 @@ use aidoku::{
 	alloc::{String, Vec},
 	helpers::uri::QueryParameters,
 	imports::{
+		defaults::{defaults_get, defaults_get_map},
 		html::{Element, Html},
 		std::{current_date, parse_date_with_options},
 	},
@@ -11,6 +12,46 @@ use aidoku::{
 };
 use chrono::{NaiveDate, NaiveDateTime};
 
+pub const BASE_URL_KEY: &str = "baseUrl";
+
+/// Returns the base url from settings, either set by the user or saved after a domain change.
+pub fn get_base_url_override() -> Option<String> {
+	defaults_get::<String>(BASE_URL_KEY)
+		.map(|url| url.trim().trim_end_matches('/').into())
+		.filter(|url: &String| !url.is_empty())
+}
+
+pub const LOGIN_KEY: &str = "login";
+
+/// Returns the cookies captured by the web login setting as a cookie header value.
+pub fn get_login_cookie() -> Option<String> {
+	let cookies = defaults_get_map(LOGIN_KEY)?;
+	if cookies.is_empty() {
+		return None;
+	}
+	Some(
+		cookies
+			.iter()
+			.map(|(name, value)| format!("{name}={value}"))
+			.collect::<Vec<_>>()
+			.join("; "),
+	)
+}
+
+/// Returns the scheme and host of an absolute url (e.g. "https://example.com").
+pub fn get_origin(url: &str) -> Option<&str> {
+	let start = url.find("://")? + 3;
+	let end = url[start..]
+		.find(['/', '?', '#'])
+		.map(|idx| start + idx)
+		.unwrap_or(url.len());
+	if end == start {
+		None
+	} else {
+		Some(&url[..end])
+	}
+}
+
 pub fn extract_f32_from_string(title: &str, text: &str) -> Vec<f32> {
 	text.replace(title, "")
 		.chars()
@@ -1,23 +1,49 @@
 use super::Params;
 use crate::{
 	Cache,
-	helpers::{extract_f32_from_string, find_first_f32, text_with_newlines},
+	helpers::{
+		BASE_URL_KEY, extract_f32_from_string, find_first_f32, get_login_cookie, get_origin,
+		text_with_newlines,
+	},
 };
 use aidoku::{
-	Chapter, ContentRating, DeepLinkResult, Filter, FilterValue, HomeComponent, HomeLayout,
-	ImageResponse, Manga, MangaPageResult, MangaWithChapter, MultiSelectFilter, Page, PageContent,
-	PageContext, Result, Viewer,
+	Chapter, ContentRating, DeepLinkResult, Filter, FilterValue, HashMap, HomeComponent,
+	HomeLayout, ImageResponse, Listing, Manga, MangaPageResult, MangaWithChapter,
+	MultiSelectFilter, Page, PageContent, PageContext, Result, Viewer,
 	alloc::{String, Vec, borrow::ToOwned, string::ToString, vec},
 	imports::{
 		canvas::ImageRef,
+		defaults::{DefaultValue, defaults_set},
 		error::AidokuError,
-		html::{Element, Html},
+		html::{Document, Element, Html},
 		net::Request,
 		std::send_partial_result,
 	},
 	prelude::*,
 };
 
+const FALLBACKS_KEY: &str = "fallbacks";
+const FOLLOWED_LISTING_ID: &str = "followed";
+const HISTORY_LISTING_ID: &str = "history";
+
+/// The new domain announced by a meta refresh or a "site moved" notice.
+fn moved_notice_url(params: &Params, html: &Document) -> Option<String> {
+	html.select_first("meta[http-equiv=refresh]")
+		.and_then(|el| el.attr("content"))
+		.and_then(|content| {
+			let idx = content.to_ascii_lowercase().find("url=")?;
+			Some(content[idx + 4..].trim_matches(['\'', '"', ' ']).into())
+		})
+		.or_else(|| {
+			if params.domain_moved_selector.is_empty() {
+				None
+			} else {
+				html.select_first(params.domain_moved_selector)
+					.and_then(|el| el.attr("abs:href"))
+			}
+		})
+}
+
 pub trait Impl {
 	fn new() -> Self;
 
@@ -54,7 +80,27 @@ pub trait Impl {
 		url: &str,
 		headers: Option<&Vec<(&'static str, &'static str)>>,
 	) -> Result<Request> {
-		let mut req = Request::get(url)?;
+		if params.detect_domain_change
+			&& !cache.domain_checked
+			&& url.starts_with(params.base_url.as_ref())
+		{
+			cache.domain_checked = true;
+			if let Some(base_url) = self.detect_base_url(params) {
+				defaults_set(BASE_URL_KEY, DefaultValue::String(base_url.clone()));
+				cache.base_url = Some(base_url);
+			}
+		}
+		// params keep the old domain until the next call, so rewrite urls built from it
+		let url = match cache
+			.base_url
+			.as_deref()
+			.zip(url.strip_prefix(params.base_url.as_ref()))
+		{
+			Some((base_url, path)) => format!("{base_url}{path}"),
+			None => url.into(),
+		};
+
+		let mut req = Request::get(&url)?;
 		if let Some(cookie) = &params.cookie {
 			req = req.header("Cookie", cookie);
 		}
@@ -69,6 +115,34 @@ pub trait Impl {
 		self.modify_request(cache, params, req)
 	}
 
+	/// Checks if the site has moved to a new domain, returning the new base url.
+	///
+	/// The old domain usually stays up either redirecting to the new one or
+	/// serving a "site moved" page that links to it. Only those explicit signals
+	/// count: canonical and og:url tags often point at mirrors or CDN hosts, and
+	/// trusting them would replace the user's chosen domain.
+	fn detect_base_url(&self, params: &Params) -> Option<String> {
+		let mut req = Request::get(params.base_url.as_ref()).ok()?;
+		if let Some(user_agent) = params.user_agent {
+			req = req.header("User-Agent", user_agent);
+		}
+		let html = req.html().ok()?;
+
+		// redirects are followed, so a moved site shows up in the page's own url
+		let url = html
+			.select_first("html")
+			.and_then(|el| el.base_uri())
+			.filter(|url| get_origin(url) != get_origin(&params.base_url))
+			.or_else(|| moved_notice_url(params, &html))?;
+
+		let origin = get_origin(&url)?;
+		if get_origin(&params.base_url) == Some(origin) {
+			None
+		} else {
+			Some(origin.into())
+		}
+	}
+
 	fn category_parser(
 		&self,
 		params: &Params,
@@ -357,22 +431,55 @@ pub trait Impl {
 			return Ok(pages);
 		};
 		for page_node in page_nodes {
-			let page_url = if page_node.has_attr("data-original") {
-				page_node.attr("abs:data-original")
-			} else if page_node.has_attr("data-cdn") {
-				page_node.attr("abs:data-cdn")
-			} else if page_node.has_attr("data-src") {
-				page_node.attr("abs:data-src")
-			} else if page_node.has_attr("src") {
-				page_node.attr("abs:src")
+			let mut urls: Vec<String> = Vec::new();
+			// on lazy-loading readers `src` is a placeholder, so it only counts
+			// when none of the data attributes resolved
+			let is_src = |attr: &str| attr == "src" || attr.ends_with(":src");
+			let data_attrs = params
+				.manga_viewer_page_attrs
+				.iter()
+				.filter(|attr| !is_src(attr));
+			let src_attrs = params
+				.manga_viewer_page_attrs
+				.iter()
+				.filter(|attr| is_src(attr));
+			for &attr in data_attrs {
+				let Some(url) = page_node
+					.attr(attr)
+					.map(|url| url.trim().into())
+					.filter(|url: &String| !url.is_empty())
+					.map(params.page_url_transformer)
+				else {
+					continue;
+				};
+				if !urls.contains(&url) {
+					urls.push(url);
+				}
+			}
+			if urls.is_empty() {
+				urls.extend(
+					src_attrs
+						.filter_map(|attr| page_node.attr(attr))
+						.map(|url| url.trim().into())
+						.filter(|url: &String| !url.is_empty())
+						.map(params.page_url_transformer)
+						.take(1),
+				);
+			}
+
+			let mut urls = urls.into_iter();
+			let page_url = urls.next().unwrap_or_default();
+			let fallbacks = urls.collect::<Vec<_>>();
+			let content = if fallbacks.is_empty() {
+				PageContent::url(page_url)
 			} else {
-				None
+				let mut context = PageContext::new();
+				context.insert(FALLBACKS_KEY.into(), fallbacks.join("\n"));
+				PageContent::url_context(page_url, context)
 			};
 
 			pages.push(Page {
-				content: PageContent::url((params.page_url_transformer)(
-					page_url.unwrap_or_default(),
-				)),
+				content,
 				has_description: false,
 				..Default::default()
 			});
@@ -409,6 +516,65 @@ pub trait Impl {
 		}
 	}
 
+	fn get_listing(
+		&self,
+		cache: &mut Cache,
+		params: &Params,
+		listing: Listing,
+		page: i32,
+	) -> Result<MangaPageResult> {
+		let listing_page = match listing.id.as_str() {
+			FOLLOWED_LISTING_ID => params.followed_page,
+			HISTORY_LISTING_ID => params.history_page,
+			_ => None,
+		};
+		let Some(listing_page) = listing_page else {
+			return Err(AidokuError::Unimplemented);
+		};
+		if get_login_cookie().is_none() {
+			bail!("Log in to view this listing");
+		}
+		self.get_manga_list(cache, params, listing_page(params, page))
+	}
+
+	fn get_dynamic_listings(&self, params: &Params) -> Result<Vec<Listing>> {
+		let mut listings = Vec::new();
+		if get_login_cookie().is_none() {
+			return Ok(listings);
+		}
+		if params.followed_page.is_some() {
+			listings.push(Listing {
+				id: FOLLOWED_LISTING_ID.into(),
+				name: "Followed".into(),
+				..Default::default()
+			});
+		}
+		if params.history_page.is_some() {
+			listings.push(Listing {
+				id: HISTORY_LISTING_ID.into(),
+				name: "History".into(),
+				..Default::default()
+			});
+		}
+		Ok(listings)
+	}
+
+	fn handle_web_login(
+		&self,
+		params: &Params,
+		_key: String,
+		cookies: HashMap<String, String>,
+	) -> Result<bool> {
+		if params.login_cookie_names.is_empty() {
+			Ok(!cookies.is_empty())
+		} else {
+			Ok(params
+				.login_cookie_names
+				.iter()
+				.any(|name| cookies.contains_key(*name)))
+		}
+	}
+
 	fn get_search_manga_list(
 		&self,
 		cache: &mut Cache,
@@ -615,19 +781,22 @@ pub trait Impl {
 		url: String,
 		context: Option<PageContext>,
 	) -> Result<Request> {
-		let mut request = {
-			if let Some(context) = context
-				&& let Some(referer) = context.get("Referer")
-			{
-				self.modify_request(cache, params, Request::get(url)?.header("Referer", referer))?
-			} else {
-				self.modify_request(
-					cache,
-					params,
-					Request::get(url)?.header("Referer", &format!("{}/", params.base_url)),
-				)?
-			}
+		let referer = context.as_ref().and_then(|context| context.get("Referer"));
+		self.create_image_request(cache, params, &url, referer.map(|s| s.as_str()))
+	}
+
+	fn create_image_request(
+		&self,
+		cache: &mut Cache,
+		params: &Params,
+		url: &str,
+		referer: Option<&str>,
+	) -> Result<Request> {
+		let request = match referer {
+			Some(referer) => Request::get(url)?.header("Referer", referer),
+			None => Request::get(url)?.header("Referer", &format!("{}/", params.base_url)),
 		};
+		let mut request = self.modify_request(cache, params, request)?;
 
 		if let Some(user_agent) = params.user_agent {
 			request = request.header("User-Agent", user_agent);
@@ -636,21 +805,80 @@ pub trait Impl {
 		Ok(request)
 	}
 
+	/// Checks if a page image is a CDN placeholder rather than the actual page.
+	///
+	/// CDNs that lost a file tend to answer with a 200 and a 1x1 (or similarly
+	/// tiny) blank gif instead of an error. Real pages are hundreds of pixels on
+	/// each side, so anything 10px or smaller is safe to treat as missing.
+	fn is_placeholder_image(&self, _params: &Params, image: &ImageRef) -> bool {
+		image.width() <= 10.0 || image.height() <= 10.0
+	}
+
+	fn handle_id_migration(&self, params: &Params, key: String) -> Result<String> {
+		// only keys stored as absolute urls carry the old domain
+		let Some(origin) = get_origin(&key) else {
+			return Ok(key);
+		};
+		if origin == params.base_url {
+			return Ok(key);
+		}
+		Ok(format!("{}{}", params.base_url, &key[origin.len()..]))
+	}
+
 	fn process_page_image(
 		&self,
-		_params: &Params,
-		_response: ImageResponse,
-		_context: Option<PageContext>,
+		cache: &mut Cache,
+		params: &Params,
+		response: ImageResponse,
+		context: Option<PageContext>,
 	) -> Result<ImageRef> {
-		Err(AidokuError::Unimplemented)
+		if response.code < 400 && !self.is_placeholder_image(params, &response.image) {
+			return Ok(response.image);
+		}
+		let Some(context) = context else {
+			return Ok(response.image);
+		};
+		let Some(fallbacks) = context.get(FALLBACKS_KEY) else {
+			return Ok(response.image);
+		};
+		let referer = context.get("Referer").map(|s| s.as_str());
+
+		// try the remaining CDNs in order, keeping the original response if all fail
+		for url in fallbacks.split('\n') {
+			let Ok(request) = self.create_image_request(cache, params, url, referer) else {
+				continue;
+			};
+			let Ok(fallback) = request.send() else {
+				continue;
+			};
+			if fallback.status_code() >= 400 {
+				continue;
+			}
+			let Ok(data) = fallback.get_data() else {
+				continue;
+			};
+			let image = ImageRef::new(&data);
+			if !self.is_placeholder_image(params, &image) {
+				return Ok(image);
+			}
+		}
+
+		Ok(response.image)
 	}
 
 	fn modify_request(
 		&self,
 		_cache: &mut Cache,
-		_params: &Params,
+		params: &Params,
 		request: Request,
 	) -> Result<Request> {
-		Ok(request)
+		let Some(login_cookie) = get_login_cookie() else {
+			return Ok(request);
+		};
+		let cookie = match &params.cookie {
+			Some(cookie) => format!("{cookie}; {login_cookie}"),
+			None => login_cookie,
+		};
+		Ok(request.header("Cookie", &cookie))
 	}
 }
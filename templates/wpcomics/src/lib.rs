@@ -1,14 +1,15 @@
 #![no_std]
 use aidoku::{
-	AidokuError, Chapter, ContentRating, DeepLinkHandler, DeepLinkResult, DynamicFilters, Filter,
-	FilterValue, Home, HomeLayout, ImageRequestProvider, ImageResponse, ListingProvider, Manga,
-	MangaPageResult, MangaStatus, Page, PageContext, PageImageProcessor, Result, Source, Viewer,
-	alloc::{String, Vec, borrow::Cow},
+	BaseUrlProvider, Chapter, ContentRating, DeepLinkHandler, DeepLinkResult, DynamicFilters,
+	DynamicListings, Filter, FilterValue, HashMap, Home, HomeLayout, ImageRequestProvider,
+	ImageResponse, Listing, ListingProvider, Manga, MangaPageResult, MangaStatus, MigrationHandler,
+	Page, PageContext, PageImageProcessor, Result, Source, Viewer, WebLoginHandler,
+	alloc::{String, Vec, borrow::Cow, string::ToString},
 	imports::{canvas::ImageRef, html::Element, net::Request},
 	prelude::*,
 };
-use core::cell::RefCell;
-use helpers::{get_search_url, parse_chapter_date};
+use core::cell::{Ref, RefCell};
+use helpers::{get_base_url_override, get_search_url, parse_chapter_date};
 
 pub mod helpers;
 mod imp;
@@ -16,7 +17,12 @@ mod imp;
 pub use imp::Impl;
 
 pub struct Params {
+	// the default base url, overridden by the "baseUrl" setting when it is set
 	pub base_url: Cow<'static, str>,
+	// if the site should be checked for a new domain once per session
+	pub detect_domain_change: bool,
+	// a link on a "site moved" page pointing to the new domain
+	pub domain_moved_selector: &'static str,
 	pub cookie: Option<String>,
 	pub custom_headers: Option<Vec<(&'static str, &'static str)>>,
 	pub status_mapping: fn(String) -> MangaStatus,
@@ -56,6 +62,9 @@ pub struct Params {
 	pub chapter_parse_id: fn(String) -> String,
 
 	pub manga_viewer_page: &'static str,
+	// image attributes holding page urls, in order of preference
+	// every url found is kept so other CDNs can be tried if the first fails
+	pub manga_viewer_page_attrs: &'static [&'static str],
 	pub manga_viewer_page_url_suffix: &'static str,
 	pub page_url_transformer: fn(String) -> String,
 
@@ -96,12 +105,20 @@ pub struct Params {
 	pub home_manga_cover_slider_attr: Option<&'static str>,
 	pub home_manga_cover_slider_transformer: fn(String) -> String,
 	pub time_formats: Option<Vec<&'static str>>,
+
+	// account listing pages, shown after logging in with the "login" setting
+	pub followed_page: Option<fn(&Params, i32) -> String>,
+	pub history_page: Option<fn(&Params, i32) -> String>,
+	// cookies that are only set once logged in, any cookie is accepted if empty
+	pub login_cookie_names: &'static [&'static str],
 }
 
 impl Default for Params {
 	fn default() -> Self {
 		Self {
 			base_url: "".into(),
+			detect_domain_change: false,
+			domain_moved_selector: "a:contains(tên miền mới)",
 			cookie: None,
 			custom_headers: None,
 			status_mapping: |status| match status.to_lowercase().as_str() {
@@ -191,6 +208,12 @@ impl Default for Params {
 			chapter_parse_id: |url| url,
 
 			manga_viewer_page: "div.page-chapter > img",
+			manga_viewer_page_attrs: &[
+				"abs:data-original",
+				"abs:data-cdn",
+				"abs:data-src",
+				"abs:src",
+			],
 			manga_viewer_page_url_suffix: "",
 			page_url_transformer: |url| url,
 
@@ -236,6 +259,10 @@ impl Default for Params {
 			home_manga_cover_slider_attr: None,
 			home_manga_cover_slider_transformer: |src| src,
 			time_formats: None,
+
+			followed_page: None,
+			history_page: None,
+			login_cookie_names: &[],
 		}
 	}
 }
@@ -244,12 +271,29 @@ impl Default for Params {
 pub struct Cache {
 	manga_id: Option<String>,
 	manga_value: Option<Vec<u8>>,
+	// the new base url found during this call, before params are updated
+	base_url: Option<String>,
+	domain_checked: bool,
 }
 
 pub struct WpComics<T: Impl> {
 	inner: T,
-	params: Params,
+	params: RefCell<Params>,
 	cache: RefCell<Cache>,
+	// the source's own base url, used again once the setting is cleared
+	default_base_url: Cow<'static, str>,
+}
+
+impl<T: Impl> WpComics<T> {
+	fn params(&self) -> Ref<'_, Params> {
+		let base_url = get_base_url_override()
+			.map(Cow::Owned)
+			.unwrap_or_else(|| self.default_base_url.clone());
+		if self.params.borrow().base_url != base_url {
+			self.params.borrow_mut().base_url = base_url;
+		}
+		self.params.borrow()
+	}
 }
 
 impl<T: Impl> Source for WpComics<T> {
@@ -258,7 +302,8 @@ impl<T: Impl> Source for WpComics<T> {
 		let params = inner.params();
 		Self {
 			inner,
-			params,
+			default_base_url: params.base_url.clone(),
+			params: RefCell::new(params),
 			cache: RefCell::new(Cache::default()),
 		}
 	}
@@ -271,7 +316,7 @@ impl<T: Impl> Source for WpComics<T> {
 	) -> Result<MangaPageResult> {
 		let mut cache = self.cache.borrow_mut();
 		self.inner
-			.get_search_manga_list(&mut cache, &self.params, query, page, filters)
+			.get_search_manga_list(&mut cache, &self.params(), query, page, filters)
 	}
 
 	fn get_manga_update(
@@ -283,7 +328,7 @@ impl<T: Impl> Source for WpComics<T> {
 		let mut cache = self.cache.borrow_mut();
 		self.inner.get_manga_update(
 			&mut cache,
-			&self.params,
+			&self.params(),
 			manga,
 			needs_details,
 			needs_chapters,
@@ -293,27 +338,41 @@ impl<T: Impl> Source for WpComics<T> {
 	fn get_page_list(&self, manga: Manga, chapter: Chapter) -> Result<Vec<Page>> {
 		let mut cache = self.cache.borrow_mut();
 		self.inner
-			.get_page_list(&mut cache, &self.params, manga, chapter)
+			.get_page_list(&mut cache, &self.params(), manga, chapter)
 	}
 }
 
 impl<T: Impl> ListingProvider for WpComics<T> {
-	fn get_manga_list(&self, _listing: aidoku::Listing, _page: i32) -> Result<MangaPageResult> {
-		Err(AidokuError::Unimplemented)
+	fn get_manga_list(&self, listing: Listing, page: i32) -> Result<MangaPageResult> {
+		let mut cache = self.cache.borrow_mut();
+		self.inner
+			.get_listing(&mut cache, &self.params(), listing, page)
+	}
+}
+
+impl<T: Impl> DynamicListings for WpComics<T> {
+	fn get_dynamic_listings(&self) -> Result<Vec<Listing>> {
+		self.inner.get_dynamic_listings(&self.params())
+	}
+}
+
+impl<T: Impl> WebLoginHandler for WpComics<T> {
+	fn handle_web_login(&self, key: String, cookies: HashMap<String, String>) -> Result<bool> {
+		self.inner.handle_web_login(&self.params(), key, cookies)
 	}
 }
 
 impl<T: Impl> Home for WpComics<T> {
 	fn get_home(&self) -> Result<HomeLayout> {
 		let mut cache = self.cache.borrow_mut();
-		self.inner.get_home(&mut cache, &self.params)
+		self.inner.get_home(&mut cache, &self.params())
 	}
 }
 
 impl<T: Impl> DynamicFilters for WpComics<T> {
 	fn get_dynamic_filters(&self) -> Result<Vec<Filter>> {
 		let mut cache = self.cache.borrow_mut();
-		self.inner.get_dynamic_filters(&mut cache, &self.params)
+		self.inner.get_dynamic_filters(&mut cache, &self.params())
 	}
 }
 
@@ -321,7 +380,7 @@ impl<T: Impl> ImageRequestProvider for WpComics<T> {
 	fn get_image_request(&self, url: String, context: Option<PageContext>) -> Result<Request> {
 		let mut cache = self.cache.borrow_mut();
 		self.inner
-			.get_image_request(&mut cache, &self.params, url, context)
+			.get_image_request(&mut cache, &self.params(), url, context)
 	}
 }
 
@@ -331,14 +390,31 @@ impl<T: Impl> PageImageProcessor for WpComics<T> {
 		response: ImageResponse,
 		context: Option<PageContext>,
 	) -> Result<ImageRef> {
+		let mut cache = self.cache.borrow_mut();
 		self.inner
-			.process_page_image(&self.params, response, context)
+			.process_page_image(&mut cache, &self.params(), response, context)
 	}
 }
 
 impl<T: Impl> DeepLinkHandler for WpComics<T> {
 	fn handle_deep_link(&self, url: String) -> Result<Option<DeepLinkResult>> {
 		let mut cache = self.cache.borrow_mut();
-		self.inner.handle_deep_link(&mut cache, &self.params, url)
+		self.inner.handle_deep_link(&mut cache, &self.params(), url)
+	}
+}
+
+impl<T: Impl> BaseUrlProvider for WpComics<T> {
+	fn get_base_url(&self) -> Result<String> {
+		Ok(self.params().base_url.to_string())
+	}
+}
+
+impl<T: Impl> MigrationHandler for WpComics<T> {
+	fn handle_manga_migration(&self, key: String) -> Result<String> {
+		self.inner.handle_id_migration(&self.params(), key)
+	}
+
+	fn handle_chapter_migration(&self, _manga_key: String, chapter_key: String) -> Result<String> {
+		self.inner.handle_id_migration(&self.params(), chapter_key)
 	}
 }